@@ -3,12 +3,20 @@ require "spec_helper"
 RSpec.describe "Not Found Page", type: :system do
   pages = [
     {
-      url: "/commands/GggGXNjmeiA59Kn1qiyG3NZ1oT1sBNBg8iwvLzJuyT7GH9dVmGggg",
+      url: "/commands/5JtVQKsT6jBdWKdscCzm4a1JzuUdBwQ3jzX3fyqkvgpraPizUzqw",
       message: "Transaction Not Found :("
     },
     {
-      url: "/blocks/3NLCZXPQH8WwSXUtrCHHz3n78RQJxNYUyn4uyx6JyTo4THApBBgg",
+      url: "/blocks/3NK2tkzqqK5spR2sZ7tujjqPksL45M3UUrcA4WhCkeiPtnugyE2x",
       message: "Block Not Found :("
+    },
+    {
+      url: "/commands/GggGXNjmeiA59Kn1qiyG3NZ1oT1sBNBg8iwvLzJuyT7GH9dVmGggg",
+      message: "Invalid transaction hash: invalid checksum"
+    },
+    {
+      url: "/blocks/3NLCZXPQH8WwSXUtrCHHz3n78RQJxNYUyn4uyx6JyTo4THApBBgg",
+      message: "Invalid state hash: invalid checksum"
     }
   ]
 
@@ -4,7 +4,7 @@ require "spec_helper"
 RSpec.describe "User Commands table", type: :system do
   let(:url) { "/addresses/accounts/#{Constants::MINA_TOKEN_ADDRESS}/#{Constants::FIRST_SENDER_ADDRESS}/commands/user" }
   let(:heading) { "User Commands" }
-  let(:columns) { ["Height", "Txn Hash", "Nonce", "Date", "Type", "Direction", "Counterparty", "Amount/Fee"].map(&:upcase) }
+  let(:columns) { ["Height", "Txn Hash", "Nonce", "Date", "Type", "Direction", "Counterparty", "Amount/Fee", "Memo"].map(&:upcase) }
 
   before do
     visit url
@@ -4,7 +4,7 @@ require "spec_helper"
 RSpec.describe "User Commands table", type: :system do
   let(:url) { "/commands/user" }
   let(:heading) { "User Commands" }
-  let(:columns) { ["Height", "Txn Hash", "Date", "Type", "Status", "From", "To", "Nonce", "Fee", "Amount"].map(&:upcase) }
+  let(:columns) { ["Height", "Txn Hash", "Date", "Type", "Status", "From", "To", "Nonce", "Fee", "Amount", "Memo"].map(&:upcase) }
 
   before do
     visit url
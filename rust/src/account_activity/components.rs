@@ -13,7 +13,10 @@ use crate::{
         },
         models::AccountActivityQueryDelegatorExt,
     },
-    common::{components::*, constants::*, models::*, spotlight::SpotlightTable, table::*},
+    common::{
+        components::*, constants::*, memo::validate_memo, models::*, network::*,
+        spotlight::SpotlightTable, table::*,
+    },
     icons::*,
     summary::models::BlockchainSummary,
 };
@@ -70,8 +73,9 @@ pub fn AccountTransactionsSection(
 ) -> impl IntoView {
     let account = use_context::<ReadSignal<Option<AccountActivityQueryAccounts>>>()
         .expect("Expected an account to be provided in the context");
-    let (summary_sig, _, _) =
-        use_local_storage::<BlockchainSummary, JsonSerdeCodec>(BLOCKCHAIN_SUMMARY_STORAGE_KEY);
+    let (summary_sig, _, _) = use_local_storage::<BlockchainSummary, JsonSerdeCodec>(
+        use_network_storage_key(BLOCKCHAIN_SUMMARY_STORAGE_KEY),
+    );
     let memo_params_map = use_params_map();
     let table_columns: Vec<TableColumn<AnySort>> = vec![
         TableColumn {
@@ -84,6 +88,7 @@ pub fn AccountTransactionsSection(
         },
         TableColumn {
             column: "Txn Hash".to_string(),
+            validate: Some(TxnHash::validate),
             search_type: ColumnSearchType::Text,
             width: Some(String::from(TABLE_COL_HASH_WIDTH)),
             ..Default::default()
@@ -119,6 +124,7 @@ pub fn AccountTransactionsSection(
         },
         TableColumn {
             column: "Counterparty".to_string(),
+            validate: Some(PublicKey::validate),
             search_type: ColumnSearchType::Text,
             width: Some(String::from(TABLE_COL_HASH_WIDTH)),
             ..Default::default()
@@ -128,10 +134,18 @@ pub fn AccountTransactionsSection(
             width: Some(String::from(TABLE_COL_NUMERIC_WIDTH)),
             ..Default::default()
         },
+        TableColumn {
+            column: "Memo".to_string(),
+            search_type: ColumnSearchType::Text,
+            validate: Some(validate_memo),
+            width: Some(String::from(TABLE_COL_HASH_WIDTH)),
+            ..Default::default()
+        },
     ];
 
     view! {
         <TableSectionTemplate
+            table_id="account-user-commands"
             table_columns
             data_sig=transactions_sig
             metadata=Signal::derive(move || {
@@ -197,8 +211,9 @@ pub fn AccountInternalCommandsSection(
 ) -> impl IntoView {
     let account = use_context::<ReadSignal<Option<AccountActivityQueryAccounts>>>()
         .expect("Expected an account to be provided in the context");
-    let (summary_sig, _, _) =
-        use_local_storage::<BlockchainSummary, JsonSerdeCodec>(BLOCKCHAIN_SUMMARY_STORAGE_KEY);
+    let (summary_sig, _, _) = use_local_storage::<BlockchainSummary, JsonSerdeCodec>(
+        use_network_storage_key(BLOCKCHAIN_SUMMARY_STORAGE_KEY),
+    );
     let memo_params_map = use_params_map();
     let table_columns: Vec<TableColumn<AnySort>> = vec![
         TableColumn {
@@ -211,6 +226,7 @@ pub fn AccountInternalCommandsSection(
         },
         TableColumn {
             column: "State Hash".to_string(),
+            validate: Some(StateHash::validate),
             search_type: ColumnSearchType::Text,
             width: Some(String::from(TABLE_COL_HASH_WIDTH)),
             ..Default::default()
@@ -234,6 +250,7 @@ pub fn AccountInternalCommandsSection(
 
     view! {
         <TableSectionTemplate
+            table_id="account-internal-commands"
             table_columns
             data_sig=txn_sig
             metadata=Signal::derive(move || {
@@ -321,6 +338,7 @@ pub fn AccountDelegationsSection(
 
     view! {
         <TableSectionTemplate
+            table_id="account-delegations"
             table_columns
             data_sig=delegations_sig
             metadata=Signal::derive(move || {
@@ -374,8 +392,9 @@ pub fn AccountOverviewSnarkJobTable(
 ) -> impl IntoView {
     let account = use_context::<ReadSignal<Option<AccountActivityQueryAccounts>>>()
         .expect("Expected an account to be provided in the context");
-    let (summary_sig, _, _) =
-        use_local_storage::<BlockchainSummary, JsonSerdeCodec>(BLOCKCHAIN_SUMMARY_STORAGE_KEY);
+    let (summary_sig, _, _) = use_local_storage::<BlockchainSummary, JsonSerdeCodec>(
+        use_network_storage_key(BLOCKCHAIN_SUMMARY_STORAGE_KEY),
+    );
     let memo_params_map = use_params_map();
     let (href, _set_href) = create_signal(
         memo_params_map
@@ -397,6 +416,7 @@ pub fn AccountOverviewSnarkJobTable(
         },
         TableColumn {
             column: "State Hash".to_string(),
+            validate: Some(StateHash::validate),
             search_type: ColumnSearchType::Text,
             width: Some(String::from(TABLE_COL_HASH_WIDTH)),
             ..Default::default()
@@ -420,6 +440,7 @@ pub fn AccountOverviewSnarkJobTable(
 
     view! {
         <TableSectionTemplate
+            table_id="account-snark-jobs"
             table_columns
             data_sig=snarks_sig
             section_heading="SNARK Jobs"
@@ -470,8 +491,9 @@ pub fn AccountOverviewBlocksTable(
 ) -> impl IntoView {
     let account = use_context::<ReadSignal<Option<AccountActivityQueryAccounts>>>()
         .expect("Expected an account to be provided in the context");
-    let (summary_sig, _, _) =
-        use_local_storage::<BlockchainSummary, JsonSerdeCodec>(BLOCKCHAIN_SUMMARY_STORAGE_KEY);
+    let (summary_sig, _, _) = use_local_storage::<BlockchainSummary, JsonSerdeCodec>(
+        use_network_storage_key(BLOCKCHAIN_SUMMARY_STORAGE_KEY),
+    );
     let memo_params_map = use_params_map();
     let (href, _set_href) = create_signal(
         memo_params_map
@@ -493,6 +515,7 @@ pub fn AccountOverviewBlocksTable(
         },
         TableColumn {
             column: "State Hash".to_string(),
+            validate: Some(StateHash::validate),
             search_type: ColumnSearchType::Text,
             width: Some(String::from(TABLE_COL_HASH_WIDTH)),
             ..Default::default()
@@ -541,6 +564,7 @@ pub fn AccountOverviewBlocksTable(
 
     view! {
         <TableSectionTemplate
+            table_id="account-block-production"
             table_columns
             data_sig=blocks_sig
             metadata=Signal::derive(move || {
@@ -624,6 +648,7 @@ pub fn AccountOverviewTokensTable(
 
     view! {
         <TableSectionTemplate
+            table_id="account-tokens"
             table_columns
             data_sig=tokens_sig
             metadata=Signal::derive(move || { None })
@@ -10,37 +10,43 @@ use crate::{
             BlockProtocolStateConsensusStateQueryInput, BlockProtocolStateQueryInput,
             BlockQueryInput,
         },
-        models::AccountActivityQueryDelegatorExt,
+        models::{AccountActivityParams, AccountActivityQueryDelegatorExt},
+    },
+    common::{
+        amount::Amount, client::query_graphql, functions::*, memo::memo_search_values, models::*,
+        network::Network, spotlight::*,
     },
-    common::{constants::*, functions::*, models::*, spotlight::*},
 };
-use graphql_client::reqwest::post_graphql;
-use rust_decimal::prelude::ToPrimitive;
 
-#[allow(clippy::too_many_arguments)]
 pub async fn load_data(
-    public_key: Option<String>,
-    blocks_limit: Option<i64>,
-    snarks_limit: Option<i64>,
-    trans_limit: Option<i64>,
-    delegators_limit: Option<i64>,
-    internal_commands_limit: Option<i64>,
-    block_height: Option<i64>,
-    txn_hash: Option<String>,
-    state_hash: Option<String>,
-    prover: Option<String>,
-    nonce: Option<u64>,
-    counterparty: Option<String>,
-    slot: Option<u64>,
-    block_producer: Option<String>,
-    current_epoch_staking_ledger: Option<u64>,
-    canonical: Option<bool>,
-    all_account_types: Option<bool>,
-    direction_in: Option<bool>,
+    network: &Network,
+    params: AccountActivityParams,
 ) -> Result<account_activity_query::ResponseData, MyError> {
+    let AccountActivityParams {
+        public_key,
+        blocks_limit,
+        snarks_limit,
+        trans_limit,
+        delegators_limit,
+        internal_commands_limit,
+        block_height,
+        txn_hash,
+        state_hash,
+        prover,
+        nonce,
+        counterparty,
+        slot,
+        block_producer,
+        current_epoch_staking_ledger,
+        canonical,
+        all_account_types,
+        direction_in,
+        memo,
+    } = params;
     let block_height = block_height.map(|x| std::cmp::max(0i64, x));
     let nonce = nonce.map(|x| x as i64);
     let slot = slot.map(|x| x as i64);
+    let memo_in = memo.map(|memo| memo_search_values(&memo).into_iter().map(Some).collect());
     let get_current_epoch_staking_ledger =
         move || current_epoch_staking_ledger.and_then(|e| e.try_into().ok());
 
@@ -143,6 +149,7 @@ pub async fn load_data(
             from: public_key.clone(),
             to: counterparty.clone(),
             nonce,
+            memo_in: memo_in.clone(),
             canonical: if canonical.is_none() {
                 Some(true)
             } else {
@@ -157,6 +164,7 @@ pub async fn load_data(
             to: public_key.clone(),
             from: counterparty.clone(),
             nonce,
+            memo_in,
             canonical: if canonical.is_none() {
                 Some(true)
             } else {
@@ -195,19 +203,7 @@ pub async fn load_data(
         },
     };
 
-    let client = reqwest::Client::new();
-
-    let response = post_graphql::<AccountActivityQuery, _>(&client, GRAPHQL_ENDPOINT, variables)
-        .await
-        .map_err(|e| MyError::NetworkError(e.to_string()))?;
-
-    if let Some(errors) = response.errors {
-        return Err(MyError::GraphQLError(errors));
-    }
-
-    response
-        .data
-        .ok_or(MyError::GraphQLEmpty("No data available".to_string()))
+    query_graphql::<AccountActivityQuery>(network, variables).await
 }
 
 pub fn get_spotlight_loading_data() -> Vec<SpotlightEntry> {
@@ -234,7 +230,7 @@ pub fn get_spotlight_data(
     let mut balance_el = decorate_with_mina_tag(
         account
             .balance
-            .map(|b| nanomina_to_mina(b.try_into().unwrap_or_default()))
+            .map(|b| Amount::from_nanomina(b).format())
             .unwrap_or_default(),
     );
     balance_el = match (
@@ -258,12 +254,8 @@ pub fn get_spotlight_data(
             let adjusted_balance = account
                 .balance
                 .map(|balance| {
-                    nanomina_to_mina(
-                        balance
-                            .checked_add(genesis_balance)
-                            .and_then(|x| x.to_u64())
-                            .expect("Expected genesis balance to be positive and within range"),
-                    )
+                    (Amount::from_nanomina(balance) + Amount::from_nanomina(genesis_balance))
+                        .format()
                 })
                 .unwrap_or_default();
 
@@ -359,11 +351,9 @@ pub fn get_spotlight_data(
         if genesis_balance > 0 {
             spotlight_entries.push(SpotlightEntry {
                 label: String::from("Genesis Balance"),
-                any_el: Some(decorate_with_mina_tag(nanomina_to_mina(
-                    genesis_balance
-                        .try_into()
-                        .expect("Expected to convert genesis balance to u64"),
-                ))),
+                any_el: Some(decorate_with_mina_tag(
+                    Amount::from_nanomina(genesis_balance).format(),
+                )),
                 copiable: false,
             });
         }
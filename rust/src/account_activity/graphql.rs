@@ -11,6 +11,7 @@ use crate::{
         InternalCommandQueryInput,
     },
     blocks::graphql::blocks_query::{BlocksQueryBlocks, BlocksQueryBlocksTransactions},
+    common::amount::Amount,
     snarks::graphql::snarks_query::{SnarksQuerySnarks, SnarksQuerySnarksBlock},
     user_commands::graphql::transactions_query::{
         TransactionsQueryTransactions, TransactionsQueryTransactionsBlock,
@@ -23,7 +24,7 @@ type Long = i32;
 
 #[derive(GraphQLQuery)]
 #[graphql(
-    schema_path = "./graphql/schemas/mina-explorer.graphql",
+    schema_path = "./graphql/schemas/mina-explorer.typed.graphql",
     query_path = "./graphql/queries/account_dialog.graphql",
     response_derives = "Serialize,PartialEq,Debug,Clone,Default",
     skip_serializing_none
@@ -240,6 +241,7 @@ impl Default for BlockProtocolStateQueryInput {
 impl Default for BlockQueryInput {
     fn default() -> Self {
         BlockQueryInput {
+            unique_block_producers_last_n_blocks: None,
             creator_nin: None,
             state_hash_lte: None,
             canonical_ne: None,
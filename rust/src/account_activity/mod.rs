@@ -1,6 +1,6 @@
 pub mod components;
 pub mod functions;
-mod graphql;
+pub mod graphql;
 pub mod models;
 pub mod page;
 mod table_traits;
@@ -2,60 +2,54 @@ use super::graphql::account_activity_query::{
     AccountActivityQueryIncomingTransactions, AccountActivityQueryOutgoingTransactions,
 };
 use crate::{
-    Params,
     account_activity::graphql::account_activity_query::StakesQueryInput,
-    common::{functions::*, table::SortDirection},
+    common::{
+        amount::Amount,
+        functions::*,
+        memo::{MemoFormat, format_memo},
+        table::SortDirection,
+    },
 };
 use chrono::{DateTime, Utc};
 use heck::ToTitleCase;
-use leptos_router::Params;
-use serde::{Deserialize, Serialize};
 use std::{cmp::Ordering, fmt};
 
 #[derive(Copy, Clone)]
 pub struct DelegateCount(pub usize);
 
-#[derive(Params, PartialEq)]
-pub struct URLParams {
-    pub id: Option<String>,
-}
-
-#[derive(Params, PartialEq)]
-pub struct QueryParams {
-    pub f: Option<bool>,
-}
-
-#[derive(Debug, Deserialize, Serialize, Clone)]
-pub struct AccountBalance {
-    pub total: String,
-}
-
-#[derive(Debug, Deserialize, Serialize, Clone)]
-#[serde(rename_all = "camelCase")]
-pub struct AccountSummary {
-    pub public_key: String,
-    pub nonce: u32,
-    pub delegate: String,
-    pub voting_for: String,
-    pub total_tx: u32,
-    pub count_pending_transactions: u32,
-    pub username: String,
-    pub balance: AccountBalance,
-}
-
-#[derive(Debug, Deserialize, Serialize, Clone)]
-pub struct AccountResponse {
-    pub account: AccountSummary,
+/// What `load_data` asks for. The limits size each section of the account
+/// page; the other fields filter them.
+#[derive(Clone, Debug, Default)]
+pub struct AccountActivityParams {
+    pub public_key: Option<String>,
+    pub blocks_limit: Option<i64>,
+    pub snarks_limit: Option<i64>,
+    pub trans_limit: Option<i64>,
+    pub delegators_limit: Option<i64>,
+    pub internal_commands_limit: Option<i64>,
+    pub block_height: Option<i64>,
+    pub txn_hash: Option<String>,
+    pub state_hash: Option<String>,
+    pub prover: Option<String>,
+    pub nonce: Option<u64>,
+    pub counterparty: Option<String>,
+    pub slot: Option<u64>,
+    pub block_producer: Option<String>,
+    pub current_epoch_staking_ledger: Option<u64>,
+    pub canonical: Option<bool>,
+    pub all_account_types: Option<bool>,
+    pub direction_in: Option<bool>,
+    pub memo: Option<String>,
 }
 
 #[derive(Clone, Debug, PartialEq, Default)]
 pub struct AccountActivityQueryDirectionalTransactions {
-    pub fee: Option<f64>,
+    pub fee: Option<Amount>,
     pub counterparty: Option<String>,
     pub counterparty_username: Option<String>,
     pub direction: Option<String>,
     pub hash: Option<String>,
-    pub amount: Option<f64>,
+    pub amount: Option<Amount>,
     pub date_time: Option<DateTime<Utc>>,
     pub height: Option<u64>,
     pub kind: Option<String>,
@@ -190,10 +184,7 @@ impl AccountActivityQueryDirectionalTransactionTrait
     for AccountActivityQueryDirectionalTransactions
 {
     fn get_fee(&self) -> String {
-        self.fee
-            .map(|f| f.round() as u64)
-            .map(nanomina_to_mina)
-            .map_or(String::new(), |f| format_mina(f.to_string()))
+        self.fee.map_or(String::new(), |f| f.format())
     }
     fn get_counterparty(&self) -> String {
         self.counterparty
@@ -212,10 +203,7 @@ impl AccountActivityQueryDirectionalTransactionTrait
         self.hash.as_ref().map_or(String::new(), |f| f.to_string())
     }
     fn get_amount(&self) -> String {
-        self.amount
-            .map(|f| f.round() as u64)
-            .map(nanomina_to_mina)
-            .map_or(String::new(), |f| f.to_string())
+        self.amount.map_or(String::new(), |a| a.format())
     }
     fn get_date_time(&self) -> String {
         self.date_time.map_or(String::new(), |f| f.to_string())
@@ -246,7 +234,7 @@ impl AccountActivityQueryDirectionalTransactionTrait
     fn get_memo(&self) -> String {
         self.memo
             .as_ref()
-            .map_or_else(String::new, ToString::to_string)
+            .map_or_else(String::new, |memo| format_memo(memo, MemoFormat::Decoded))
     }
 
     fn _get_canonical(&self) -> bool {
@@ -421,11 +409,11 @@ mod merge_tests {
         // Test data (only Some transactions with date_time)
         let incoming = vec![
             Some(AccountActivityQueryIncomingTransactions {
-                fee: Some(0.1),
+                fee: Some(Amount::from_nanomina(100_000_000i64)),
                 from: Some("alice".to_string()),
                 to: Some("bob".to_string()),
                 hash: Some("in1".to_string()),
-                amount: Some(10.0),
+                amount: Some(Amount::from_nanomina(10_000_000_000i64)),
                 block: Some(AccountActivityQueryIncomingTransactionsBlock {
                     date_time: Some(parse_date("2023-10-01T10:00:00Z")),
                 }),
@@ -438,11 +426,11 @@ mod merge_tests {
                 ..Default::default()
             }),
             Some(AccountActivityQueryIncomingTransactions {
-                fee: Some(0.2),
+                fee: Some(Amount::from_nanomina(200_000_000i64)),
                 from: Some("alice".to_string()),
                 to: Some("alice".to_string()), // Self-transfer
                 hash: Some("in2".to_string()),
-                amount: Some(5.0),
+                amount: Some(Amount::from_nanomina(5_000_000_000i64)),
                 block: Some(AccountActivityQueryIncomingTransactionsBlock {
                     date_time: Some(parse_date("2023-10-02T12:00:00Z")),
                 }),
@@ -457,11 +445,11 @@ mod merge_tests {
         ];
 
         let outgoing = vec![Some(AccountActivityQueryOutgoingTransactions {
-            fee: Some(0.3),
+            fee: Some(Amount::from_nanomina(300_000_000i64)),
             from: Some("bob".to_string()),
             to: Some("charlie".to_string()),
             hash: Some("out1".to_string()),
-            amount: Some(15.0),
+            amount: Some(Amount::from_nanomina(15_000_000_000i64)),
             block: Some(AccountActivityQueryOutgoingTransactionsBlock {
                 date_time: Some(parse_date("2023-10-01T09:00:00Z")),
             }),
@@ -481,11 +469,11 @@ mod merge_tests {
         let expected = vec![
             // Latest: 2023-10-02T12:00:00Z (in2)
             Some(AccountActivityQueryDirectionalTransactions {
-                fee: Some(0.2),
+                fee: Some(Amount::from_nanomina(200_000_000i64)),
                 counterparty: Some("alice".to_string()),
                 direction: Some("IN".to_string()),
                 hash: Some("in2".to_string()),
-                amount: Some(5.0),
+                amount: Some(Amount::from_nanomina(5_000_000_000i64)),
                 date_time: Some(parse_date("2023-10-02T12:00:00Z")),
                 height: Some(101),
                 kind: Some("payment".to_string()),
@@ -497,11 +485,11 @@ mod merge_tests {
             }),
             // 2023-10-01T10:00:00Z (in1)
             Some(AccountActivityQueryDirectionalTransactions {
-                fee: Some(0.1),
+                fee: Some(Amount::from_nanomina(100_000_000i64)),
                 counterparty: Some("alice".to_string()),
                 direction: Some("IN".to_string()),
                 hash: Some("in1".to_string()),
-                amount: Some(10.0),
+                amount: Some(Amount::from_nanomina(10_000_000_000i64)),
                 date_time: Some(parse_date("2023-10-01T10:00:00Z")),
                 height: Some(100),
                 kind: Some("payment".to_string()),
@@ -513,11 +501,11 @@ mod merge_tests {
             }),
             // 2023-10-01T09:00:00Z (out1)
             Some(AccountActivityQueryDirectionalTransactions {
-                fee: Some(0.3),
+                fee: Some(Amount::from_nanomina(300_000_000i64)),
                 counterparty: Some("charlie".to_string()),
                 direction: Some("OUT".to_string()),
                 hash: Some("out1".to_string()),
-                amount: Some(15.0),
+                amount: Some(Amount::from_nanomina(15_000_000_000i64)),
                 date_time: Some(parse_date("2023-10-01T09:00:00Z")),
                 height: Some(99),
                 kind: Some("payment".to_string()),
@@ -16,14 +16,17 @@ use crate::{
         models::AccountActivityQueryDirectionalTransactions,
     },
     common::{
+        amount::Amount,
         components::*,
         constants::*,
-        functions::{decorate_with_mina_tag, nanomina_to_mina},
+        functions::decorate_with_mina_tag,
         models::{MyError, NavEntry, NavIcon},
+        network::*,
         spotlight::*,
     },
     icons::*,
     summary::models::BlockchainSummary,
+    watchlist::components::WatchlistToggle,
 };
 use codee::string::JsonSerdeCodec;
 use leptos::*;
@@ -34,8 +37,9 @@ use leptos_use::storage::use_local_storage;
 #[component]
 fn AccountSpotlightPage() -> impl IntoView {
     let memo_params_map = use_params_map();
-    let (summary_sig, _, _) =
-        use_local_storage::<BlockchainSummary, JsonSerdeCodec>(BLOCKCHAIN_SUMMARY_STORAGE_KEY);
+    let (summary_sig, _, _) = use_local_storage::<BlockchainSummary, JsonSerdeCodec>(
+        use_network_storage_key(BLOCKCHAIN_SUMMARY_STORAGE_KEY),
+    );
 
     let account = use_context::<ReadSignal<Option<AccountActivityQueryAccounts>>>()
         .expect("Expected an optional account provided");
@@ -49,6 +53,15 @@ fn AccountSpotlightPage() -> impl IntoView {
             .and_then(|acc| acc.username)
             .unwrap_or("Unknown".to_string())
     };
+    let watchlist_toggle = move || -> Option<Children> {
+        memo_params_map.get().get("id").cloned().map(|id| {
+            Box::new(move || {
+                Fragment::new(vec![
+                    view! { <WatchlistToggle public_key=id /> }.into_view(),
+                ])
+            }) as Children
+        })
+    };
 
     view! {
         <Title formatter=move |text| format!("Account Overview | {text}") text=username />
@@ -64,12 +77,13 @@ fn AccountSpotlightPage() -> impl IntoView {
                         view! {
                             <SpotlightSection
                                 header="Account Spotlight"
+                                top_right=watchlist_toggle()
                                 spotlight_items=vec![
                                     SpotlightEntry {
                                         label: String::from("Genesis Balance"),
                                         any_el: Some(
                                             decorate_with_mina_tag(
-                                                nanomina_to_mina(balance.try_into().unwrap()),
+                                                Amount::from_nanomina(balance).format(),
                                             ),
                                         ),
                                         copiable: false,
@@ -89,6 +103,7 @@ fn AccountSpotlightPage() -> impl IntoView {
                         view! {
                             <SpotlightSection
                                 header="Account Spotlight"
+                                top_right=watchlist_toggle()
                                 spotlight_items=get_spotlight_data(
                                     &acc,
                                     summary_sig.get().blockchain_length,
@@ -107,6 +122,7 @@ fn AccountSpotlightPage() -> impl IntoView {
                         view! {
                             <SpotlightSection
                                 header="Account Spotlight"
+                                top_right=watchlist_toggle()
                                 spotlight_items=vec![]
                                 meta=Some("Account has no MINA balance.".to_string())
                                 id=memo_params_map.get().get("id").cloned()
@@ -277,15 +293,17 @@ pub fn AccountSpotlightTabbedPage() -> impl IntoView {
     let (q_direction_sig, _) = create_query_signal::<String>(QUERY_PARAM_DIRECTION);
     let (row_limit_sig, _) = create_query_signal::<i64>("row-limit");
 
-    let (summary_sig, _, _) =
-        use_local_storage::<BlockchainSummary, JsonSerdeCodec>(BLOCKCHAIN_SUMMARY_STORAGE_KEY);
+    let (summary_sig, _, _) = use_local_storage::<BlockchainSummary, JsonSerdeCodec>(
+        use_network_storage_key(BLOCKCHAIN_SUMMARY_STORAGE_KEY),
+    );
 
+    let network = use_network();
     let current_epoch_staking_ledger = move || {
         summary_sig
             .get()
             .chain
             .as_ref()
-            .and_then(|c| c.get(MAINNET_2_CHAIN_ID))
+            .and_then(|c| network.with_value(|n| c.get(&n.chain_id).cloned()))
             .map(|c| c.latest_epoch)
     };
     let activity_resource = create_resource(
@@ -304,7 +322,7 @@ pub fn AccountSpotlightTabbedPage() -> impl IntoView {
                 get_tab(),
             )
         },
-        |(
+        move |(
             value,
             canonical_opt,
             qp_map,
@@ -327,24 +345,28 @@ pub fn AccountSpotlightTabbedPage() -> impl IntoView {
             if let Some(id) = value.get("id").cloned() {
                 // Attempt to load data and handle any potential errors more gracefully
                 match load_data(
-                    Some(id.clone()),
-                    blocks_limit,
-                    snarks_limit,
-                    trans_limit,
-                    delegators_limit,
-                    internal_commands_limit,
-                    block_height,
-                    qp_map.get(QUERY_PARAM_TXN_HASH).cloned(),
-                    qp_map.get(QUERY_PARAM_STATE_HASH).cloned(),
-                    Some(id.clone()),
-                    nonce,
-                    qp_map.get(QUERY_PARAM_COUNTERPARTY).cloned(),
-                    slot,
-                    Some(id),
-                    current_epoch_staking_ledger,
-                    canonical_opt,
-                    q_type.map(|q_type| q_type != TYPE_SEARCH_OPTION_ZKAPP),
-                    q_direction.map(|d| d == DIRECTION_IN),
+                    &network.get_value(),
+                    AccountActivityParams {
+                        public_key: Some(id.clone()),
+                        blocks_limit,
+                        snarks_limit,
+                        trans_limit,
+                        delegators_limit,
+                        internal_commands_limit,
+                        block_height,
+                        txn_hash: qp_map.get(QUERY_PARAM_TXN_HASH).cloned(),
+                        state_hash: qp_map.get(QUERY_PARAM_STATE_HASH).cloned(),
+                        prover: Some(id.clone()),
+                        nonce,
+                        counterparty: qp_map.get(QUERY_PARAM_COUNTERPARTY).cloned(),
+                        slot,
+                        block_producer: Some(id),
+                        current_epoch_staking_ledger,
+                        canonical: canonical_opt,
+                        all_account_types: q_type.map(|q_type| q_type != TYPE_SEARCH_OPTION_ZKAPP),
+                        direction_in: q_direction.map(|d| d == DIRECTION_IN),
+                        memo: qp_map.get(QUERY_PARAM_MEMO).cloned(),
+                    },
                 )
                 .await
                 {
@@ -356,7 +378,7 @@ pub fn AccountSpotlightTabbedPage() -> impl IntoView {
                 }
             } else {
                 logging::error!("Could not parse id parameter from URL");
-                Err(MyError::ParseError(String::from(
+                Err(MyError::InvalidInput(String::from(
                     "Could not parse id parameter from url",
                 )))
             }
@@ -454,11 +476,12 @@ pub fn AccountSpotlightTabs() -> impl IntoView {
     let tokens = use_context::<ReadSignal<Option<Vec<Option<AccountActivityQueryTokenHolders>>>>>()
         .expect("Expected there to be an optional tokens provided");
 
+    let mina_token_address = use_mina_token_address();
     let tabs = vec![
         NavEntry {
             href: format!(
                 "/addresses/accounts/{}/{}/commands/user",
-                MINA_TOKEN_ADDRESS,
+                mina_token_address,
                 id()
             ),
             text: "User Commands".to_string(),
@@ -472,7 +495,7 @@ pub fn AccountSpotlightTabs() -> impl IntoView {
         NavEntry {
             href: format!(
                 "/addresses/accounts/{}/{}/commands/internal",
-                MINA_TOKEN_ADDRESS,
+                mina_token_address,
                 id()
             ),
             text: "Internal Commands".to_string(),
@@ -486,7 +509,7 @@ pub fn AccountSpotlightTabs() -> impl IntoView {
         NavEntry {
             href: format!(
                 "/addresses/accounts/{}/{}/snark-jobs",
-                MINA_TOKEN_ADDRESS,
+                mina_token_address,
                 id()
             ),
             text: "SNARK Jobs".to_string(),
@@ -499,7 +522,7 @@ pub fn AccountSpotlightTabs() -> impl IntoView {
         NavEntry {
             href: format!(
                 "/addresses/accounts/{}/{}/block-production",
-                MINA_TOKEN_ADDRESS,
+                mina_token_address,
                 id()
             ),
             text: "Block Production".to_string(),
@@ -512,7 +535,7 @@ pub fn AccountSpotlightTabs() -> impl IntoView {
         NavEntry {
             href: format!(
                 "/addresses/accounts/{}/{}/delegations",
-                MINA_TOKEN_ADDRESS,
+                mina_token_address,
                 id()
             ),
             text: "Delegations".to_string(),
@@ -521,7 +544,7 @@ pub fn AccountSpotlightTabs() -> impl IntoView {
             ..Default::default()
         },
         NavEntry {
-            href: format!("/addresses/accounts/{}/{}/tokens", MINA_TOKEN_ADDRESS, id()),
+            href: format!("/addresses/accounts/{}/{}/tokens", mina_token_address, id()),
             text: "Tokens".to_string(),
             icon: NavIcon::Tokens,
             number_bubble: tokens.get().map(|tokens| tokens.len()), // Wrap in Some
@@ -15,11 +15,11 @@ use crate::{
         },
     },
     common::{
-        constants::{
-            LHS_MAX_DIGIT_PADDING, LHS_MAX_SPACE_FEES, MINA_TOKEN_ADDRESS, QUERY_PARAM_ID,
-        },
+        amount::Amount,
+        constants::{LHS_MAX_DIGIT_PADDING, LHS_MAX_SPACE_FEES, QUERY_PARAM_ID},
         functions::*,
         models::*,
+        network::use_mina_token_address,
         table::TableData,
     },
 };
@@ -31,27 +31,12 @@ impl TableData for Vec<Option<AccountActivityQueryDirectionalTransactions>> {
             .map(|opt_trans| match opt_trans {
                 Some(transaction) => vec![
                     convert_to_span(transaction.get_height()),
-                    if !transaction.get_memo().is_empty() {
-                        convert_array_to_span(vec![
-                            convert_to_copy_link(
-                                transaction.get_hash(),
-                                format!("/commands/{}", transaction.get_hash()),
-                            ),
-                            convert_to_span(transaction.get_memo())
-                                .attr("class", "block text-xs font-light text-slate-400"),
-                        ])
-                        .attr("class", "block")
-                    } else {
-                        convert_to_copy_link(
-                            transaction.get_hash(),
-                            format!("/commands/{}", transaction.get_hash()),
-                        )
-                    },
-                    convert_to_pill(transaction.get_nonce(), ColorVariant::Grey),
-                    convert_to_title(
-                        convert_to_local_timezone_formatted(&transaction.get_date_time()),
-                        transaction.get_date_time(),
+                    convert_to_copy_link(
+                        transaction.get_hash(),
+                        format!("/commands/{}", transaction.get_hash()),
                     ),
+                    convert_to_pill(transaction.get_nonce(), ColorVariant::Grey),
+                    convert_to_date_time(transaction.get_date_time()),
                     convert_to_pill(
                         transaction.get_kind(),
                         match transaction.get_kind().as_str() {
@@ -78,11 +63,31 @@ impl TableData for Vec<Option<AccountActivityQueryDirectionalTransactions>> {
                             .attr("class", "text-xs text-slate-400"),
                     ])
                     .attr("class", "flex flex-col items-start"),
+                    convert_to_span(transaction.get_memo()),
                 ],
                 None => vec![],
             })
             .collect::<Vec<_>>()
     }
+
+    fn get_raw_rows(&self) -> Vec<Vec<String>> {
+        self.iter()
+            .flatten()
+            .map(|transaction| {
+                vec![
+                    opt_to_string(transaction.height),
+                    transaction.get_hash(),
+                    opt_to_string(transaction.nonce),
+                    transaction.get_date_time(),
+                    transaction.get_kind(),
+                    transaction.get_direction(),
+                    transaction.get_counterparty(),
+                    opt_to_string(transaction.amount.map(|a| a.to_mina_string())),
+                    transaction.get_memo(),
+                ]
+            })
+            .collect()
+    }
 }
 
 impl TableData for Vec<Option<AccountActivityQuerySnarks>> {
@@ -95,10 +100,7 @@ impl TableData for Vec<Option<AccountActivityQuerySnarks>> {
                         snark.get_block_state_hash(),
                         format!("/blocks/{}/snark-jobs", snark.get_block_state_hash()),
                     ),
-                    convert_to_title(
-                        convert_to_local_timezone_formatted(&snark.get_date_time()),
-                        snark.get_date_time(),
-                    ),
+                    convert_to_date_time(snark.get_date_time()),
                     convert_to_span(snark.get_prover()),
                     decorate_with_mina_tag(snark.get_fee()),
                 ],
@@ -106,10 +108,26 @@ impl TableData for Vec<Option<AccountActivityQuerySnarks>> {
             })
             .collect::<Vec<_>>()
     }
+
+    fn get_raw_rows(&self) -> Vec<Vec<String>> {
+        self.iter()
+            .flatten()
+            .map(|snark| {
+                vec![
+                    opt_to_string(snark.block_height),
+                    snark.get_block_state_hash(),
+                    snark.get_date_time(),
+                    snark.get_prover(),
+                    opt_to_string(snark.fee.map(|f| f.to_mina_string())),
+                ]
+            })
+            .collect()
+    }
 }
 
 impl TableData for Vec<Option<AccountActivityQueryTokenHolders>> {
     fn get_rows(&self) -> Vec<Vec<HtmlElement<html::AnyElement>>> {
+        let mina_token_address = use_mina_token_address();
         self.iter()
             .map(|opt_token| match opt_token {
                 Some(token) => vec![
@@ -136,7 +154,7 @@ impl TableData for Vec<Option<AccountActivityQueryTokenHolders>> {
                                 "see more".to_string(),
                                 format!(
                                     "/addresses/accounts/{}/{}/tokens/{}",
-                                    MINA_TOKEN_ADDRESS, token.account.public_key, token.token
+                                    mina_token_address, token.account.public_key, token.token
                                 ),
                             )
                         })
@@ -146,6 +164,22 @@ impl TableData for Vec<Option<AccountActivityQueryTokenHolders>> {
             })
             .collect::<Vec<_>>()
     }
+
+    fn get_raw_rows(&self) -> Vec<Vec<String>> {
+        self.iter()
+            .flatten()
+            .map(|token| {
+                vec![
+                    token.symbol.to_string(),
+                    token.token.to_string(),
+                    token.account.balance.to_string(),
+                    token.account.nonce.to_string(),
+                    token.account.delegate.to_string(),
+                    String::new(),
+                ]
+            })
+            .collect()
+    }
 }
 
 pub trait SnarkTrait {
@@ -181,10 +215,7 @@ impl SnarkTrait for AccountActivityQuerySnarks {
             .map_or_else(String::new, |f| f.to_string())
     }
     fn get_fee(&self) -> String {
-        self.fee
-            .map(|f| f.round() as u64)
-            .map(nanomina_to_mina)
-            .unwrap_or_default()
+        self.fee.map(|f| f.format()).unwrap_or_default()
     }
 }
 
@@ -223,10 +254,7 @@ impl TableData for Vec<Option<AccountActivityQueryBlocks>> {
                         format!("/blocks/{}/spotlight", block.get_state_hash()),
                     ),
                     convert_to_pill(block.get_slot(), ColorVariant::Grey),
-                    convert_to_title(
-                        convert_to_local_timezone_formatted(&block.get_date_time()),
-                        block.get_date_time(),
-                    ),
+                    convert_to_date_time(block.get_date_time()),
                     convert_to_span(block.get_creator_account()),
                     decorate_with_mina_tag(block.get_coinbase()),
                     convert_to_pill(block.get_transaction_count(), ColorVariant::Blue),
@@ -240,6 +268,37 @@ impl TableData for Vec<Option<AccountActivityQueryBlocks>> {
             })
             .collect()
     }
+
+    fn get_raw_rows(&self) -> Vec<Vec<String>> {
+        self.iter()
+            .flatten()
+            .map(|block| {
+                vec![
+                    opt_to_string(block.block_height),
+                    block.get_state_hash(),
+                    opt_to_string(
+                        block
+                            .protocol_state
+                            .as_ref()
+                            .and_then(|o| o.consensus_state.as_ref())
+                            .and_then(|o| o.slot_since_genesis),
+                    ),
+                    block.get_date_time(),
+                    block.get_creator_account(),
+                    block
+                        .transactions
+                        .as_ref()
+                        .and_then(|o| o.coinbase.as_deref())
+                        .and_then(|c| c.parse::<Amount>().ok())
+                        .map(|c| c.to_mina_string())
+                        .unwrap_or_default(),
+                    block.get_transaction_count(),
+                    block.get_snark_job_count(),
+                    block.get_coinbase_receiver(),
+                ]
+            })
+            .collect()
+    }
 }
 
 pub trait BlockTrait {
@@ -292,7 +351,8 @@ impl BlockTrait for AccountActivityQueryBlocks {
         self.transactions
             .as_ref()
             .and_then(|o| o.coinbase.as_deref())
-            .map(nanomina_str_to_mina)
+            .and_then(|c| c.parse::<Amount>().ok())
+            .map(|c| c.format())
             .unwrap_or_default()
     }
     fn get_coinbase_receiver_username(&self) -> Option<String> {
@@ -338,6 +398,23 @@ impl TableData for Vec<Option<AccountActivityQueryDelegatorExt>> {
             })
             .collect::<Vec<_>>()
     }
+
+    fn get_raw_rows(&self) -> Vec<Vec<String>> {
+        self.iter()
+            .flatten()
+            .map(|stake| {
+                vec![
+                    stake.get_public_key(),
+                    opt_to_string(
+                        stake
+                            .delegated_balance
+                            .map(|b| Amount::from_nanomina(b).to_mina_string()),
+                    ),
+                    stake.get_percent_of_delegation(),
+                ]
+            })
+            .collect()
+    }
 }
 
 impl TableData for Vec<Option<AccountActivityQueryInternalCommands>> {
@@ -352,15 +429,31 @@ impl TableData for Vec<Option<AccountActivityQueryInternalCommands>> {
                     ),
                     decorate_with_mina_tag(internal_command.get_fee()),
                     convert_to_pill(internal_command.get_type(), ColorVariant::Grey),
-                    convert_to_title(
-                        convert_to_local_timezone_formatted(&internal_command.get_block_datetime()),
-                        internal_command.get_block_datetime(),
-                    ),
+                    convert_to_date_time(internal_command.get_block_datetime()),
                 ],
                 None => vec![],
             })
             .collect::<Vec<_>>()
     }
+
+    fn get_raw_rows(&self) -> Vec<Vec<String>> {
+        self.iter()
+            .flatten()
+            .map(|internal_command| {
+                vec![
+                    opt_to_string(internal_command.block_height),
+                    internal_command.get_state_hash(),
+                    opt_to_string(
+                        internal_command
+                            .fee
+                            .map(|f| Amount::from_nanomina(f).to_mina_string()),
+                    ),
+                    internal_command.get_type(),
+                    internal_command.get_block_datetime(),
+                ]
+            })
+            .collect()
+    }
 }
 
 pub trait StakeTrait {
@@ -380,12 +473,8 @@ impl StakeTrait for AccountActivityQueryDelegatorExt {
     }
 
     fn get_delegated_balance(&self) -> String {
-        self.delegated_balance
-            .unwrap_or(0)
-            .try_into()
-            .map(nanomina_to_mina)
-            .map(|number| format_number_for_html(&number, LHS_MAX_DIGIT_PADDING))
-            .unwrap_or_default()
+        let balance = Amount::from_nanomina(self.delegated_balance.unwrap_or(0));
+        format_number_for_html(&balance.format(), LHS_MAX_DIGIT_PADDING)
     }
 
     fn get_percent_of_delegation(&self) -> String {
@@ -414,8 +503,8 @@ impl InternalCommandTrait for AccountActivityQueryInternalCommands {
     }
     fn get_fee(&self) -> String {
         self.fee
-            .map(|i| nanomina_to_mina(i as u64))
-            .map(|number| format_number_for_html(&number, LHS_MAX_SPACE_FEES))
+            .map(Amount::from_nanomina)
+            .map(|fee| format_number_for_html(&fee.format(), LHS_MAX_SPACE_FEES))
             .unwrap_or_default()
     }
     fn get_type(&self) -> String {
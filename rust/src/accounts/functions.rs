@@ -1,45 +1,37 @@
 use super::{
     graphql::{
-        AccountsQuery,
+        AccountsQuery, TokenSymbolQuery,
         accounts_query::{self, AccountSortByInput},
+        token_symbol_query,
     },
-    models::TokenSymbolResponse,
+    models::TokenData,
 };
-use crate::common::{constants::GRAPHQL_ENDPOINT, models::*};
-use graphql_client::reqwest::post_graphql;
+use crate::common::{amount::Amount, client::query_graphql, models::*, network::Network};
 
-pub async fn load_token_symbol(token_id: Option<String>) -> Result<TokenSymbolResponse, MyError> {
-    if token_id.is_none() {
-        return Err(MyError::ParseError("token id must be set".into()));
-    }
-    let query_body = format!(
-        r#"{{"query": "{{ tokens(limit: 1, query: {{ token: \"{}\" }}) {{ symbol num_holders }} }}"}}"#,
-        token_id.unwrap(),
-    );
-    let client = reqwest::Client::new();
-    let response = client
-        .post(GRAPHQL_ENDPOINT)
-        .body(query_body)
-        .send()
-        .await
-        .map_err(|e| MyError::NetworkError(e.to_string()))?;
-
-    if response.status().is_success() {
-        Ok(response
-            .json::<TokenSymbolResponse>()
-            .await
-            .map_err(|e| MyError::ParseError(e.to_string()))?)
-    } else {
-        Err(MyError::NetworkError("Failed to fetch data".into()))
-    }
+pub async fn load_token_symbol(
+    network: &Network,
+    token_id: Option<String>,
+) -> Result<Option<TokenData>, MyError> {
+    let Some(token) = token_id else {
+        return Err(MyError::InvalidInput("token id must be set".into()));
+    };
+    let variables = token_symbol_query::Variables { token };
+    let data = query_graphql::<TokenSymbolQuery>(network, variables).await?;
+    Ok(data
+        .tokens
+        .into_iter()
+        .flatten()
+        .next()
+        .map(TokenData::from))
 }
 
 #[allow(clippy::too_many_arguments)]
 pub async fn load_data(
+    network: &Network,
     limit: Option<i64>,
     public_key: Option<PublicKey>,
     username: Option<String>,
-    mina_balance: Option<f64>,
+    mina_balance: Option<Amount>,
     delegate: Option<String>,
     sort_by: Option<accounts_query::AccountSortByInput>,
     zk_app: Option<bool>,
@@ -58,9 +50,7 @@ pub async fn load_data(
             public_key: public_key.map(|pk| pk.as_str().to_string()),
             username,
             token,
-            balance_lte: mina_balance
-                .map(|mb| mb * 1_000_000_000f64)
-                .map(|nmb| nmb as i64),
+            balance_lte: mina_balance.and_then(|mb| i64::try_from(mb.nanomina()).ok()),
             delegate,
             zkapp: zk_app,
         })
@@ -72,17 +62,5 @@ pub async fn load_data(
         sort_by: sort_by.unwrap_or(AccountSortByInput::BALANCE_DESC),
     };
 
-    let client = reqwest::Client::new();
-
-    let response = post_graphql::<AccountsQuery, _>(&client, GRAPHQL_ENDPOINT, variables)
-        .await
-        .map_err(|e| MyError::NetworkError(e.to_string()))?;
-
-    if let Some(errors) = response.errors {
-        return Err(MyError::GraphQLError(errors));
-    }
-
-    response
-        .data
-        .ok_or(MyError::GraphQLEmpty("No data available".to_string()))
+    query_graphql::<AccountsQuery>(network, variables).await
 }
@@ -3,7 +3,7 @@ use graphql_client::GraphQLQuery;
 
 #[derive(GraphQLQuery)]
 #[graphql(
-    schema_path = "./graphql/schemas/mina-explorer.graphql",
+    schema_path = "./graphql/schemas/mina-explorer.typed.graphql",
     query_path = "./graphql/queries/accounts.graphql",
     response_derives = "Serialize,PartialEq,Debug,Clone",
     skip_serializing_none
@@ -23,3 +23,11 @@ impl Default for accounts_query::AccountQueryInput {
         }
     }
 }
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "./graphql/schemas/mina-explorer.typed.graphql",
+    query_path = "./graphql/queries/token_symbol.graphql",
+    response_derives = "Serialize,PartialEq,Debug,Clone"
+)]
+pub struct TokenSymbolQuery;
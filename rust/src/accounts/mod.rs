@@ -1,6 +1,5 @@
-mod components;
-mod functions;
-mod graphql;
+pub mod functions;
+pub mod graphql;
 pub mod models;
 pub mod page;
 mod table_traits;
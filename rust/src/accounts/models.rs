@@ -1,38 +1,21 @@
+use super::graphql::token_symbol_query::TokenSymbolQueryTokens;
 use crate::common::table::*;
 use serde::*;
 use std::fmt;
 
-#[derive(Deserialize, Serialize, Debug, Clone)]
-pub struct TokenSymbolResponse {
-    pub data: TokensData,
-}
-
-#[derive(Deserialize, Serialize, Debug, Clone)]
-pub struct TokensData {
-    pub tokens: Vec<TokenData>,
-}
-
 #[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
 pub struct TokenData {
     pub symbol: String,
     pub num_holders: u64,
 }
 
-#[derive(Debug, Deserialize, Serialize, Clone)]
-pub struct AllAccountResponse {
-    pub data: Vec<AllAccountSummary>,
-}
-
-#[derive(Debug, Deserialize, Serialize, Clone)]
-pub struct AllAccountSummary {
-    pub pk: String,
-    pub balance: f64,
-    pub delegate: String,
-    pub token: i32,
-    pub nonce: i32,
-    pub voting_for: String,
-    pub public_key: String,
-    pub username: String,
+impl From<TokenSymbolQueryTokens> for TokenData {
+    fn from(token: TokenSymbolQueryTokens) -> Self {
+        TokenData {
+            symbol: token.symbol.unwrap_or_default(),
+            num_holders: token.num_holders as u64,
+        }
+    }
 }
 
 #[derive(Clone)]
@@ -1,7 +1,7 @@
 use super::{graphql::accounts_query, models::TokenData};
 use crate::{
-    accounts::{components::NextAccountsPage, functions::*, models::AccountsSort},
-    common::{components::*, constants::*, models::*, table::*},
+    accounts::{functions::*, models::AccountsSort},
+    common::{amount::Amount, components::*, constants::*, models::*, network::*, table::*},
     summary::models::BlockchainSummary,
 };
 use codee::string::JsonSerdeCodec;
@@ -22,27 +22,30 @@ pub fn AccountsPage() -> impl IntoView {
 
 #[component]
 fn AccountsPageContents() -> impl IntoView {
-    let (summary_sig, _, _) =
-        use_local_storage::<BlockchainSummary, JsonSerdeCodec>(BLOCKCHAIN_SUMMARY_STORAGE_KEY);
+    let (summary_sig, _, _) = use_local_storage::<BlockchainSummary, JsonSerdeCodec>(
+        use_network_storage_key(BLOCKCHAIN_SUMMARY_STORAGE_KEY),
+    );
     let memo_params_map = use_params_map();
     let (data_sig, set_data) = create_signal(None);
     let (account_sig, _) = create_query_signal::<String>(QUERY_PARAM_ACCOUNT);
-    let (balance_sig, _) = create_query_signal::<f64>(QUERY_PARAM_BALANCE);
+    let (balance_sig, _) = create_query_signal::<String>(QUERY_PARAM_BALANCE);
     let (delegate_sig, _) = create_query_signal::<String>(QUERY_PARAM_DELEGATE);
     let (row_limit_sig, _) = create_query_signal::<i64>("row-limit");
     let (sort_dir_sig, _) = create_query_signal::<String>("sort-dir");
     let (q_type_sig, _) = create_query_signal::<String>(QUERY_PARAM_TYPE);
     let (token_sig, set_token) = create_signal::<Option<TokenData>>(None);
+    let network = use_network();
 
     let get_token_id = move || memo_params_map.get().get("token_id").cloned();
+    let is_mina_token = move || {
+        get_token_id().expect("expect token to be a URL param")
+            == network.with_value(|n| n.mina_token_address.clone())
+    };
 
     let public_key_memo = Memo::new(move |_| {
         account_sig
             .get()
-            .and_then(|account| match PublicKey::new(account) {
-                Ok(public_key) => Some(public_key),
-                Err(_) => None,
-            })
+            .and_then(|account| PublicKey::new(account).ok())
     });
     let username_memo = Memo::new(move |_| {
         account_sig
@@ -70,7 +73,16 @@ fn AccountsPageContents() -> impl IntoView {
                 get_token_id(),
             )
         },
-        |(public_key, username, balance, delegate, mut row_limit, sort_dir, q_type, q_token)| async move {
+        move |(
+            public_key,
+            username,
+            balance,
+            delegate,
+            mut row_limit,
+            sort_dir,
+            q_type,
+            q_token,
+        )| async move {
             let s_dir = if let Some(s) = sort_dir.and_then(|s| AccountsSort::try_from(s).ok()) {
                 s
             } else {
@@ -83,10 +95,11 @@ fn AccountsPageContents() -> impl IntoView {
             let is_zk_app = q_type.is_some_and(|p| p == TYPE_SEARCH_OPTION_ZKAPP);
 
             load_data(
+                &network.get_value(),
                 Some(*row_limit.get_or_insert(25i64)),
                 public_key,
                 username,
-                balance,
+                balance.and_then(|b| Amount::from_mina(&b).ok()),
                 delegate,
                 Some(sort_by),
                 Some(is_zk_app),
@@ -95,8 +108,8 @@ fn AccountsPageContents() -> impl IntoView {
             .await
         },
     );
-    let token_resource = create_resource(get_token_id, |token| async move {
-        load_token_symbol(token).await
+    let token_resource = create_resource(get_token_id, move |token| async move {
+        load_token_symbol(&network.get_value(), token).await
     });
 
     let get_data = move || resource.get().and_then(|res| res.ok());
@@ -111,9 +124,7 @@ fn AccountsPageContents() -> impl IntoView {
     });
 
     create_effect(move |_| {
-        if let Some(token) =
-            get_token().and_then(|token_resp| token_resp.data.tokens.first().cloned())
-        {
+        if let Some(token) = get_token().flatten() {
             set_token.set(Some(token));
         }
     });
@@ -128,6 +139,8 @@ fn AccountsPageContents() -> impl IntoView {
             } else {
                 AccountsSort::BalanceDesc
             };
+            // Pages follow a balance upper bound, which only works sorting down.
+            let is_pageable = matches!(s_dir, AccountsSort::BalanceDesc);
             let table_columns: Vec<TableColumn<AnySort>> = vec![
                 TableColumn {
                     column: "Type".to_string(),
@@ -162,6 +175,7 @@ fn AccountsPageContents() -> impl IntoView {
                 },
                 TableColumn {
                     column: "Delegate".to_string(),
+                    validate: Some(PublicKey::validate),
                     width: Some(String::from(TABLE_COL_HASH_WIDTH)),
                     search_type: ColumnSearchType::Text,
                     ..Default::default()
@@ -174,6 +188,7 @@ fn AccountsPageContents() -> impl IntoView {
             ];
             view! {
                 <TableSectionTemplate
+                    table_id="token-accounts"
                     table_columns
                     data_sig
                     metadata=Signal::derive(move || {
@@ -185,8 +200,7 @@ fn AccountsPageContents() -> impl IntoView {
                                 )
                                 .available_records(
                                     move || {
-                                        get_token_id().expect("expect token to be a URL param")
-                                            != MINA_TOKEN_ADDRESS && q_type_sig.get().is_none()
+                                        !is_mina_token() && q_type_sig.get().is_none()
                                     },
                                     token_sig.get().map(|t| t.num_holders).unwrap_or_default(),
                                     Some(
@@ -198,16 +212,14 @@ fn AccountsPageContents() -> impl IntoView {
                                 )
                                 .available_records(
                                     move || {
-                                        get_token_id().expect("expect token to be a URL param")
-                                            == MINA_TOKEN_ADDRESS && q_type_sig.get().is_none()
+                                        is_mina_token() && q_type_sig.get().is_none()
                                     },
                                     summary_sig.get().total_num_mina_accounts,
                                     Some("Total MINA accounts".to_string()),
                                 )
                                 .available_records(
                                     move || {
-                                        get_token_id().expect("expect token to be a URL param")
-                                            == MINA_TOKEN_ADDRESS
+                                        is_mina_token()
                                             && q_type_sig
                                                 .get()
                                                 .as_ref()
@@ -229,13 +241,40 @@ fn AccountsPageContents() -> impl IntoView {
                         .map(|t| format!("{} Token Accounts", t.symbol))
                         .unwrap_or("Accounts".to_string())
                     is_loading=resource.loading()
+                    error=Signal::derive(move || resource.get().and_then(|res| res.err()))
+                    on_retry=Callback::new(move |_| resource.refetch())
                     footer=move || {
-                        view! {
-                            <NextAccountsPage
-                                data=data_sig.get().unwrap_or(vec![])
-                                row_limit=row_limit_sig.get().map(|rl| rl as u64)
-                            />
-                        }
+                        is_pageable
+                            .then(|| {
+                                view! {
+                                    <NextPage
+                                        data_sig
+                                        set_data_sig=set_data
+                                        row_limit=Signal::derive(move || {
+                                            row_limit_sig.get().map(|rl| rl as u64)
+                                        })
+                                        fetch=move |balance, limit| async move {
+                                            load_data(
+                                                    &network.get_value(),
+                                                    Some(limit as i64),
+                                                    public_key_memo.get_untracked(),
+                                                    username_memo.get_untracked(),
+                                                    balance,
+                                                    delegate_sig.get_untracked(),
+                                                    Some(accounts_query::AccountSortByInput::BALANCE_DESC),
+                                                    Some(
+                                                        q_type_sig
+                                                            .get_untracked()
+                                                            .is_some_and(|p| p == TYPE_SEARCH_OPTION_ZKAPP),
+                                                    ),
+                                                    untrack(get_token_id),
+                                                )
+                                                .await
+                                                .map(|data| data.accounts)
+                                        }
+                                    />
+                                }
+                            })
                     }
                     controls=move || {
                         view! {
@@ -1,6 +1,9 @@
 use crate::{
     accounts::graphql::accounts_query::AccountsQueryAccounts,
-    common::{constants::LHS_MAX_DIGIT_PADDING, functions::*, models::*, table::TableData},
+    common::{
+        amount::Amount, constants::LHS_MAX_DIGIT_PADDING, functions::*, models::*,
+        pagination::Paginated, table::TableData,
+    },
 };
 use leptos::*;
 
@@ -27,6 +30,30 @@ impl TableData for Vec<Option<AccountsQueryAccounts>> {
             })
             .collect::<Vec<_>>()
     }
+
+    fn get_raw_rows(&self) -> Vec<Vec<String>> {
+        self.iter()
+            .flatten()
+            .map(|account| {
+                vec![
+                    if account.is_zk_app() {
+                        "Zkapp".to_string()
+                    } else {
+                        String::new()
+                    },
+                    account.get_public_key(),
+                    opt_to_string(
+                        account
+                            .balance
+                            .map(|b| Amount::from_nanomina(b).to_mina_string()),
+                    ),
+                    opt_to_string(account.nonce),
+                    account.get_delegate(),
+                    account.get_timelocked(),
+                ]
+            })
+            .collect()
+    }
 }
 
 pub trait AccountTrait {
@@ -58,8 +85,8 @@ impl AccountTrait for AccountsQueryAccounts {
         self.balance
             .as_ref()
             .cloned()
-            .map(|b| nanomina_to_mina(b as u64))
-            .map(|number| format_number_for_html(&number, LHS_MAX_DIGIT_PADDING))
+            .map(Amount::from_nanomina)
+            .map(|balance| format_number_for_html(&balance.format(), LHS_MAX_DIGIT_PADDING))
             .unwrap_or_default()
             .to_string()
     }
@@ -78,3 +105,15 @@ impl AccountTrait for AccountsQueryAccounts {
             .unwrap()
     }
 }
+
+impl Paginated for AccountsQueryAccounts {
+    type Cursor = Amount;
+
+    fn cursor(&self) -> Option<Amount> {
+        self.balance.map(Amount::from_nanomina)
+    }
+
+    fn key(&self) -> Option<String> {
+        Some(self.get_public_key())
+    }
+}
@@ -0,0 +1,115 @@
+use crate::common::{
+    address_book::*,
+    functions::read_file_text,
+    i18n::{t, tn},
+};
+use leptos::{ev::SubmitEvent, *};
+use web_sys::HtmlInputElement;
+
+const INPUT_CLASS: &str = "h-9 px-2 border border-[#DADCE0] rounded-md text-sm";
+
+/// Adds an entry, or relabels the key if it is already in the address book.
+#[component]
+pub fn AddressBookForm() -> impl IntoView {
+    let set_book = use_address_book().set_book;
+    let (public_key, set_public_key) = create_signal(String::new());
+    let (label, set_label) = create_signal(String::new());
+    let (tags, set_tags) = create_signal(String::new());
+    let (error, set_error) = create_signal(None::<String>);
+
+    let on_submit = move |ev: SubmitEvent| {
+        ev.prevent_default();
+        match AddressBookEntry::new(
+            &public_key.get_untracked(),
+            &label.get_untracked(),
+            &tags.get_untracked(),
+        ) {
+            Ok(entry) => {
+                set_book.update(|book| book.upsert(entry));
+                set_public_key.set(String::new());
+                set_label.set(String::new());
+                set_tags.set(String::new());
+                set_error.set(None);
+            }
+            Err(err) => set_error.set(Some(err)),
+        }
+    };
+
+    view! {
+        <form class="px-8 pb-4 flex flex-wrap gap-4 items-end" on:submit=on_submit>
+            <label class="flex flex-col text-sm font-semibold grow">
+                {t("Public Key")}
+                <input
+                    type="text"
+                    class=INPUT_CLASS
+                    prop:value=public_key
+                    on:input=move |ev| set_public_key.set(event_target_value(&ev))
+                />
+            </label>
+            <label class="flex flex-col text-sm font-semibold">
+                {t("Label")}
+                <input
+                    type="text"
+                    class=INPUT_CLASS
+                    prop:value=label
+                    on:input=move |ev| set_label.set(event_target_value(&ev))
+                />
+            </label>
+            <label class="flex flex-col text-sm font-semibold">
+                {t("Tags")}
+                <input
+                    type="text"
+                    class=INPUT_CLASS
+                    placeholder=t("Comma separated")
+                    prop:value=tags
+                    on:input=move |ev| set_tags.set(event_target_value(&ev))
+                />
+            </label>
+            <input
+                type="submit"
+                class="bg-granola-orange text-white text-sm font-semibold h-9 rounded-md px-6 cursor-pointer"
+                value=t("Save")
+            />
+            {move || {
+                error.get().map(|err| view! { <p class="w-full text-sm text-red-600">{err}</p> })
+            }}
+        </form>
+    }
+}
+
+/// Merges a CSV or JSON file, as exported from the address book table.
+#[component]
+pub fn AddressBookImport() -> impl IntoView {
+    let set_book = use_address_book().set_book;
+    let (status, set_status) = create_signal(None::<String>);
+
+    let on_change = move |ev: ev::Event| {
+        let input = event_target::<HtmlInputElement>(&ev);
+        let Some(file) = input.files().and_then(|files| files.get(0)) else {
+            return;
+        };
+        input.set_value("");
+        read_file_text(file, move |text| match parse_address_book(&text) {
+            Ok(entries) => {
+                set_status.set(Some(tn("{count} entries imported", entries.len() as u64)));
+                set_book.update(|book| book.import(entries));
+            }
+            Err(err) => set_status.set(Some(err)),
+        });
+    };
+
+    view! {
+        <div class="flex items-center text-sm">
+            <label class="cursor-pointer font-semibold text-granola-orange hover:underline">
+                {t("Import")}
+                <input
+                    type="file"
+                    accept=".csv,.json,text/csv,application/json"
+                    class="hidden"
+                    on:change=on_change
+                />
+            </label>
+            {move || status.get().map(|status| view! { <span class="ml-2">{status}</span> })}
+        </div>
+    }
+}
@@ -0,0 +1,3 @@
+mod components;
+pub mod page;
+mod table_trait;
@@ -0,0 +1,62 @@
+use super::components::*;
+use crate::common::{
+    address_book::*,
+    components::*,
+    constants::{TABLE_COL_HASH_WIDTH, TABLE_COL_USERNAME_WIDTH},
+    models::TableMetadata,
+    table::*,
+};
+use leptos::*;
+use leptos_meta::*;
+
+#[component]
+pub fn AddressBookPage() -> impl IntoView {
+    let book = use_address_book().book;
+    let (data_sig, set_data) = create_signal(None);
+
+    create_effect(move |_| {
+        set_data.set(Some(book.get().0.into_iter().map(Some).collect::<Vec<_>>()));
+    });
+
+    let table_columns: Vec<TableColumn<AnySort>> = ADDRESS_BOOK_HEADERS
+        .iter()
+        .zip([
+            TABLE_COL_HASH_WIDTH,
+            TABLE_COL_USERNAME_WIDTH,
+            TABLE_COL_USERNAME_WIDTH,
+        ])
+        .map(|(column, width)| TableColumn {
+            column: column.to_string(),
+            width: Some(String::from(width)),
+            alignment: Some(ColumnTextAlignment::Left),
+            ..Default::default()
+        })
+        .collect();
+
+    view! {
+        <Title text="Address Book | Local Labels" />
+        <PageContainer>
+            <AppSection>
+                <AppHeading heading="Add Address" />
+                <AddressBookForm />
+            </AppSection>
+            <TableSectionTemplate
+                table_id="address-book"
+                table_columns
+                data_sig
+                is_loading=Signal::derive(move || data_sig.get().is_none())
+                section_heading="Address Book"
+                controls=|| view! { <AddressBookImport /> }
+                metadata=Signal::derive(move || {
+                    data_sig
+                        .get()
+                        .map(|data| TableMetadata {
+                            displayed_records: data.len() as u64,
+                            total_records: Some(data.len() as u64),
+                            ..Default::default()
+                        })
+                })
+            />
+        </PageContainer>
+    }
+}
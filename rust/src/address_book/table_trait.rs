@@ -0,0 +1,58 @@
+use crate::{
+    common::{address_book::*, functions::*, i18n::t, network::use_mina_token_address, table::*},
+    icons::CloseIcon,
+};
+use leptos::*;
+
+impl TableData for Vec<Option<AddressBookEntry>> {
+    fn get_rows(&self) -> Vec<Vec<HtmlElement<html::AnyElement>>> {
+        let set_book = use_address_book().set_book;
+        let mina_token_address = use_mina_token_address();
+        self.iter()
+            .map(|opt_entry| match opt_entry {
+                Some(entry) => {
+                    let public_key = entry.public_key.clone();
+                    vec![
+                        convert_to_copy_link(
+                            entry.public_key.clone(),
+                            format!(
+                                "/addresses/accounts/{}/{}/spotlight",
+                                mina_token_address, entry.public_key
+                            ),
+                        ),
+                        convert_array_to_span(vec![
+                            convert_to_span(entry.label.clone()).attr("class", "mr-2"),
+                            view! {
+                                <button
+                                    class="text-slate-400 hover:text-granola-orange"
+                                    title=t("Remove")
+                                    on:click=move |_| {
+                                        set_book.update(|book| book.remove(&public_key))
+                                    }
+                                >
+                                    <CloseIcon width=16 />
+                                </button>
+                            }
+                            .into_any(),
+                        ]),
+                        convert_to_span(entry.tags.join(", ")),
+                    ]
+                }
+                None => vec![],
+            })
+            .collect::<Vec<_>>()
+    }
+
+    fn get_raw_rows(&self) -> Vec<Vec<String>> {
+        self.iter()
+            .flatten()
+            .map(|entry| {
+                vec![
+                    entry.public_key.clone(),
+                    entry.label.clone(),
+                    entry.tags.join(", "),
+                ]
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,308 @@
+use super::{functions::*, models::*};
+use crate::{
+    common::{components::*, constants::QUERY_PARAM_FILTER, i18n::t, models::ButtonStyleVariant},
+    icons::CloseIcon,
+};
+use leptos::*;
+use leptos_router::create_query_signal;
+
+const INPUT_CLASS: &str = "h-8 px-2 border border-[#DADCE0] rounded-md text-sm bg-white";
+const LINK_CLASS: &str = "text-sm text-granola-orange font-semibold";
+
+/// Builds the filter tree of `fields` and applies it through the URL, where
+/// the table's loader picks it up.
+#[component]
+pub fn AdvancedFilterSection(fields: &'static [FilterField]) -> impl IntoView {
+    let (filter_qp, set_filter_qp) = create_query_signal::<String>(QUERY_PARAM_FILTER);
+    let draft = create_rw_signal(
+        filter_qp
+            .get_untracked()
+            .and_then(|json| parse_filter(&json).ok())
+            .unwrap_or_default(),
+    );
+    let (open, set_open) = create_signal(filter_qp.get_untracked().is_some());
+    let (error, set_error) = create_signal(None::<String>);
+
+    let apply = move |_| {
+        let filter = draft.get_untracked();
+        if let Err(err) = check_filter(&filter, fields) {
+            set_error.set(Some(err));
+            return;
+        }
+        set_error.set(None);
+        set_filter_qp.set(
+            (!is_empty(&filter))
+                .then(|| serde_json::to_string(&filter).ok())
+                .flatten(),
+        );
+    };
+    let clear = move |_| {
+        draft.set(FilterNode::default());
+        set_error.set(None);
+        set_filter_qp.set(None);
+    };
+
+    view! {
+        <AppSection>
+            <span class="w-full flex justify-between items-center pr-4">
+                <AppHeading heading="Advanced Filter" />
+                <button class=LINK_CLASS on:click=move |_| set_open.update(|open| *open = !*open)>
+                    {move || if open.get() { t("Hide") } else { t("Show") }}
+                </button>
+            </span>
+            <Show when=move || open.get()>
+                <div class="advanced-filter px-8 pb-4 flex flex-col gap-2 text-sm">
+                    {move || node_view(draft, vec![], &draft.get(), fields)}
+                    {move || {
+                        error.get().map(|err| view! { <p class="text-red-600">{err}</p> })
+                    }}
+                    <div class="flex gap-2">
+                        <Button text=t("Apply") on_click=apply />
+                        <Button
+                            text=t("Clear")
+                            style_variant=ButtonStyleVariant::Tertiary
+                            on_click=clear
+                        />
+                    </div>
+                </div>
+            </Show>
+        </AppSection>
+    }
+}
+
+/// Checks every condition without building a query input.
+fn check_filter(node: &FilterNode, fields: &[FilterField]) -> Result<(), String> {
+    match node {
+        FilterNode::And(children) | FilterNode::Or(children) => children
+            .iter()
+            .try_for_each(|child| check_filter(child, fields)),
+        FilterNode::Condition(condition) => fields
+            .iter()
+            .find(|field| field.key == condition.field)
+            .ok_or_else(|| format!("Unknown field: {}", condition.field))
+            .and_then(|field| condition_value(field, condition))
+            .map(|_| ()),
+    }
+}
+
+fn node_view(
+    draft: RwSignal<FilterNode>,
+    path: Vec<usize>,
+    node: &FilterNode,
+    fields: &'static [FilterField],
+) -> View {
+    match node {
+        FilterNode::Condition(condition) => condition_view(draft, path, condition, fields),
+        FilterNode::And(children) | FilterNode::Or(children) => {
+            let all = matches!(node, FilterNode::And(_));
+            let is_root = path.is_empty();
+            let (add_path, all_path, remove_path) = (path.clone(), path.clone(), path.clone());
+            let add = move |child: FilterNode| {
+                let path = add_path.clone();
+                draft.update(|root| {
+                    if let Some(FilterNode::And(children) | FilterNode::Or(children)) =
+                        node_at_mut(root, &path)
+                    {
+                        children.push(child);
+                    }
+                })
+            };
+            let add_group = add.clone();
+            view! {
+                <div
+                    class="filter-group flex flex-col gap-2"
+                    class=("border-l-2", !is_root)
+                    class=("border-slate-200", !is_root)
+                    class=("pl-3", !is_root)
+                >
+                    <div class="flex items-center gap-2">
+                        <select
+                            class=INPUT_CLASS
+                            on:change=move |ev| {
+                                let all = event_target_value(&ev) == "and";
+                                draft.update(|root| set_all(root, &all_path, all))
+                            }
+                        >
+                            <option value="and" selected=all>
+                                {t("AND")}
+                            </option>
+                            <option value="or" selected=!all>
+                                {t("OR")}
+                            </option>
+                        </select>
+                        <button class=LINK_CLASS on:click=move |_| add(new_condition(fields))>
+                            {format!("+ {}", t("Condition"))}
+                        </button>
+                        <button
+                            class=LINK_CLASS
+                            on:click=move |_| add_group(FilterNode::Or(vec![new_condition(fields)]))
+                        >
+                            {format!("+ {}", t("Group"))}
+                        </button>
+                        {(!is_root)
+                            .then(|| {
+                                view! {
+                                    <button
+                                        title=t("Remove")
+                                        on:click=move |_| {
+                                            draft.update(|root| remove_node(root, &remove_path))
+                                        }
+                                    >
+                                        <CloseIcon width=16 />
+                                    </button>
+                                }
+                            })}
+                    </div>
+                    {children
+                        .iter()
+                        .enumerate()
+                        .map(|(index, child)| {
+                            let mut child_path = path.clone();
+                            child_path.push(index);
+                            node_view(draft, child_path, child, fields)
+                        })
+                        .collect_view()}
+                </div>
+            }
+            .into_view()
+        }
+    }
+}
+
+fn condition_view(
+    draft: RwSignal<FilterNode>,
+    path: Vec<usize>,
+    condition: &Condition,
+    fields: &'static [FilterField],
+) -> View {
+    let field = fields
+        .iter()
+        .find(|field| field.key == condition.field)
+        .copied()
+        .unwrap_or(fields[0]);
+    let update = {
+        let path = path.clone();
+        move |f: &dyn Fn(&mut Condition)| {
+            draft.update(|root| {
+                if let Some(FilterNode::Condition(condition)) = node_at_mut(root, &path) {
+                    f(condition)
+                }
+            })
+        }
+    };
+    let (update_op, update_value) = (update.clone(), update.clone());
+    let op = condition.op;
+    let value_input = match field.kind {
+        _ if !op.takes_value() => ().into_view(),
+        FieldKind::Options(options) if !op.takes_list() => {
+            value_select(options, condition.value.clone(), update_value)
+        }
+        FieldKind::Bool => value_select(&["true", "false"], condition.value.clone(), update_value),
+        _ => view! {
+            <input
+                type="text"
+                class=INPUT_CLASS.to_string() + " grow font-mono"
+                placeholder=if op.takes_list() { t("Comma separated") } else { String::new() }
+                value=condition.value.clone()
+                on:change=move |ev| {
+                    let value = event_target_value(&ev);
+                    update_value(&|condition| condition.value = value.clone())
+                }
+            />
+        }
+        .into_view(),
+    };
+
+    view! {
+        <div class="filter-condition flex flex-wrap items-center gap-2">
+            <select
+                class=INPUT_CLASS
+                on:change=move |ev| {
+                    let key = event_target_value(&ev);
+                    let Some(field) = fields.iter().find(|field| field.key == key) else {
+                        return;
+                    };
+                    update(&|condition| {
+                        condition.field = field.key.to_string();
+                        if !field.operators.contains(&condition.op) {
+                            condition.op = field.operators[0];
+                        }
+                        condition.value = default_value(field, condition.op);
+                    })
+                }
+            >
+                {fields
+                    .iter()
+                    .map(|option| {
+                        view! {
+                            <option value=option.key selected=option.key == field.key>
+                                {t(option.label)}
+                            </option>
+                        }
+                    })
+                    .collect_view()}
+            </select>
+            <select
+                class=INPUT_CLASS
+                on:change=move |ev| {
+                    if let Some(op) = Operator::from_key(&event_target_value(&ev)) {
+                        update_op(&|condition| {
+                            if condition.op.takes_list() != op.takes_list() {
+                                condition.value = default_value(&field, op);
+                            }
+                            condition.op = op;
+                        })
+                    }
+                }
+            >
+                {field
+                    .operators
+                    .iter()
+                    .map(|option| {
+                        view! {
+                            <option value=option.key() selected=*option == op>
+                                {t(option.label())}
+                            </option>
+                        }
+                    })
+                    .collect_view()}
+            </select>
+            {value_input}
+            <button
+                title=t("Remove")
+                on:click=move |_| draft.update(|root| remove_node(root, &path))
+            >
+                <CloseIcon width=16 />
+            </button>
+        </div>
+    }
+    .into_view()
+}
+
+fn value_select(
+    options: &'static [&'static str],
+    current: String,
+    update: impl Fn(&dyn Fn(&mut Condition)) + 'static,
+) -> View {
+    view! {
+        <select
+            class=INPUT_CLASS
+            on:change=move |ev| {
+                let value = event_target_value(&ev);
+                update(&|condition| condition.value = value.clone())
+            }
+        >
+            {options
+                .iter()
+                .map(|option| {
+                    view! {
+                        <option value=*option selected=*option == current>
+                            {*option}
+                        </option>
+                    }
+                })
+                .collect_view()}
+        </select>
+    }
+    .into_view()
+}
@@ -0,0 +1,464 @@
+use super::models::*;
+use crate::common::{amount::Amount, constants::QUERY_PARAM_FILTER};
+use leptos_router::ParamsMap;
+
+/// A generated GraphQL query input that a filter tree can be turned into.
+pub trait FilterTarget: Default {
+    const FIELDS: &'static [FilterField];
+
+    /// Combines `children` with `AND` when `all` is set, or with `OR`.
+    fn group(all: bool, children: Vec<Self>) -> Self;
+
+    /// A default input with the one condition set. `NotExists` arrives as
+    /// `Exists` with a false flag.
+    fn condition(field: &str, op: Operator, value: FilterValue) -> Result<Self, String>;
+}
+
+/// Converts a checked value into the type of a query input field.
+pub trait FromFilterValue: Sized {
+    fn from_filter_value(value: FilterValue) -> Result<Self, String>;
+}
+
+fn parse<T: std::str::FromStr>(value: &str) -> Result<T, String> {
+    value
+        .parse()
+        .map_err(|_| format!("Invalid value: {}", value))
+}
+
+fn single(value: FilterValue) -> Result<String, String> {
+    match value {
+        FilterValue::One(value) => Ok(value),
+        FilterValue::Flag(flag) => Ok(flag.to_string()),
+        FilterValue::Many(_) => Err("Expected a single value".to_string()),
+    }
+}
+
+fn list(value: FilterValue) -> Result<Vec<String>, String> {
+    match value {
+        FilterValue::Many(values) => Ok(values),
+        _ => Err("Expected a list of values".to_string()),
+    }
+}
+
+impl FromFilterValue for Option<String> {
+    fn from_filter_value(value: FilterValue) -> Result<Self, String> {
+        single(value).map(Some)
+    }
+}
+
+impl FromFilterValue for Option<bool> {
+    fn from_filter_value(value: FilterValue) -> Result<Self, String> {
+        single(value).and_then(|value| parse(&value)).map(Some)
+    }
+}
+
+impl FromFilterValue for Option<i64> {
+    fn from_filter_value(value: FilterValue) -> Result<Self, String> {
+        single(value).and_then(|value| parse(&value)).map(Some)
+    }
+}
+
+impl FromFilterValue for Option<Amount> {
+    fn from_filter_value(value: FilterValue) -> Result<Self, String> {
+        single(value).and_then(|value| parse(&value)).map(Some)
+    }
+}
+
+impl FromFilterValue for Option<Vec<Option<String>>> {
+    fn from_filter_value(value: FilterValue) -> Result<Self, String> {
+        list(value).map(|values| Some(values.into_iter().map(Some).collect()))
+    }
+}
+
+impl FromFilterValue for Option<Vec<Option<i64>>> {
+    fn from_filter_value(value: FilterValue) -> Result<Self, String> {
+        list(value)?
+            .iter()
+            .map(|value| parse(value).map(Some))
+            .collect::<Result<_, _>>()
+            .map(Some)
+    }
+}
+
+impl FromFilterValue for Option<Vec<Option<Amount>>> {
+    fn from_filter_value(value: FilterValue) -> Result<Self, String> {
+        list(value)?
+            .iter()
+            .map(|value| parse(value).map(Some))
+            .collect::<Result<_, _>>()
+            .map(Some)
+    }
+}
+
+/// Sets the field of `$input` that `$op` maps to, e.g.
+/// `set_field!(input, op, value, Eq => from, In => from_in)`.
+macro_rules! set_field {
+    ($input:ident, $op:expr, $value:expr, $($variant:ident => $field:ident),+ $(,)?) => {
+        match $op {
+            $($crate::advanced_filter::models::Operator::$variant => {
+                $input.$field =
+                    $crate::advanced_filter::functions::FromFilterValue::from_filter_value($value)?
+            })+
+            op => return Err(format!("Unsupported operator: {}", op.label())),
+        }
+    };
+}
+pub(crate) use set_field;
+
+/// Checks a condition against its field, returning the value to query with.
+pub fn condition_value(field: &FilterField, condition: &Condition) -> Result<FilterValue, String> {
+    if !field.operators.contains(&condition.op) {
+        return Err(format!(
+            "{} cannot be compared with \"{}\"",
+            field.label,
+            condition.op.label()
+        ));
+    }
+    match condition.op {
+        Operator::Exists => return Ok(FilterValue::Flag(true)),
+        Operator::NotExists => return Ok(FilterValue::Flag(false)),
+        _ => {}
+    }
+    let check = |value: &str| -> Result<String, String> {
+        match field.kind {
+            FieldKind::Text(validate) => validate
+                .map_or(Ok(()), |validate| validate(value))
+                .map(|_| value.to_string()),
+            FieldKind::Integer => value
+                .parse::<i64>()
+                .map(|number| number.to_string())
+                .map_err(|_| format!("Invalid number for {}: {}", field.label, value)),
+            FieldKind::Mina => Amount::from_mina(value).map(|amount| amount.nanomina().to_string()),
+            FieldKind::Bool => value
+                .parse::<bool>()
+                .map(|flag| flag.to_string())
+                .map_err(|_| format!("Invalid value for {}: {}", field.label, value)),
+            FieldKind::Options(options) => options
+                .contains(&value)
+                .then(|| value.to_string())
+                .ok_or_else(|| format!("Invalid value for {}: {}", field.label, value)),
+        }
+    };
+    let values = condition
+        .value
+        .split(if condition.op.takes_list() { ',' } else { '\n' })
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+        .map(check)
+        .collect::<Result<Vec<_>, _>>()?;
+    match values.as_slice() {
+        [] => Err(format!("Missing value for {}", field.label)),
+        _ if condition.op.takes_list() => Ok(FilterValue::Many(values)),
+        [value] => Ok(FilterValue::One(value.clone())),
+        _ => Err(format!("Expected a single value for {}", field.label)),
+    }
+}
+
+/// Turns a filter tree into a query input. Empty groups match everything.
+pub fn to_query_input<T: FilterTarget>(node: &FilterNode) -> Result<T, String> {
+    match node {
+        FilterNode::And(children) | FilterNode::Or(children) => {
+            let children = children
+                .iter()
+                .map(to_query_input)
+                .collect::<Result<Vec<T>, _>>()?;
+            Ok(if children.is_empty() {
+                T::default()
+            } else {
+                T::group(matches!(node, FilterNode::And(_)), children)
+            })
+        }
+        FilterNode::Condition(condition) => {
+            let field = T::FIELDS
+                .iter()
+                .find(|field| field.key == condition.field)
+                .ok_or_else(|| format!("Unknown field: {}", condition.field))?;
+            let value = condition_value(field, condition)?;
+            let op = match condition.op {
+                Operator::NotExists => Operator::Exists,
+                op => op,
+            };
+            T::condition(field.key, op, value)
+        }
+    }
+}
+
+pub fn parse_filter(json: &str) -> Result<FilterNode, String> {
+    serde_json::from_str(json).map_err(|e| format!("Invalid filter: {}", e))
+}
+
+/// The URL's advanced filter as a query input, if it is set and valid.
+pub fn get_advanced_filter<T: FilterTarget>(query_params_map: &ParamsMap) -> Option<T> {
+    query_params_map
+        .get(QUERY_PARAM_FILTER)
+        .and_then(|json| parse_filter(json).ok())
+        .and_then(|node| to_query_input(&node).ok())
+}
+
+/// What the editor shows before anything is entered. Fields with a fixed
+/// set of values start on the first one.
+pub fn default_value(field: &FilterField, op: Operator) -> String {
+    match field.kind {
+        FieldKind::Options(options) if !op.takes_list() => options[0].to_string(),
+        FieldKind::Bool => true.to_string(),
+        _ => String::new(),
+    }
+}
+
+/// A condition on the first of `fields`, for the editor to start from.
+pub fn new_condition(fields: &[FilterField]) -> FilterNode {
+    let field = &fields[0];
+    FilterNode::Condition(Condition {
+        field: field.key.to_string(),
+        op: field.operators[0],
+        value: default_value(field, field.operators[0]),
+    })
+}
+
+/// The node at `path`, given as child indices from the root.
+pub fn node_at_mut<'a>(node: &'a mut FilterNode, path: &[usize]) -> Option<&'a mut FilterNode> {
+    match (path.split_first(), node) {
+        (None, node) => Some(node),
+        (Some((index, rest)), FilterNode::And(children) | FilterNode::Or(children)) => children
+            .get_mut(*index)
+            .and_then(|child| node_at_mut(child, rest)),
+        _ => None,
+    }
+}
+
+pub fn remove_node(root: &mut FilterNode, path: &[usize]) {
+    let Some((index, parent)) = path.split_last() else {
+        return;
+    };
+    if let Some(FilterNode::And(children) | FilterNode::Or(children)) = node_at_mut(root, parent) {
+        if *index < children.len() {
+            children.remove(*index);
+        }
+    }
+}
+
+/// Switches the group at `path` between `AND` and `OR`.
+pub fn set_all(root: &mut FilterNode, path: &[usize], all: bool) {
+    if let Some(node) = node_at_mut(root, path) {
+        *node = match std::mem::take(node) {
+            FilterNode::And(children) | FilterNode::Or(children) if all => {
+                FilterNode::And(children)
+            }
+            FilterNode::And(children) | FilterNode::Or(children) => FilterNode::Or(children),
+            condition => condition,
+        };
+    }
+}
+
+pub fn is_empty(node: &FilterNode) -> bool {
+    match node {
+        FilterNode::And(children) | FilterNode::Or(children) => children.iter().all(is_empty),
+        FilterNode::Condition(_) => false,
+    }
+}
+
+#[cfg(test)]
+mod advanced_filter_tests {
+    use super::*;
+
+    #[derive(Default, Debug, PartialEq)]
+    struct Input {
+        and: Option<Vec<Input>>,
+        or: Option<Vec<Input>>,
+        kind: Option<String>,
+        kind_ne: Option<String>,
+        from_in: Option<Vec<Option<String>>>,
+        memo_exists: Option<bool>,
+        amount_gte: Option<Amount>,
+        nonce_nin: Option<Vec<Option<i64>>>,
+    }
+
+    impl FilterTarget for Input {
+        const FIELDS: &'static [FilterField] = &[
+            FilterField {
+                key: "kind",
+                label: "Kind",
+                kind: FieldKind::Options(&["PAYMENT", "ZKAPP"]),
+                operators: HASH_OPERATORS,
+            },
+            FilterField {
+                key: "from",
+                label: "From",
+                kind: FieldKind::Text(Some(crate::common::models::PublicKey::validate)),
+                operators: TEXT_OPERATORS,
+            },
+            FilterField {
+                key: "memo",
+                label: "Memo",
+                kind: FieldKind::Text(None),
+                operators: TEXT_OPERATORS,
+            },
+            FilterField {
+                key: "amount",
+                label: "Amount",
+                kind: FieldKind::Mina,
+                operators: AMOUNT_OPERATORS,
+            },
+            FilterField {
+                key: "nonce",
+                label: "Nonce",
+                kind: FieldKind::Integer,
+                operators: NUMBER_OPERATORS,
+            },
+        ];
+
+        fn group(all: bool, children: Vec<Self>) -> Self {
+            if all {
+                Input {
+                    and: Some(children),
+                    ..Default::default()
+                }
+            } else {
+                Input {
+                    or: Some(children),
+                    ..Default::default()
+                }
+            }
+        }
+
+        fn condition(field: &str, op: Operator, value: FilterValue) -> Result<Self, String> {
+            let mut input = Input::default();
+            match field {
+                "kind" => set_field!(input, op, value, Eq => kind, Ne => kind_ne),
+                "from" => set_field!(input, op, value, In => from_in),
+                "memo" => set_field!(input, op, value, Exists => memo_exists),
+                "amount" => set_field!(input, op, value, Gte => amount_gte),
+                "nonce" => set_field!(input, op, value, Nin => nonce_nin),
+                _ => return Err(format!("Unknown field: {}", field)),
+            }
+            Ok(input)
+        }
+    }
+
+    const KEY_A: &str = "B62qrCz3ehCqi8Pn8y3vWC9zYEB9RKsidauv15DeZxhzkxL3bKeba5h";
+    const KEY_B: &str = "B62qrPN5Y5yq8kGE3FbVKbGTdTAJNdtNtB5sNVpxyRwWGcDEhpMzc8g";
+
+    fn condition(field: &str, op: Operator, value: &str) -> FilterNode {
+        FilterNode::Condition(Condition {
+            field: field.to_string(),
+            op,
+            value: value.to_string(),
+        })
+    }
+
+    #[test]
+    fn test_to_query_input() {
+        let filter = FilterNode::And(vec![
+            condition("from", Operator::In, &format!("{}, {},", KEY_A, KEY_B)),
+            condition("kind", Operator::Ne, "ZKAPP"),
+            FilterNode::Or(vec![
+                condition("memo", Operator::NotExists, ""),
+                condition("amount", Operator::Gte, "1.5"),
+            ]),
+        ]);
+        let input = to_query_input::<Input>(&filter).unwrap();
+        assert_eq!(
+            input,
+            Input {
+                and: Some(vec![
+                    Input {
+                        from_in: Some(vec![Some(KEY_A.to_string()), Some(KEY_B.to_string())]),
+                        ..Default::default()
+                    },
+                    Input {
+                        kind_ne: Some("ZKAPP".to_string()),
+                        ..Default::default()
+                    },
+                    Input {
+                        or: Some(vec![
+                            Input {
+                                memo_exists: Some(false),
+                                ..Default::default()
+                            },
+                            Input {
+                                amount_gte: Some(Amount::from_nanomina(1_500_000_000)),
+                                ..Default::default()
+                            },
+                        ]),
+                        ..Default::default()
+                    },
+                ]),
+                ..Default::default()
+            }
+        );
+    }
+
+    #[test]
+    fn test_invalid_conditions() {
+        let check = |node: FilterNode| to_query_input::<Input>(&node).is_err();
+        assert!(check(condition("from", Operator::In, "B62qnotakey")));
+        assert!(check(condition("kind", Operator::Eq, "COINBASE")));
+        assert!(check(condition("kind", Operator::Gt, "ZKAPP")));
+        assert!(check(condition("nonce", Operator::Nin, "1,x")));
+        assert!(check(condition("amount", Operator::Gte, " ")));
+        assert!(check(condition("unknown", Operator::Eq, "1")));
+        // operators the field allows but the input does not support
+        assert!(check(condition("nonce", Operator::Eq, "1")));
+        assert_eq!(
+            to_query_input::<Input>(&condition("nonce", Operator::Nin, "1, 2")).unwrap(),
+            Input {
+                nonce_nin: Some(vec![Some(1), Some(2)]),
+                ..Default::default()
+            }
+        );
+    }
+
+    #[test]
+    fn test_empty_groups_match_everything() {
+        let filter = FilterNode::Or(vec![FilterNode::And(vec![])]);
+        assert!(is_empty(&filter));
+        assert_eq!(
+            to_query_input::<Input>(&filter).unwrap(),
+            Input {
+                or: Some(vec![Input::default()]),
+                ..Default::default()
+            }
+        );
+        assert_eq!(
+            to_query_input::<Input>(&FilterNode::default()).unwrap(),
+            Input::default()
+        );
+    }
+
+    #[test]
+    fn test_url_round_trip() {
+        let filter = FilterNode::And(vec![
+            condition("kind", Operator::Ne, "ZKAPP"),
+            condition("memo", Operator::Exists, ""),
+        ]);
+        let json = serde_json::to_string(&filter).unwrap();
+        assert_eq!(
+            json,
+            r#"{"and":[{"is":{"field":"kind","op":"ne","value":"ZKAPP"}},{"is":{"field":"memo","op":"exists"}}]}"#
+        );
+        assert_eq!(parse_filter(&json).unwrap(), filter);
+        assert!(parse_filter("{\"xor\":[]}").is_err());
+    }
+
+    #[test]
+    fn test_tree_editing() {
+        let mut root = FilterNode::And(vec![
+            condition("kind", Operator::Eq, "ZKAPP"),
+            FilterNode::Or(vec![condition("memo", Operator::Exists, "")]),
+        ]);
+        assert_eq!(
+            node_at_mut(&mut root, &[1, 0]).cloned(),
+            Some(condition("memo", Operator::Exists, ""))
+        );
+        assert!(node_at_mut(&mut root, &[0, 0]).is_none());
+        set_all(&mut root, &[1], true);
+        assert!(matches!(
+            node_at_mut(&mut root, &[1]),
+            Some(FilterNode::And(_))
+        ));
+        remove_node(&mut root, &[1, 0]);
+        remove_node(&mut root, &[0]);
+        assert_eq!(root, FilterNode::And(vec![FilterNode::And(vec![])]));
+        assert!(is_empty(&root));
+    }
+}
@@ -0,0 +1,3 @@
+pub mod components;
+pub mod functions;
+pub mod models;
@@ -0,0 +1,157 @@
+use crate::common::table::Validator;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum Operator {
+    Eq,
+    Ne,
+    In,
+    Nin,
+    Gt,
+    Gte,
+    Lt,
+    Lte,
+    Exists,
+    NotExists,
+}
+
+impl Operator {
+    pub fn key(self) -> &'static str {
+        match self {
+            Operator::Eq => "eq",
+            Operator::Ne => "ne",
+            Operator::In => "in",
+            Operator::Nin => "nin",
+            Operator::Gt => "gt",
+            Operator::Gte => "gte",
+            Operator::Lt => "lt",
+            Operator::Lte => "lte",
+            Operator::Exists => "exists",
+            Operator::NotExists => "not_exists",
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Operator::Eq => "=",
+            Operator::Ne => "≠",
+            Operator::In => "in",
+            Operator::Nin => "not in",
+            Operator::Gt => ">",
+            Operator::Gte => "≥",
+            Operator::Lt => "<",
+            Operator::Lte => "≤",
+            Operator::Exists => "exists",
+            Operator::NotExists => "does not exist",
+        }
+    }
+
+    pub fn from_key(key: &str) -> Option<Operator> {
+        ALL_OPERATORS.into_iter().find(|op| op.key() == key)
+    }
+
+    pub fn takes_value(self) -> bool {
+        !matches!(self, Operator::Exists | Operator::NotExists)
+    }
+
+    /// `In` and `Nin` take comma separated values.
+    pub fn takes_list(self) -> bool {
+        matches!(self, Operator::In | Operator::Nin)
+    }
+}
+
+pub const ALL_OPERATORS: [Operator; 10] = [
+    Operator::Eq,
+    Operator::Ne,
+    Operator::In,
+    Operator::Nin,
+    Operator::Gt,
+    Operator::Gte,
+    Operator::Lt,
+    Operator::Lte,
+    Operator::Exists,
+    Operator::NotExists,
+];
+
+pub const TEXT_OPERATORS: &[Operator] = &[
+    Operator::Eq,
+    Operator::Ne,
+    Operator::In,
+    Operator::Nin,
+    Operator::Exists,
+    Operator::NotExists,
+];
+pub const HASH_OPERATORS: &[Operator] = &[Operator::Eq, Operator::Ne, Operator::In, Operator::Nin];
+pub const NUMBER_OPERATORS: &[Operator] = &[
+    Operator::Eq,
+    Operator::Ne,
+    Operator::In,
+    Operator::Nin,
+    Operator::Gt,
+    Operator::Gte,
+    Operator::Lt,
+    Operator::Lte,
+];
+pub const AMOUNT_OPERATORS: &[Operator] = &[
+    Operator::Gt,
+    Operator::Gte,
+    Operator::Lt,
+    Operator::Lte,
+    Operator::Eq,
+    Operator::Ne,
+];
+pub const BOOL_OPERATORS: &[Operator] = &[Operator::Eq];
+
+#[derive(Clone, Copy)]
+pub enum FieldKind {
+    Text(Option<Validator>),
+    Integer,
+    /// Entered in MINA, queried in nanomina.
+    Mina,
+    Bool,
+    Options(&'static [&'static str]),
+}
+
+/// A field of a GraphQL query input that conditions can be set on.
+#[derive(Clone, Copy)]
+pub struct FilterField {
+    pub key: &'static str,
+    pub label: &'static str,
+    pub kind: FieldKind,
+    pub operators: &'static [Operator],
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct Condition {
+    pub field: String,
+    pub op: Operator,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub value: String,
+}
+
+/// The filter tree, serialised into the URL as JSON, e.g.
+/// `{"and":[{"is":{"field":"kind","op":"ne","value":"PAYMENT"}}]}`.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum FilterNode {
+    And(Vec<FilterNode>),
+    Or(Vec<FilterNode>),
+    #[serde(rename = "is")]
+    Condition(Condition),
+}
+
+impl Default for FilterNode {
+    fn default() -> Self {
+        FilterNode::And(vec![])
+    }
+}
+
+/// A condition's value once checked against its field. Numbers and amounts
+/// are kept as the strings the query input fields parse.
+#[derive(Clone, Debug, PartialEq)]
+pub enum FilterValue {
+    Flag(bool),
+    One(String),
+    Many(Vec<String>),
+}
@@ -1,6 +1,6 @@
 use super::{functions::*, models::*};
 use crate::{
-    common::{components::*, constants::*, functions::*, table::*},
+    common::{components::*, constants::*, functions::*, network::*, table::*},
     summary::models::BlockchainSummary,
 };
 use codee::string::JsonSerdeCodec;
@@ -18,7 +18,7 @@ pub fn CacheBustScript(
     #[prop(optional, default = false)] defer: bool,
 ) -> impl IntoView {
     view! {
-        <script src=format!("{}?v={}", src, get_unix_timestamp().to_string()) defer=defer></script>
+        <script src=format!("{}?v={}", src, chrono::Utc::now().timestamp()) defer=defer></script>
     }
 }
 
@@ -33,8 +33,9 @@ pub fn AnalyticsFilters(#[prop(optional, default = false)] by_block: bool) -> im
     let nav_clone = navigate.clone();
     let location = use_location();
 
-    let (summary_sig, _, _) =
-        use_local_storage::<BlockchainSummary, JsonSerdeCodec>(BLOCKCHAIN_SUMMARY_STORAGE_KEY);
+    let (summary_sig, _, _) = use_local_storage::<BlockchainSummary, JsonSerdeCodec>(
+        use_network_storage_key(BLOCKCHAIN_SUMMARY_STORAGE_KEY),
+    );
 
     let apply = move |_| {
         by_block.then(|| {
@@ -159,10 +160,12 @@ pub fn AnalyticsFilters(#[prop(optional, default = false)] by_block: bool) -> im
 #[component]
 pub fn SnarkerLeaderboard() -> impl IntoView {
     let (epoch_sig, set_epoch) = create_query_signal::<u32>("epoch");
-    let (summary_sig, _, _) =
-        use_local_storage::<BlockchainSummary, JsonSerdeCodec>(BLOCKCHAIN_SUMMARY_STORAGE_KEY);
+    let (summary_sig, _, _) = use_local_storage::<BlockchainSummary, JsonSerdeCodec>(
+        use_network_storage_key(BLOCKCHAIN_SUMMARY_STORAGE_KEY),
+    );
 
     let (sort_dir_sig, _) = create_query_signal::<String>("sort-dir");
+    let network = use_network();
     let resource = create_resource(
         move || (epoch_sig.get(), sort_dir_sig.get()),
         move |(epoch, sort_dir)| async move {
@@ -171,7 +174,13 @@ pub fn SnarkerLeaderboard() -> impl IntoView {
                 .and_then(|s| SnarkerLeaderboardHighestFees::try_from(s).ok());
             let total_fees_sort =
                 sort_dir.and_then(|s| SnarkerLeaderboardTotalFees::try_from(s).ok());
-            load_snarker_leaderboard_data(epoch, total_fees_sort, highest_fee_sort).await
+            load_snarker_leaderboard_data(
+                &network.get_value(),
+                epoch,
+                total_fees_sort,
+                highest_fee_sort,
+            )
+            .await
         },
     );
     let (data_sig, set_data) = create_signal(None);
@@ -192,7 +201,7 @@ pub fn SnarkerLeaderboard() -> impl IntoView {
                 .get()
                 .chain
                 .as_ref()
-                .and_then(|c| c.get(MAINNET_2_CHAIN_ID))
+                .and_then(|c| network.with_value(|n| c.get(&n.chain_id).cloned()))
                 .map(|c| c.latest_epoch)
                 .unwrap_or_default(),
             set_epoch,
@@ -200,12 +209,7 @@ pub fn SnarkerLeaderboard() -> impl IntoView {
     });
 
     create_effect(move |_| {
-        set_data.set(
-            resource
-                .get()
-                .and_then(|res| res.ok())
-                .map(|res| res.data.top_snarkers),
-        );
+        set_data.set(resource.get().and_then(|res| res.ok()));
     });
 
     {
@@ -253,9 +257,12 @@ pub fn SnarkerLeaderboard() -> impl IntoView {
             ];
             view! {
                 <TableSectionTemplate
+                    table_id="snarker-leaderboard"
                     table_columns
                     data_sig
                     is_loading=resource.loading()
+                    error=Signal::derive(move || resource.get().and_then(|res| res.err()))
+                    on_retry=Callback::new(move |_| resource.refetch())
                     section_heading="Snarker Leaderboard"
                     controls=move || {
                         epoch_sig.get();
@@ -297,10 +304,11 @@ pub fn SnarkerLeaderboard() -> impl IntoView {
 pub fn SnarkFees() -> impl IntoView {
     let (blockheight_lte_sig, _) = create_query_signal::<u64>(QUERY_PARAM_BLOCKHEIGHT_LTE);
     let (blockheight_gte_sig, _) = create_query_signal::<u64>(QUERY_PARAM_BLOCKHEIGHT_GTE);
+    let network = use_network();
     let resource = create_resource(
         move || (blockheight_lte_sig.get(), blockheight_gte_sig.get()),
         move |(blockheight_lte, blockheight_gte)| async move {
-            load_snark_fees(blockheight_lte, blockheight_gte).await
+            load_snark_fees(&network.get_value(), blockheight_lte, blockheight_gte).await
         },
     );
     let (data_sig, set_data) = create_signal(None);
@@ -310,7 +318,7 @@ pub fn SnarkFees() -> impl IntoView {
             resource
                 .get()
                 .and_then(|res| res.ok())
-                .map(|data| SnarkStatsContainer::from(data.data.blocks)),
+                .map(SnarkStatsContainer::from),
         ));
     });
 
@@ -332,9 +340,12 @@ pub fn SnarkFees() -> impl IntoView {
             ];
             view! {
                 <TableSectionTemplate
+                    table_id="snark-fees"
                     table_columns
                     data_sig
                     is_loading=resource.loading()
+                    error=Signal::derive(move || resource.get().and_then(|res| res.err()))
+                    on_retry=Callback::new(move |_| resource.refetch())
                     section_heading="SNARK Fees Overview"
                 />
             }
@@ -1,66 +1,59 @@
-use super::models::*;
-use crate::common::{constants::*, models::MyError};
+use super::{
+    graphql::{SnarkFeesQuery, TopSnarkersQuery, snark_fees_query, top_snarkers_query},
+    models::*,
+};
+use crate::common::{client::query_graphql, models::MyError, network::Network};
 
 pub async fn load_snark_fees(
+    network: &Network,
     blockheight_lte: Option<u64>,
     blockheight_gte: Option<u64>,
-) -> Result<SnarkFeesResponse, MyError> {
-    if blockheight_lte.is_none() || blockheight_gte.is_none() {
-        return Err(MyError::ParseError("Block limits must be set".into()));
-    }
-    let query_body = format!(
-        r#"{{"query":"query SnarkFeesQuery(\n  $limit: Int = 100\n, $query: BlockQueryInput!) {{\n  blocks(limit: $limit, query: $query) {{\n    blockHeight\n    snarkFees\n    snarkJobs{{\n fee }}\n}}\n}}\n","variables":{{"limit": 10000000, "query": {{ "blockHeight_lte": {}, "blockHeight_gte": {} }} }},"operationName":"SnarkFeesQuery"}}"#,
-        blockheight_lte.unwrap(),
-        blockheight_gte.unwrap()
-    );
-    let client = reqwest::Client::new();
-    let response = client
-        .post(GRAPHQL_ENDPOINT)
-        .body(query_body)
-        .send()
-        .await
-        .map_err(|e| MyError::NetworkError(e.to_string()))?;
-
-    if response.status().is_success() {
-        Ok(response
-            .json::<SnarkFeesResponse>()
-            .await
-            .map_err(|e| MyError::ParseError(e.to_string()))?)
-    } else {
-        Err(MyError::NetworkError("Failed to fetch data".into()))
-    }
+) -> Result<Vec<SnarkFeeData>, MyError> {
+    let (Some(blockheight_lte), Some(blockheight_gte)) = (blockheight_lte, blockheight_gte) else {
+        return Err(MyError::InvalidInput("Block limits must be set".into()));
+    };
+    let variables = snark_fees_query::Variables {
+        blockheight_lte: blockheight_lte as i64,
+        blockheight_gte: blockheight_gte as i64,
+    };
+    let data = query_graphql::<SnarkFeesQuery>(network, variables).await?;
+    Ok(data
+        .blocks
+        .into_iter()
+        .flatten()
+        .map(SnarkFeeData::from)
+        .collect())
 }
 
 pub async fn load_snarker_leaderboard_data(
+    network: &Network,
     epoch: Option<u32>,
     sort_by_total_fees_opt: Option<SnarkerLeaderboardTotalFees>,
     sort_by_highest_fee_opt: Option<SnarkerLeaderboardHighestFees>,
-) -> Result<SnarkerLeaderboardResponse, MyError> {
-    if epoch.is_none() {
-        return Err(MyError::ParseError("Epoch must not be None".into()));
-    }
-    let query_body = format!(
-        r#"{{"query":"query TopSnarkers($query: TopSnarkersQueryInput!, $limit: Int = 50, $sort_by: TopSnarkersSortByInput!) {{ topSnarkers(query: $query, limit: $limit, sortBy: $sort_by) {{ username public_key total_fees epoch_fees min_fee epoch_min_fee max_fee epoch_max_fee snarks_sold epoch_snarks_sold }} }}","variables":{{"limit": 50, "sort_by": "{}", "query": {{ "epoch": {} }} }},"operationName":"TopSnarkers"}}"#,
-        sort_by_total_fees_opt
-            .map(|s| s.to_string())
-            .or_else(|| sort_by_highest_fee_opt.map(|s| s.to_string()))
-            .unwrap_or(SnarkerLeaderboardHighestFees::HighestFeeDesc.to_string()),
-        epoch.unwrap()
-    );
-    let client = reqwest::Client::new();
-    let response = client
-        .post(GRAPHQL_ENDPOINT)
-        .body(query_body)
-        .send()
-        .await
-        .map_err(|e| MyError::NetworkError(e.to_string()))?;
-
-    if response.status().is_success() {
-        Ok(response
-            .json::<SnarkerLeaderboardResponse>()
-            .await
-            .map_err(|e| MyError::ParseError(e.to_string()))?)
-    } else {
-        Err(MyError::NetworkError("Failed to fetch data".into()))
-    }
+) -> Result<Vec<TopSnarkerStat>, MyError> {
+    let Some(epoch) = epoch else {
+        return Err(MyError::InvalidInput("Epoch must not be None".into()));
+    };
+    let sort_by = sort_by_total_fees_opt
+        .and_then(|s| top_snarkers_query::TopSnarkersSortByInput::try_from(s).ok())
+        .or_else(|| {
+            sort_by_highest_fee_opt
+                .and_then(|s| top_snarkers_query::TopSnarkersSortByInput::try_from(s).ok())
+        })
+        .unwrap_or(top_snarkers_query::TopSnarkersSortByInput::MAX_FEE_DESC);
+    let variables = top_snarkers_query::Variables {
+        query: top_snarkers_query::TopSnarkersQueryInput {
+            epoch: epoch as i64,
+        },
+        limit: Some(50),
+        sort_by,
+    };
+    let data = query_graphql::<TopSnarkersQuery>(network, variables).await?;
+    Ok(data
+        .top_snarkers
+        .unwrap_or_default()
+        .into_iter()
+        .flatten()
+        .map(TopSnarkerStat::from)
+        .collect())
 }
@@ -0,0 +1,19 @@
+use graphql_client::GraphQLQuery;
+
+type Long = u64;
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "./graphql/schemas/mina-explorer.typed.graphql",
+    query_path = "./graphql/queries/snark_fees.graphql",
+    response_derives = "Serialize,PartialEq,Debug,Clone"
+)]
+pub struct SnarkFeesQuery;
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "./graphql/schemas/mina-explorer.typed.graphql",
+    query_path = "./graphql/queries/snarker_leaderboard.graphql",
+    response_derives = "Serialize,PartialEq,Debug,Clone"
+)]
+pub struct TopSnarkersQuery;
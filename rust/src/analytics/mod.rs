@@ -1,5 +1,6 @@
 mod components;
 mod functions;
+pub mod graphql;
 pub mod models;
 pub mod page;
 pub mod staker_leaderboard;
@@ -1,17 +1,18 @@
-use crate::common::table::{AnySort, CycleSort, SortDirection};
+use super::graphql::{
+    snark_fees_query::SnarkFeesQueryBlocks,
+    top_snarkers_query::{TopSnarkersQueryTopSnarkers, TopSnarkersSortByInput},
+};
+use crate::common::{
+    amount::Amount,
+    table::{AnySort, CycleSort, SortDirection},
+};
 use serde::*;
 use statrs::statistics::{Data, Distribution, OrderStatistics};
 use std::fmt;
 
-#[derive(Deserialize, Serialize, Debug, Clone)]
-pub struct BlockAnalyticsData {
-    pub epoch_num_blocks: i64,
-    pub total_num_blocks: i64,
-}
-
 #[derive(Deserialize, Serialize, Debug, Clone)]
 pub struct SnarkJob {
-    fee: u64,
+    fee: Amount,
 }
 
 #[derive(Deserialize, Serialize, Debug, Clone)]
@@ -31,42 +32,47 @@ pub struct SnarkStatsContainer {
 #[derive(Deserialize, Serialize, Debug, Clone)]
 pub struct SnarkStats {
     pub count: usize,
-    pub sum: f64,
-    pub mean: Option<f64>,
-    pub median: f64,
+    pub sum: Amount,
+    pub mean: Option<Amount>,
+    pub median: Amount,
     pub std_dev: Option<f64>,
-    pub min: Option<f64>,
-    pub max: Option<f64>,
-    pub lower_quartile: f64,
-    pub upper_quartile: f64,
+    pub min: Option<Amount>,
+    pub max: Option<Amount>,
+    pub lower_quartile: Amount,
+    pub upper_quartile: Amount,
 }
 
 impl SnarkStats {
     pub fn new(data: Vec<SnarkFeeData>) -> Self {
-        let snark_jobs: Vec<f64> = data
+        let fees = data
             .iter()
-            .flat_map(|snark_data| snark_data.snark_jobs.iter().map(|j| j.fee as f64))
+            .flat_map(|snark_data| snark_data.snark_jobs.iter().map(|j| j.fee))
             .collect::<Vec<_>>();
-        let snark_jobs_data = Data::new(snark_jobs.clone());
+        // Averages and quartiles interpolate, so they are computed in floating
+        // point and rounded back to whole nanomina.
+        let fees_data = Data::new(
+            fees.iter()
+                .map(|fee| fee.nanomina() as f64)
+                .collect::<Vec<_>>(),
+        );
         Self {
-            count: snark_jobs.len(),
-            sum: snark_jobs.iter().sum(),
-            mean: snark_jobs_data.mean(),
-            median: snark_jobs_data.clone().median(),
-            std_dev: snark_jobs_data.std_dev(),
-            min: snark_jobs
-                .clone()
-                .into_iter()
-                .reduce(|arg0, arg1| f32::min(arg0 as f32, arg1 as f32).into()),
-            max: snark_jobs
-                .into_iter()
-                .reduce(|arg0, arg1| f32::max(arg0 as f32, arg1 as f32).into()),
-            lower_quartile: snark_jobs_data.clone().lower_quartile(),
-            upper_quartile: snark_jobs_data.clone().upper_quartile(),
+            count: fees.len(),
+            sum: fees.iter().sum(),
+            mean: fees_data.mean().map(round_to_nanomina),
+            median: round_to_nanomina(fees_data.clone().median()),
+            std_dev: fees_data.std_dev(),
+            min: fees.iter().min().copied(),
+            max: fees.iter().max().copied(),
+            lower_quartile: round_to_nanomina(fees_data.clone().lower_quartile()),
+            upper_quartile: round_to_nanomina(fees_data.clone().upper_quartile()),
         }
     }
 }
 
+fn round_to_nanomina(nanomina: f64) -> Amount {
+    Amount::from_nanomina(nanomina.round() as i128)
+}
+
 impl From<Vec<SnarkFeeData>> for SnarkStatsContainer {
     fn from(data: Vec<SnarkFeeData>) -> Self {
         let filtered_data = data
@@ -78,7 +84,7 @@ impl From<Vec<SnarkFeeData>> for SnarkStatsContainer {
                     .snark_jobs
                     .clone()
                     .into_iter()
-                    .filter(|snark_job| snark_job.fee > 0)
+                    .filter(|snark_job| snark_job.fee > Amount::ZERO)
                     .collect::<Vec<_>>(),
             })
             .collect::<Vec<_>>();
@@ -89,24 +95,22 @@ impl From<Vec<SnarkFeeData>> for SnarkStatsContainer {
     }
 }
 
-#[derive(Deserialize, Serialize, Debug, Clone)]
-pub struct BlocksAnalyticsData {
-    pub blocks: Vec<BlockAnalyticsData>,
-}
-
-#[derive(Deserialize, Serialize, Debug, Clone)]
-pub struct SnarkFeesData {
-    pub blocks: Vec<SnarkFeeData>,
-}
-
-#[derive(Deserialize, Serialize, Debug, Clone)]
-pub struct BlocksAnalyticsResponse {
-    pub data: BlocksAnalyticsData,
-}
-
-#[derive(Deserialize, Serialize, Debug, Clone)]
-pub struct SnarkFeesResponse {
-    pub data: SnarkFeesData,
+impl From<SnarkFeesQueryBlocks> for SnarkFeeData {
+    fn from(block: SnarkFeesQueryBlocks) -> Self {
+        SnarkFeeData {
+            block_height: block.block_height.unwrap_or_default(),
+            snark_fees: block.snark_fees.unwrap_or_default(),
+            snark_jobs: block
+                .snark_jobs
+                .unwrap_or_default()
+                .into_iter()
+                .flatten()
+                .map(|job| SnarkJob {
+                    fee: Amount::from_nanomina(job.fee.unwrap_or_default()),
+                })
+                .collect(),
+        }
+    }
 }
 
 #[derive(Deserialize, Serialize, Debug, Clone)]
@@ -123,15 +127,21 @@ pub struct TopSnarkerStat {
     pub epoch_snarks_sold: u64,
 }
 
-#[derive(Deserialize, Serialize, Debug, Clone)]
-#[serde(rename_all = "camelCase")]
-pub struct TopSnarkers {
-    pub top_snarkers: Vec<TopSnarkerStat>,
-}
-
-#[derive(Deserialize, Serialize, Debug, Clone)]
-pub struct SnarkerLeaderboardResponse {
-    pub data: TopSnarkers,
+impl From<TopSnarkersQueryTopSnarkers> for TopSnarkerStat {
+    fn from(snarker: TopSnarkersQueryTopSnarkers) -> Self {
+        TopSnarkerStat {
+            username: snarker.username,
+            public_key: snarker.public_key,
+            total_fees: snarker.total_fees,
+            epoch_fees: snarker.epoch_fees,
+            min_fee: snarker.min_fee,
+            epoch_min_fee: snarker.epoch_min_fee,
+            max_fee: snarker.max_fee,
+            epoch_max_fee: snarker.epoch_max_fee,
+            snarks_sold: snarker.snarks_sold,
+            epoch_snarks_sold: snarker.epoch_snarks_sold,
+        }
+    }
 }
 
 #[derive(Clone)]
@@ -193,6 +203,19 @@ impl TryFrom<String> for SnarkerLeaderboardTotalFees {
     }
 }
 
+impl TryFrom<SnarkerLeaderboardTotalFees> for TopSnarkersSortByInput {
+    type Error = &'static str;
+    fn try_from(sort: SnarkerLeaderboardTotalFees) -> Result<TopSnarkersSortByInput, Self::Error> {
+        match sort {
+            SnarkerLeaderboardTotalFees::TotalFeesAsc => Ok(TopSnarkersSortByInput::TOTAL_FEES_ASC),
+            SnarkerLeaderboardTotalFees::TotalFeesDesc => {
+                Ok(TopSnarkersSortByInput::TOTAL_FEES_DESC)
+            }
+            SnarkerLeaderboardTotalFees::Nil => Err("No total fees sort selected"),
+        }
+    }
+}
+
 #[derive(Clone)]
 #[allow(dead_code)]
 pub enum SnarkerLeaderboardHighestFees {
@@ -251,3 +274,56 @@ impl TryFrom<String> for SnarkerLeaderboardHighestFees {
         }
     }
 }
+
+impl TryFrom<SnarkerLeaderboardHighestFees> for TopSnarkersSortByInput {
+    type Error = &'static str;
+    fn try_from(
+        sort: SnarkerLeaderboardHighestFees,
+    ) -> Result<TopSnarkersSortByInput, Self::Error> {
+        match sort {
+            SnarkerLeaderboardHighestFees::HighestFeeAsc => Ok(TopSnarkersSortByInput::MAX_FEE_ASC),
+            SnarkerLeaderboardHighestFees::HighestFeeDesc => {
+                Ok(TopSnarkersSortByInput::MAX_FEE_DESC)
+            }
+            SnarkerLeaderboardHighestFees::Nil => Err("No highest fee sort selected"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod snark_stats_tests {
+    use super::*;
+
+    fn fee_data(fees: &[u64]) -> SnarkFeeData {
+        SnarkFeeData {
+            block_height: 1,
+            snark_fees: String::new(),
+            snark_jobs: fees
+                .iter()
+                .map(|fee| SnarkJob {
+                    fee: Amount::from_nanomina(*fee),
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn test_snark_stats_are_exact() {
+        // Large enough that summing as f64 would drop the trailing nanomina.
+        let big = 9_007_199_254_740_993;
+        let stats = SnarkStatsContainer::from(vec![fee_data(&[big, 0]), fee_data(&[1])]);
+        assert_eq!(stats.all.count, 3);
+        assert_eq!(stats.all.sum, Amount::from_nanomina(big + 1));
+        assert_eq!(stats.all.min, Some(Amount::ZERO));
+        assert_eq!(stats.all.max, Some(Amount::from_nanomina(big)));
+        assert_eq!(stats.non_zero.count, 2);
+        assert_eq!(stats.non_zero.min, Some(Amount::from_nanomina(1)));
+    }
+
+    #[test]
+    fn test_snark_stats_empty() {
+        let stats = SnarkStats::new(vec![]);
+        assert_eq!(stats.sum, Amount::ZERO);
+        assert_eq!(stats.min, None);
+    }
+}
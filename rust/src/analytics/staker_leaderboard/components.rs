@@ -4,7 +4,7 @@ use super::{
     models::{DelegationTotals, ExtendedTopStakersSortByInput, StakerStats},
 };
 use crate::{
-    common::{components::*, constants::*, table::*},
+    common::{components::*, constants::*, network::*, table::*},
     summary::models::BlockchainSummary,
 };
 use codee::string::JsonSerdeCodec;
@@ -17,12 +17,15 @@ use std::collections::HashMap;
 pub fn StakerLeaderboard() -> impl IntoView {
     let (epoch_sig, set_epoch) = create_query_signal::<u32>("epoch");
     let (sort_dir_sig, _) = create_query_signal::<String>("sort-dir");
-    let (summary_sig, _, _) =
-        use_local_storage::<BlockchainSummary, JsonSerdeCodec>(BLOCKCHAIN_SUMMARY_STORAGE_KEY);
+    let (summary_sig, _, _) = use_local_storage::<BlockchainSummary, JsonSerdeCodec>(
+        use_network_storage_key(BLOCKCHAIN_SUMMARY_STORAGE_KEY),
+    );
+    let network = use_network();
     let resource = create_resource(
         move || (epoch_sig.get(), sort_dir_sig.get()),
         move |(epoch, sort_dir)| async move {
             load_data(
+                &network.get_value(),
                 epoch,
                 sort_dir.and_then(|dir| TopStakersSortByInput::try_from(dir).ok()),
             )
@@ -47,7 +50,7 @@ pub fn StakerLeaderboard() -> impl IntoView {
                 .get()
                 .chain
                 .as_ref()
-                .and_then(|c| c.get(MAINNET_2_CHAIN_ID))
+                .and_then(|c| network.with_value(|n| c.get(&n.chain_id).cloned()))
                 .map(|c| c.latest_epoch)
                 .unwrap_or_default(),
             set_epoch,
@@ -171,9 +174,12 @@ pub fn StakerLeaderboard() -> impl IntoView {
             ];
             view! {
                 <TableSectionTemplate
+                    table_id="staker-leaderboard"
                     table_columns
                     data_sig
                     is_loading=resource.loading()
+                    error=Signal::derive(move || resource.get().and_then(|res| res.err()))
+                    on_retry=Callback::new(move |_| resource.refetch())
                     section_heading="Staker Leaderboard"
                     controls=move || {
                         epoch_sig.get();
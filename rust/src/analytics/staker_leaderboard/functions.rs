@@ -1,6 +1,4 @@
-use graphql_client::reqwest::post_graphql;
-
-use crate::common::{constants::GRAPHQL_ENDPOINT, models::MyError};
+use crate::common::{client::query_graphql, models::MyError, network::Network};
 
 use super::graphql::{
     TopStakersQuery,
@@ -11,11 +9,12 @@ use super::graphql::{
 };
 
 pub async fn load_data(
+    network: &Network,
     epoch: Option<u32>,
     sort_by: Option<TopStakersSortByInput>,
 ) -> Result<ResponseData, MyError> {
     if epoch.is_none() {
-        return Err(MyError::ParseError("Epoch must not be None".into()));
+        return Err(MyError::InvalidInput("Epoch must not be None".into()));
     }
 
     let query = TopStakersQueryInput {
@@ -40,17 +39,5 @@ pub async fn load_data(
         sort_by: sort_by.unwrap_or(TopStakersSortByInput::NUM_CANONICAL_BLOCKS_PRODUCED_DESC),
     };
 
-    let client = reqwest::Client::new();
-
-    let response = post_graphql::<TopStakersQuery, _>(&client, GRAPHQL_ENDPOINT, variables)
-        .await
-        .map_err(|e| MyError::NetworkError(e.to_string()))?;
-
-    if let Some(errors) = response.errors {
-        return Err(MyError::GraphQLError(errors));
-    }
-
-    response
-        .data
-        .ok_or(MyError::GraphQLEmpty("No data available".to_string()))
+    query_graphql::<TopStakersQuery>(network, variables).await
 }
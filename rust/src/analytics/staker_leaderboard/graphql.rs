@@ -1,3 +1,4 @@
+use crate::common::amount::Amount;
 use chrono::Utc;
 use graphql_client::GraphQLQuery;
 use top_stakers_query::{
@@ -8,7 +9,7 @@ type Long = i32;
 
 #[derive(GraphQLQuery)]
 #[graphql(
-    schema_path = "./graphql/schemas/mina-explorer.graphql",
+    schema_path = "./graphql/schemas/mina-explorer.typed.graphql",
     query_path = "./graphql/queries/staker_leaderboard.graphql",
     response_derives = "Serialize,PartialEq,Debug,Clone,Default",
     skip_serializing_none
@@ -140,6 +141,7 @@ impl Default for BlockProtocolStateConsensusStateQueryInput {
 impl Default for BlockQueryInput {
     fn default() -> Self {
         BlockQueryInput {
+            unique_block_producers_last_n_blocks: None,
             creator_nin: None,
             state_hash_lte: None,
             canonical_ne: None,
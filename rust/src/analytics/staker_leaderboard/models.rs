@@ -72,25 +72,6 @@ pub struct StakerStats {
     pub delegation_totals: DelegationTotals,
 }
 
-#[derive(Deserialize, Serialize, Debug, Clone, Default)]
-pub struct TopStakerBlocks {
-    pub epoch_num_canonical_blocks: u32,
-    pub epoch_num_blocks: u32,
-    pub epoch_num_slots_produced: u32,
-}
-
-#[derive(Deserialize, Serialize, Debug, Clone)]
-#[serde(rename_all = "camelCase")]
-pub struct TopStakers {
-    pub top_stakers: Vec<StakerStats>,
-    pub blocks: Vec<TopStakerBlocks>,
-}
-
-#[derive(Deserialize, Serialize, Debug, Clone)]
-pub struct StakerLeaderboardResponse {
-    pub data: TopStakers,
-}
-
 impl SortDirection for ExtendedTopStakersSortByInput {
     fn is_desc(&self) -> bool {
         matches!(
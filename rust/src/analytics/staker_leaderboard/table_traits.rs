@@ -47,4 +47,19 @@ impl TableData for Vec<StakerStats> {
             })
             .collect()
     }
+
+    fn get_raw_rows(&self) -> Vec<Vec<String>> {
+        self.iter()
+            .map(|stat| {
+                vec![
+                    stat.public_key.to_string(),
+                    stat.num_canonical_blocks_produced.to_string(),
+                    stat.delegation_totals.total_stake_percentage.to_string(),
+                    stat.num_slots_produced.to_string(),
+                    opt_to_string(stat.orphan_rate()),
+                    stat.num_supercharged_blocks_produced.to_string(),
+                ]
+            })
+            .collect()
+    }
 }
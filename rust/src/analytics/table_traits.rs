@@ -1,5 +1,5 @@
 use super::models::{SnarkStats, SnarkStatsContainer, TopSnarkerStat};
-use crate::common::{functions::*, table::TableData};
+use crate::common::{amount::Amount, functions::*, table::TableData};
 use leptos::*;
 
 const TOP_SNARKER_FEE_MAX_LHS_DIGITS: usize = 3;
@@ -10,15 +10,15 @@ impl TableData for Vec<TopSnarkerStat> {
                 vec![
                     convert_to_linkable_address(stat.username.clone(), stat.public_key.clone()),
                     convert_to_span(format_number_for_html(
-                        nanomina_to_mina(stat.epoch_fees).as_str(),
+                        &Amount::from_nanomina(stat.epoch_fees).format(),
                         TOP_SNARKER_FEE_MAX_LHS_DIGITS,
                     )),
                     convert_to_span(format_number_for_html(
-                        nanomina_to_mina(stat.epoch_min_fee).as_str(),
+                        &Amount::from_nanomina(stat.epoch_min_fee).format(),
                         TOP_SNARKER_FEE_MAX_LHS_DIGITS,
                     )),
                     convert_to_span(format_number_for_html(
-                        nanomina_to_mina(stat.epoch_max_fee).as_str(),
+                        &Amount::from_nanomina(stat.epoch_max_fee).format(),
                         TOP_SNARKER_FEE_MAX_LHS_DIGITS,
                     )),
                     convert_to_span(stat.epoch_snarks_sold.to_string()),
@@ -26,6 +26,20 @@ impl TableData for Vec<TopSnarkerStat> {
             })
             .collect()
     }
+
+    fn get_raw_rows(&self) -> Vec<Vec<String>> {
+        self.iter()
+            .map(|stat| {
+                vec![
+                    stat.public_key.to_string(),
+                    Amount::from_nanomina(stat.epoch_fees).to_mina_string(),
+                    Amount::from_nanomina(stat.epoch_min_fee).to_mina_string(),
+                    Amount::from_nanomina(stat.epoch_max_fee).to_mina_string(),
+                    stat.epoch_snarks_sold.to_string(),
+                ]
+            })
+            .collect()
+    }
 }
 
 impl TableData for Option<SnarkStatsContainer> {
@@ -39,111 +53,38 @@ impl TableData for Option<SnarkStatsContainer> {
                 ],
                 vec![
                     convert_to_span("Sum".to_string()),
-                    convert_to_span(nanomina_to_mina(
-                        stats_cont.all.get_sum().parse::<u64>().ok().unwrap_or(0),
-                    )),
-                    convert_to_span(nanomina_to_mina(
-                        stats_cont
-                            .non_zero
-                            .get_sum()
-                            .parse::<u64>()
-                            .ok()
-                            .unwrap_or(0),
-                    )),
+                    convert_to_span(stats_cont.all.get_sum()),
+                    convert_to_span(stats_cont.non_zero.get_sum()),
                 ],
                 vec![
                     convert_to_span("Mean".to_string()),
-                    convert_to_span(nanomina_to_mina(
-                        stats_cont.all.get_mean().parse::<u64>().ok().unwrap_or(0),
-                    )),
-                    convert_to_span(nanomina_to_mina(
-                        stats_cont
-                            .non_zero
-                            .get_mean()
-                            .parse::<u64>()
-                            .ok()
-                            .unwrap_or(0),
-                    )),
+                    convert_to_span(stats_cont.all.get_mean()),
+                    convert_to_span(stats_cont.non_zero.get_mean()),
                 ],
                 vec![
                     convert_to_span("Median".to_string()),
-                    convert_to_span(nanomina_to_mina(
-                        stats_cont.all.get_median().parse::<u64>().ok().unwrap_or(0),
-                    )),
-                    convert_to_span(nanomina_to_mina(
-                        stats_cont
-                            .non_zero
-                            .get_median()
-                            .parse::<u64>()
-                            .ok()
-                            .unwrap_or(0),
-                    )),
+                    convert_to_span(stats_cont.all.get_median()),
+                    convert_to_span(stats_cont.non_zero.get_median()),
                 ],
                 vec![
                     convert_to_span("Min".to_string()),
-                    convert_to_span(nanomina_to_mina(
-                        stats_cont.all.get_min().parse::<u64>().ok().unwrap_or(0),
-                    )),
-                    convert_to_span(nanomina_to_mina(
-                        stats_cont
-                            .non_zero
-                            .get_min()
-                            .parse::<u64>()
-                            .ok()
-                            .unwrap_or(0),
-                    )),
+                    convert_to_span(stats_cont.all.get_min()),
+                    convert_to_span(stats_cont.non_zero.get_min()),
                 ],
                 vec![
                     convert_to_span("Max".to_string()),
-                    convert_to_span(nanomina_to_mina(
-                        stats_cont.all.get_max().parse::<u64>().ok().unwrap_or(0),
-                    )),
-                    convert_to_span(nanomina_to_mina(
-                        stats_cont
-                            .non_zero
-                            .get_max()
-                            .parse::<u64>()
-                            .ok()
-                            .unwrap_or(0),
-                    )),
+                    convert_to_span(stats_cont.all.get_max()),
+                    convert_to_span(stats_cont.non_zero.get_max()),
                 ],
                 vec![
                     convert_to_span("25%".to_string()),
-                    convert_to_span(nanomina_to_mina(
-                        stats_cont
-                            .all
-                            .get_lower_quartile()
-                            .parse::<u64>()
-                            .ok()
-                            .unwrap_or(0),
-                    )),
-                    convert_to_span(nanomina_to_mina(
-                        stats_cont
-                            .non_zero
-                            .get_lower_quartile()
-                            .parse::<u64>()
-                            .ok()
-                            .unwrap_or(0),
-                    )),
+                    convert_to_span(stats_cont.all.get_lower_quartile()),
+                    convert_to_span(stats_cont.non_zero.get_lower_quartile()),
                 ],
                 vec![
                     convert_to_span("75%".to_string()),
-                    convert_to_span(nanomina_to_mina(
-                        stats_cont
-                            .all
-                            .get_upper_quartile()
-                            .parse::<u64>()
-                            .ok()
-                            .unwrap_or(0),
-                    )),
-                    convert_to_span(nanomina_to_mina(
-                        stats_cont
-                            .non_zero
-                            .get_upper_quartile()
-                            .parse::<u64>()
-                            .ok()
-                            .unwrap_or(0),
-                    )),
+                    convert_to_span(stats_cont.all.get_upper_quartile()),
+                    convert_to_span(stats_cont.non_zero.get_upper_quartile()),
                 ],
             ]
         })
@@ -166,24 +107,24 @@ impl SnarkTableData for SnarkStats {
         self.count.to_string()
     }
     fn get_sum(&self) -> String {
-        self.sum.to_string()
+        self.sum.format()
     }
     fn get_mean(&self) -> String {
-        self.mean.map_or("-".to_string(), |mean| mean.to_string())
+        self.mean.map_or("-".to_string(), |mean| mean.format())
     }
     fn get_median(&self) -> String {
-        self.median.to_string()
+        self.median.format()
     }
     fn get_min(&self) -> String {
-        self.min.map_or("-".to_string(), |min| min.to_string())
+        self.min.map_or("-".to_string(), |min| min.format())
     }
     fn get_max(&self) -> String {
-        self.max.map_or("-".to_string(), |max| max.to_string())
+        self.max.map_or("-".to_string(), |max| max.format())
     }
     fn get_lower_quartile(&self) -> String {
-        self.lower_quartile.to_string()
+        self.lower_quartile.format()
     }
     fn get_upper_quartile(&self) -> String {
-        self.upper_quartile.to_string()
+        self.upper_quartile.format()
     }
 }
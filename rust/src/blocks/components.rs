@@ -4,8 +4,20 @@ use super::{
     models::*,
 };
 use crate::{
+    advanced_filter::functions::get_advanced_filter,
     blocks::graphql::blocks_query,
-    common::{components::*, constants::*, functions::*, models::*, spotlight::*, table::*},
+    common::{
+        amount::Amount,
+        components::*,
+        constants::*,
+        datetime::{use_time_settings, validate_date},
+        functions::*,
+        live::*,
+        models::*,
+        network::*,
+        spotlight::*,
+        table::*,
+    },
     icons::*,
     summary::models::BlockchainSummary,
 };
@@ -17,12 +29,8 @@ use charming::{
 use codee::string::JsonSerdeCodec;
 use leptos::*;
 use leptos_router::*;
-use leptos_use::{
-    UseIntervalReturn, UseTimeoutFnReturn, storage::use_local_storage, use_document_visibility,
-    use_interval, use_timeout_fn,
-};
+use leptos_use::{UseTimeoutFnReturn, storage::use_local_storage, use_timeout_fn};
 use std::collections::HashMap;
-use web_sys::VisibilityState;
 
 #[component]
 pub fn BlockTabContainer(content: BlockContent) -> impl IntoView {
@@ -103,6 +111,7 @@ pub fn BlockUserCommands(block: BlocksQueryBlocks) -> impl IntoView {
 
     view! {
         <TableSectionTemplate
+            table_id="block-user-commands"
             table_columns
             data_sig
             is_loading=loading_sig.into()
@@ -119,6 +128,7 @@ pub fn BlockSnarkJobs(block: BlocksQueryBlocks) -> impl IntoView {
     let table_columns: Vec<TableColumn<AnySort>> = vec![
         TableColumn {
             column: "State Hash".to_string(),
+            validate: Some(StateHash::validate),
             width: Some(String::from(TABLE_COL_HASH_WIDTH)),
             ..Default::default()
         },
@@ -141,6 +151,7 @@ pub fn BlockSnarkJobs(block: BlocksQueryBlocks) -> impl IntoView {
 
     view! {
         <TableSectionTemplate
+            table_id="block-snark-jobs"
             table_columns
             data_sig
             is_loading=loading_sig.into()
@@ -203,6 +214,7 @@ pub fn BlockInternalCommands(block: BlocksQueryBlocks) -> impl IntoView {
 
     view! {
         <TableSectionTemplate
+            table_id="block-internal-commands"
             table_columns
             data_sig
             is_loading=loading_sig.into()
@@ -222,12 +234,11 @@ pub fn BlockAnalytics(block: BlocksQueryBlocks) -> impl IntoView {
                 .filter_map(|transaction_option| {
                     transaction_option
                         .as_ref()
-                        .map(|transaction| transaction.amount.unwrap_or(0.0))
-                        .map(|f| f.round() as u64)
+                        .and_then(|transaction| transaction.amount)
                 })
                 .sum()
         } else {
-            0
+            Amount::ZERO
         }
     };
 
@@ -241,11 +252,7 @@ pub fn BlockAnalytics(block: BlocksQueryBlocks) -> impl IntoView {
                         subtext="In MINA"
                         value=convert_to_span(
                             format_number_helper(
-                                &normalize_number_format(
-                                        &nanomina_to_mina(user_command_amount_total()),
-                                    )
-                                    .ok()
-                                    .unwrap(),
+                                &user_command_amount_total().to_mina_string(),
                                 Some(1),
                             ),
                         )
@@ -300,7 +307,7 @@ pub fn BlockSpotlightFeeTransferAnalytics(block: BlocksQueryBlocks) -> impl Into
                         else {
                             return None;
                         };
-                        let parsed_fee = str::parse::<i32>(fee).unwrap_or(0);
+                        let parsed_fee = fee.parse::<Amount>().unwrap_or_default();
                         let sixth_to_last = recipient.len() - 6;
                         let recip = [
                             recipient[..6].to_string(),
@@ -309,7 +316,7 @@ pub fn BlockSpotlightFeeTransferAnalytics(block: BlocksQueryBlocks) -> impl Into
                         Some((recip.join("..."), parsed_fee))
                     })
                     .fold(HashMap::new(), |mut acc, (recipient, fee)| {
-                        *acc.entry(recipient).or_insert(0) += fee;
+                        *acc.entry(recipient).or_insert(Amount::ZERO) += fee;
                         acc
                     });
                 set_data.set(pie_hashmap);
@@ -318,7 +325,7 @@ pub fn BlockSpotlightFeeTransferAnalytics(block: BlocksQueryBlocks) -> impl Into
     });
 
     let UseTimeoutFnReturn { start, .. } = use_timeout_fn(
-        |data: HashMap<String, i32>| {
+        |data: HashMap<String, Amount>| {
             setup_and_render_chart(&data, "chart", "Top Internal Transfers");
         },
         1000.0,
@@ -351,10 +358,10 @@ pub fn BlockSpotlightUserCommandAnalytics(block: BlocksQueryBlocks) -> impl Into
                             recipient[..6].to_string(),
                             recipient[sixth_to_last..].to_string(),
                         ];
-                        Some((recip.join("..."), amount as i64))
+                        Some((recip.join("..."), amount))
                     })
                     .fold(HashMap::new(), |mut acc, (recipient, amount)| {
-                        *acc.entry(recipient).or_insert(0) += amount;
+                        *acc.entry(recipient).or_insert(Amount::ZERO) += amount;
                         acc
                     });
                 set_data.set(pie_hashmap);
@@ -371,10 +378,7 @@ pub fn BlockSpotlightUserCommandAnalytics(block: BlocksQueryBlocks) -> impl Into
     view! { <div id="chart2" class="p-4 md:p-8"></div> }
 }
 
-fn setup_and_render_chart<T>(data: &HashMap<String, T>, chart_id: &str, chart_title: &str)
-where
-    T: Into<i64> + Copy + 'static,
-{
+fn setup_and_render_chart(data: &HashMap<String, Amount>, chart_id: &str, chart_title: &str) {
     let d = data.clone();
     let ch_id = chart_id.to_string();
     let ch_tl = chart_title.to_string();
@@ -391,21 +395,18 @@ where
 }
 
 // Asynchronous function to render the chart
-async fn render_pie_chart<T>(data: &HashMap<String, T>, chart_id: &str, chart_title: &str)
-where
-    T: Into<i64> + Copy,
-{
+async fn render_pie_chart(data: &HashMap<String, Amount>, chart_id: &str, chart_title: &str) {
     let mut sorted_data = data
         .iter()
-        .map(|(key, &val)| (Into::<i64>::into(val), key))
+        .map(|(key, &val)| (val, key))
         .collect::<Vec<_>>();
-    sorted_data.sort_by(|a, b| b.0.cmp(&a.0));
+    sorted_data.sort_by_key(|b| std::cmp::Reverse(b.0));
 
     let size = sorted_data.len();
     let (top_items, rest) = sorted_data.split_at_mut(5.min(size));
 
     let binding = String::from("Other");
-    let aggregated = rest.iter().fold((0, &binding), |mut acc, tup| {
+    let aggregated = rest.iter().fold((Amount::ZERO, &binding), |mut acc, tup| {
         acc.0 += tup.0;
         acc
     });
@@ -414,6 +415,10 @@ where
     if !rest.is_empty() {
         result.push(aggregated);
     }
+    let result = result
+        .into_iter()
+        .map(|(amount, key)| (amount.to_mina_f64(), key))
+        .collect::<Vec<_>>();
 
     let series = Pie::new()
         .radius(vec!["50", "100"])
@@ -527,9 +532,7 @@ pub fn BlockSpotlight(block: BlocksQueryBlocks) -> impl IntoView {
             header="Block Spotlight".to_string()
             spotlight_items=spotlight_items
             id=Some(get_state_hash(&block))
-            meta=Some(
-                format!("{} ({})", date_time, convert_to_local_timezone_formatted(&date_time)),
-            )
+            meta=date_time_meta(date_time)
         >
 
             <BlockIcon width=40 />
@@ -611,42 +614,44 @@ fn BlockSpotlightPlaceholder() -> impl IntoView {
 
 #[component]
 pub fn BlocksSection() -> impl IntoView {
-    let (summary_sig, _, _) =
-        use_local_storage::<BlockchainSummary, JsonSerdeCodec>(BLOCKCHAIN_SUMMARY_STORAGE_KEY);
-    let visibility = use_document_visibility();
+    let (summary_sig, _, _) = use_local_storage::<BlockchainSummary, JsonSerdeCodec>(
+        use_network_storage_key(BLOCKCHAIN_SUMMARY_STORAGE_KEY),
+    );
+    let visible = use_page_visible();
     let query_params_map = use_query_map();
     let (data_sig, set_data_sig) = create_signal(None);
     let (block_height_sig, _) = create_query_signal::<u64>(QUERY_PARAM_HEIGHT);
     let (row_limit_sig, _) = create_query_signal::<u64>("row-limit");
     let (slot_sig, _) = create_query_signal::<u64>(QUERY_PARAM_SLOT);
     let (canonical_sig, _) = create_query_signal::<String>("canonical");
-    let UseIntervalReturn { counter, .. } = use_interval(LIVE_RELOAD_INTERVAL);
+    let (new_blocks_sig, set_new_blocks) = create_signal(0);
+    let live = use_live_updates();
+    let time_settings = use_time_settings().settings;
+    let network = use_network();
 
     let resource = create_resource(
         move || {
             (
-                counter.get(),
                 query_params_map.get(),
+                time_settings.with(|s| s.time_zone.clone()),
                 block_height_sig.get(),
                 slot_sig.get(),
                 canonical_sig.get(),
                 row_limit_sig.get(),
             )
         },
-        move |(_, q_map, block_height, slot, canonical, row_limit)| async move {
-            if visibility.get_untracked() == VisibilityState::Visible {
+        move |(q_map, time_zone, block_height, slot, canonical, row_limit)| async move {
+            if visible.get_untracked() {
                 load_data(
+                    &network.get_value(),
                     row_limit,
                     q_map.get(QUERY_PARAM_BLOCK_PRODUCER).cloned(),
                     q_map.get(QUERY_PARAM_STATE_HASH).cloned(),
                     block_height,
                     slot,
-                    match canonical {
-                        Some(c) if &c == "All" => None,
-                        Some(c) if &c == "Canonical" => Some(true),
-                        Some(c) if &c == "Non-Canonical" => Some(false),
-                        _ => None,
-                    },
+                    canonical_filter(canonical),
+                    get_range_filters(&q_map, &time_zone),
+                    get_advanced_filter(&q_map),
                 )
                 .await
             } else {
@@ -683,11 +688,14 @@ pub fn BlocksSection() -> impl IntoView {
         },
         TableColumn {
             column: "Date".to_string(),
+            search_type: ColumnSearchType::DateRange,
+            validate: Some(validate_date),
             width: Some(String::from(TABLE_COL_DATE_WIDTH)),
             ..Default::default()
         },
         TableColumn {
             column: "Block Producer".to_string(),
+            validate: Some(PublicKey::validate),
             search_type: ColumnSearchType::Text,
             width: Some(String::from(TABLE_COL_HASH_WIDTH)),
             ..Default::default()
@@ -720,11 +728,74 @@ pub fn BlocksSection() -> impl IntoView {
     create_effect(move |_| {
         if let Some(data) = resource.get().and_then(|res| res.ok()) {
             set_data_sig.set(Some(data.blocks));
+            set_new_blocks.set(0);
         }
     });
 
+    // Prepend blocks announced after the table was loaded. Only the unfiltered
+    // first page follows the tip; any other view is left as the user chose it.
+    create_effect(move |_| {
+        let Some(tip) = live.latest_height() else {
+            return;
+        };
+        let q_map = query_params_map.get_untracked();
+        if [
+            QUERY_PARAM_HEIGHT,
+            QUERY_PARAM_SLOT,
+            QUERY_PARAM_STATE_HASH,
+            QUERY_PARAM_BLOCK_PRODUCER,
+        ]
+        .iter()
+        .any(|param| q_map.get(param).is_some())
+            || has_range_filters(&q_map)
+            || q_map.get(QUERY_PARAM_FILTER).is_some()
+        {
+            return;
+        }
+        let Some(top) = data_sig.get_untracked().and_then(|blocks| {
+            blocks
+                .iter()
+                .flatten()
+                .filter_map(|block| block.block_height)
+                .max()
+        }) else {
+            return;
+        };
+        let missing = tip.saturating_sub(top as u64);
+        if missing == 0 {
+            return;
+        }
+        let row_limit = row_limit_sig.get_untracked().unwrap_or(TABLE_ROW_LIMIT);
+        let canonical = canonical_filter(canonical_sig.get_untracked());
+        spawn_local(async move {
+            if let Ok(data) = load_data(
+                &network.get_value(),
+                Some(missing.min(row_limit)),
+                None,
+                None,
+                None,
+                None,
+                canonical,
+                Default::default(),
+                None,
+            )
+            .await
+            {
+                let (blocks, added) = prepend_new(
+                    data_sig.get_untracked().unwrap_or_default(),
+                    data.blocks,
+                    row_limit as usize,
+                    |block| block.as_ref().and_then(|b| b.state_hash.clone()),
+                );
+                set_data_sig.set(Some(blocks));
+                set_new_blocks.update(|count| *count += added);
+            }
+        });
+    });
+
     view! {
         <TableSectionTemplate
+            table_id="blocks"
             table_columns
             data_sig
             section_heading="Blocks"
@@ -733,7 +804,9 @@ pub fn BlocksSection() -> impl IntoView {
                 let indexes_not_available = query_params_map.get(QUERY_PARAM_HEIGHT).is_some()
                     || query_params_map.get(QUERY_PARAM_STATE_HASH).is_some()
                     || query_params_map.get(QUERY_PARAM_SLOT).is_some()
-                    || query_params_map.get(QUERY_PARAM_BLOCK_PRODUCER).is_some();
+                    || query_params_map.get(QUERY_PARAM_BLOCK_PRODUCER).is_some()
+                    || has_range_filters(&query_params_map)
+                    || query_params_map.get(QUERY_PARAM_FILTER).is_some();
                 let indexes_available = !indexes_not_available;
                 let canonical_blocks = query_params_map
                     .get(QUERY_PARAM_CANONICAL)
@@ -769,11 +842,50 @@ pub fn BlocksSection() -> impl IntoView {
             })
 
             is_loading=resource.loading()
+            error=Signal::derive(move || resource.get().and_then(|res| res.err()))
+            on_retry=Callback::new(move |_| resource.refetch())
+            additional_info=move || {
+                view! {
+                    <Show when=move || { new_blocks_sig.get() > 0 }>
+                        <button
+                            id="new-blocks-banner"
+                            class="text-sm text-granola-orange font-semibold"
+                            title="Dismiss"
+                            on:click=move |_| set_new_blocks.set(0)
+                        >
+                            {move || match new_blocks_sig.get() {
+                                1 => "1 new block".to_string(),
+                                count => format!("{} new blocks", count),
+                            }}
+                        </button>
+                    </Show>
+                }
+            }
             footer=move || {
                 view! {
-                    <NextBlockPage
-                        data=data_sig.get().unwrap_or(vec![])
-                        row_limit=row_limit_sig.get()
+                    <NextPage
+                        data_sig
+                        set_data_sig
+                        row_limit=row_limit_sig
+                        fetch=move |height, limit| async move {
+                            let q_map = query_params_map.get_untracked();
+                            load_data(
+                                    &network.get_value(),
+                                    Some(limit),
+                                    q_map.get(QUERY_PARAM_BLOCK_PRODUCER).cloned(),
+                                    q_map.get(QUERY_PARAM_STATE_HASH).cloned(),
+                                    height,
+                                    slot_sig.get_untracked(),
+                                    canonical_filter(canonical_sig.get_untracked()),
+                                    get_range_filters(
+                                        &q_map,
+                                        &time_settings.with_untracked(|s| s.time_zone.clone()),
+                                    ),
+                                    get_advanced_filter(&q_map),
+                                )
+                                .await
+                                .map(|data| data.blocks)
+                        }
                     />
                 }
             }
@@ -5,12 +5,19 @@ use super::{
     },
     models::BlocksQueryBlocksTransactionsUserCommandsExt,
 };
-use crate::common::{
-    constants::*,
-    functions::{format_number, nanomina_str_to_mina, nanomina_to_mina},
-    models::MyError,
+use crate::{
+    advanced_filter::{
+        functions::{FilterTarget, set_field},
+        models::*,
+    },
+    common::{
+        amount::Amount,
+        client::query_graphql,
+        functions::format_number,
+        models::{MyError, PublicKey, RangeFilters, StateHash},
+        network::Network,
+    },
 };
-use graphql_client::reqwest::post_graphql;
 
 pub fn get_snark_block_state_hash(snark: &BlocksQueryBlocksSnarkJobs) -> String {
     snark
@@ -33,8 +40,7 @@ pub fn get_snark_prover_username(snark: &BlocksQueryBlocksSnarkJobs) -> Option<S
 pub fn get_snark_fee(snark: &BlocksQueryBlocksSnarkJobs) -> String {
     snark
         .fee
-        .map(|i| nanomina_to_mina(i as u64))
-        .map_or_else(String::new, |o| o.to_string())
+        .map_or_else(String::new, |fee| Amount::from_nanomina(fee).format())
 }
 
 pub fn get_user_commands(
@@ -95,12 +101,17 @@ pub fn get_creator_username(block: &BlocksQueryBlocks) -> Option<String> {
     block.creator_username.clone()
 }
 
-pub fn get_coinbase(block: &BlocksQueryBlocks) -> String {
+pub fn get_coinbase_amount(block: &BlocksQueryBlocks) -> Option<Amount> {
     block
         .transactions
         .as_ref()
         .and_then(|o| o.coinbase.as_deref())
-        .map(nanomina_str_to_mina)
+        .and_then(|nanomina| nanomina.parse::<Amount>().ok())
+}
+
+pub fn get_coinbase(block: &BlocksQueryBlocks) -> String {
+    get_coinbase_amount(block)
+        .map(|amount| amount.format())
         .unwrap_or_default()
 }
 
@@ -188,7 +199,8 @@ pub fn get_transaction_fees(block: &BlocksQueryBlocks) -> String {
     block
         .tx_fees
         .as_deref()
-        .map(nanomina_str_to_mina)
+        .and_then(|nanomina| nanomina.parse::<Amount>().ok())
+        .map(|amount| amount.format())
         .unwrap_or_default()
 }
 
@@ -196,7 +208,8 @@ pub fn get_snark_fees(block: &BlocksQueryBlocks) -> String {
     block
         .snark_fees
         .as_deref()
-        .map(nanomina_str_to_mina)
+        .and_then(|nanomina| nanomina.parse::<Amount>().ok())
+        .map(|amount| amount.format())
         .unwrap_or_default()
 }
 
@@ -206,8 +219,7 @@ pub fn get_total_currency(block: &BlocksQueryBlocks) -> String {
         .as_ref()
         .and_then(|o| o.consensus_state.as_ref())
         .and_then(|o| o.total_currency)
-        .map(|f| f.round() as u64)
-        .map(nanomina_to_mina)
+        .map(|f| f.format())
         .unwrap_or_default()
 }
 
@@ -230,13 +242,26 @@ pub fn get_coinbase_receiver_username(block: &BlocksQueryBlocks) -> Option<Strin
         .and_then(|t| t.coinbase_receiver_username.clone())
 }
 
+/// Maps the canonical select menu's value to the query's `canonical` filter.
+pub fn canonical_filter(canonical: Option<String>) -> Option<bool> {
+    match canonical.as_deref() {
+        Some("Canonical") => Some(true),
+        Some("Non-Canonical") => Some(false),
+        _ => None,
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
 pub async fn load_data(
+    network: &Network,
     limit: Option<u64>,
     block_creator_account: Option<String>,
     state_hash: Option<String>,
     block_height: Option<u64>,
     slot: Option<u64>,
     canonical: Option<bool>,
+    ranges: RangeFilters,
+    filter: Option<blocks_query::BlockQueryInput>,
 ) -> Result<blocks_query::ResponseData, MyError> {
     let variables = blocks_query::Variables {
         sort_by: blocks_query::BlockSortByInput::BLOCKHEIGHT_DESC,
@@ -245,6 +270,9 @@ pub async fn load_data(
             canonical,
             state_hash,
             block_height_lte: block_height.map(|x| x as i64),
+            date_time_gte: ranges.date_time.gte,
+            date_time_lte: ranges.date_time.lte,
+            and: filter.map(|filter| vec![filter]),
             creator_account: Some(blocks_query::BlockCreatorAccountQueryInput {
                 public_key: block_creator_account,
                 ..Default::default()
@@ -260,17 +288,101 @@ pub async fn load_data(
         },
     };
 
-    let client = reqwest::Client::new();
+    query_graphql::<BlocksQuery>(network, variables).await
+}
+
+/// The same tab of the block page for `state_hash`.
+pub fn block_tab_path(pathname: &str, state_hash: &str) -> String {
+    let tab = pathname
+        .trim_start_matches("/blocks/")
+        .split_once('/')
+        .map_or("spotlight", |(_, tab)| tab);
+    format!("/blocks/{}/{}", state_hash, tab)
+}
+
+/// The canonical block right above `block`, if there is one yet.
+pub async fn load_next_block(
+    network: &Network,
+    block: &BlocksQueryBlocks,
+) -> Result<Option<BlocksQueryBlocks>, MyError> {
+    let Some(height) = block.block_height else {
+        return Ok(None);
+    };
+    let data = load_data(
+        network,
+        Some(1),
+        None,
+        None,
+        Some(height as u64 + 1),
+        None,
+        Some(true),
+        Default::default(),
+        None,
+    )
+    .await?;
+    Ok(data
+        .blocks
+        .into_iter()
+        .flatten()
+        .find(|next| next.block_height == Some(height + 1)))
+}
 
-    let response = post_graphql::<BlocksQuery, _>(&client, GRAPHQL_ENDPOINT, variables)
-        .await
-        .map_err(|e| MyError::NetworkError(e.to_string()))?;
+impl FilterTarget for blocks_query::BlockQueryInput {
+    const FIELDS: &'static [FilterField] = &[
+        FilterField {
+            key: "block_height",
+            label: "Height",
+            kind: FieldKind::Integer,
+            operators: NUMBER_OPERATORS,
+        },
+        FilterField {
+            key: "state_hash",
+            label: "State Hash",
+            kind: FieldKind::Text(Some(StateHash::validate)),
+            operators: HASH_OPERATORS,
+        },
+        FilterField {
+            key: "creator",
+            label: "Block Producer",
+            kind: FieldKind::Text(Some(PublicKey::validate)),
+            operators: HASH_OPERATORS,
+        },
+        FilterField {
+            key: "canonical",
+            label: "Canonical",
+            kind: FieldKind::Bool,
+            operators: BOOL_OPERATORS,
+        },
+    ];
 
-    if let Some(errors) = response.errors {
-        return Err(MyError::GraphQLError(errors));
+    fn group(all: bool, children: Vec<Self>) -> Self {
+        if all {
+            Self {
+                and: Some(children),
+                ..Default::default()
+            }
+        } else {
+            Self {
+                or: Some(children),
+                ..Default::default()
+            }
+        }
     }
 
-    response
-        .data
-        .ok_or(MyError::GraphQLEmpty("No data available".to_string()))
+    fn condition(field: &str, op: Operator, value: FilterValue) -> Result<Self, String> {
+        let mut input = Self::default();
+        match field {
+            "block_height" => set_field!(input, op, value,
+                Eq => block_height, Ne => block_height_ne, In => block_height_in,
+                Nin => block_height_nin, Gt => block_height_gt, Gte => block_height_gte,
+                Lt => block_height_lt, Lte => block_height_lte),
+            "state_hash" => set_field!(input, op, value,
+                Eq => state_hash, Ne => state_hash_ne, In => state_hash_in, Nin => state_hash_nin),
+            "creator" => set_field!(input, op, value,
+                Eq => creator, Ne => creator_ne, In => creator_in, Nin => creator_nin),
+            "canonical" => set_field!(input, op, value, Eq => canonical),
+            _ => return Err(format!("Unknown field: {}", field)),
+        }
+        Ok(input)
+    }
 }
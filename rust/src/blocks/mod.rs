@@ -1,5 +1,5 @@
 pub mod components;
-mod functions;
+pub mod functions;
 pub mod graphql;
 pub mod models;
 pub mod page;
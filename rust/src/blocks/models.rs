@@ -1,4 +1,7 @@
-use crate::blocks::graphql::blocks_query::BlocksQueryBlocksTransactionsUserCommandsReceiverAccount;
+use crate::{
+    blocks::graphql::blocks_query::BlocksQueryBlocksTransactionsUserCommandsReceiverAccount,
+    common::amount::Amount,
+};
 
 #[derive(Clone)]
 pub enum BlockContent {
@@ -15,8 +18,8 @@ pub struct BlocksQueryBlocksTransactionsUserCommandsExt {
     pub to: Option<String>,
     pub sender_username: Option<String>,
     pub hash: Option<String>,
-    pub fee: Option<f64>,
-    pub amount: Option<f64>,
+    pub fee: Option<Amount>,
+    pub amount: Option<Amount>,
     pub kind: Option<String>,
     pub memo: Option<String>,
     pub failure_reason: Option<String>,
@@ -1,13 +1,13 @@
 use super::{components::*, functions::*, models::*};
 use crate::{
     blocks::graphql::blocks_query,
-    common::{components::*, constants::*, models::*},
+    common::{components::*, constants::*, live::*, models::*, network::*, shortcuts::is_typing},
 };
 use codee::string::JsonSerdeCodec;
 use leptos::*;
 use leptos_meta::Title;
 use leptos_router::*;
-use leptos_use::{UseIntervalReturn, storage::*, use_interval};
+use leptos_use::storage::*;
 
 #[component]
 pub fn BlockSpotlightTab() -> impl IntoView {
@@ -51,13 +51,28 @@ pub fn BlockAnalyticsTab() -> impl IntoView {
 
 #[component]
 pub fn BlocksLocalStorage() -> impl IntoView {
-    let (_, set_blocks, _) =
-        use_local_storage::<blocks_query::ResponseData, JsonSerdeCodec>(BLOCKS_STORAGE_KEY);
-    let UseIntervalReturn { counter, .. } = use_interval(LIVE_RELOAD_INTERVAL);
+    let (_, set_blocks, _) = use_local_storage::<blocks_query::ResponseData, JsonSerdeCodec>(
+        use_network_storage_key(BLOCKS_STORAGE_KEY),
+    );
+    let live = use_live_updates();
+    let network = use_network();
 
     let resource = create_resource(
-        move || counter.get(),
-        |_| async move { load_data(Some(1000), None, None, None, None, Some(true)).await },
+        move || live.refresh_key(),
+        move |_| async move {
+            load_data(
+                &network.get_value(),
+                Some(1000),
+                None,
+                None,
+                None,
+                None,
+                Some(true),
+                Default::default(),
+                None,
+            )
+            .await
+        },
     );
 
     create_effect(move |_| {
@@ -74,11 +89,23 @@ pub fn BlocksLocalStorage() -> impl IntoView {
 pub fn BlockTabbedPage() -> impl IntoView {
     let memo_params_map = use_params_map();
     let id = move || memo_params_map.with(|p| p.get("id").cloned().unwrap_or_default());
+    let network = use_network();
     let resource = create_resource(
         move || memo_params_map.get(),
-        |value| async move {
+        move |value| async move {
             let state_hash = value.get("id");
-            load_data(Some(1), None, state_hash.cloned(), None, None, None).await
+            load_data(
+                &network.get_value(),
+                Some(1),
+                None,
+                state_hash.cloned(),
+                None,
+                None,
+                None,
+                Default::default(),
+                None,
+            )
+            .await
         },
     );
 
@@ -95,6 +122,39 @@ pub fn BlockTabbedPage() -> impl IntoView {
 
     provide_context(option_block);
 
+    let location = use_location();
+    let navigate = use_navigate();
+    let handle = window_event_listener(ev::keydown, move |ev| {
+        if is_typing(&ev) || ev.ctrl_key() || ev.meta_key() || ev.alt_key() {
+            return;
+        }
+        let Some(block) = option_block.get_untracked() else {
+            return;
+        };
+        let pathname = location.pathname.get_untracked();
+        match ev.key().as_str() {
+            "[" => {
+                let previous = get_previous_state_hash(&block);
+                if !previous.is_empty() {
+                    navigate(&block_tab_path(&pathname, &previous), Default::default());
+                }
+            }
+            "]" => {
+                let navigate = navigate.clone();
+                spawn_local(async move {
+                    if let Ok(Some(next)) = load_next_block(&network.get_value(), &block).await {
+                        navigate(
+                            &block_tab_path(&pathname, &get_state_hash(&next)),
+                            Default::default(),
+                        );
+                    }
+                });
+            }
+            _ => {}
+        }
+    });
+    on_cleanup(move || handle.remove());
+
     let tabs = move || {
         vec![
             NavEntry {
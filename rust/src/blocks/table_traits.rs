@@ -7,9 +7,11 @@ use crate::{
         BlocksQueryBlocksSnarkJobs, BlocksQueryBlocksTransactionsFeeTransfer,
     },
     common::{
+        amount::Amount,
         constants::{TXN_STATUS_APPLIED, TXN_STATUS_FAILED},
         functions::*,
         models::*,
+        pagination::Paginated,
         table::*,
     },
 };
@@ -30,10 +32,7 @@ impl TableData for Vec<Option<BlocksQueryBlocks>> {
                         format!("/blocks/{}/spotlight", get_state_hash(block)),
                     ),
                     convert_to_pill(get_global_slot(block), ColorVariant::Grey),
-                    convert_to_title(
-                        convert_to_local_timezone_formatted(&get_date_time(block)),
-                        get_date_time(block),
-                    ),
+                    convert_to_date_time(get_date_time(block)),
                     convert_to_linkable_address(
                         get_creator_username(block),
                         get_creator_account(block),
@@ -61,6 +60,41 @@ impl TableData for Vec<Option<BlocksQueryBlocks>> {
             })
             .collect()
     }
+
+    fn get_raw_rows(&self) -> Vec<Vec<String>> {
+        self.iter()
+            .flatten()
+            .map(|block| {
+                vec![
+                    opt_to_string(block.block_height),
+                    get_state_hash(block),
+                    opt_to_string(
+                        block
+                            .protocol_state
+                            .as_ref()
+                            .and_then(|o| o.consensus_state.as_ref())
+                            .and_then(|o| o.slot_since_genesis),
+                    ),
+                    get_date_time(block),
+                    get_creator_account(block),
+                    block
+                        .transactions
+                        .as_ref()
+                        .and_then(|o| o.coinbase.as_deref())
+                        .and_then(|c| c.parse::<Amount>().ok())
+                        .map(|c| c.to_mina_string())
+                        .unwrap_or_default(),
+                    format!(
+                        "{}/{}",
+                        opt_to_string(get_transaction_count(block)),
+                        block.block_num_zkapp_commands
+                    ),
+                    opt_to_string(get_snark_job_count(block)),
+                    get_coinbase_receiver(block),
+                ]
+            })
+            .collect()
+    }
 }
 
 impl TableData for Vec<Option<BlocksQueryBlocksTransactionsUserCommandsExt>> {
@@ -121,17 +155,35 @@ impl TableData for Vec<Option<BlocksQueryBlocksTransactionsUserCommandsExt>> {
                         user_command.get_to(),
                     ),
                     convert_to_pill(format_number(user_command.get_nonce()), ColorVariant::Grey),
-                    decorate_with_mina_tag(nanomina_to_mina(
-                        user_command.get_fee().parse::<u64>().ok().unwrap_or(0),
-                    )),
-                    decorate_with_mina_tag(nanomina_to_mina(
-                        user_command.get_amount().parse::<u64>().ok().unwrap_or(0),
-                    )),
+                    decorate_with_mina_tag(user_command.get_fee()),
+                    decorate_with_mina_tag(user_command.get_amount()),
                 ],
                 None => vec![],
             })
             .collect()
     }
+
+    fn get_raw_rows(&self) -> Vec<Vec<String>> {
+        self.iter()
+            .flatten()
+            .map(|user_command| {
+                vec![
+                    user_command.get_txn_hash(),
+                    user_command.get_kind(),
+                    if user_command.get_failure_reason().is_none() {
+                        TXN_STATUS_APPLIED.to_string()
+                    } else {
+                        TXN_STATUS_FAILED.to_string()
+                    },
+                    user_command.get_from(),
+                    user_command.get_to(),
+                    user_command.get_nonce(),
+                    opt_to_string(user_command.fee.map(|f| f.to_mina_string())),
+                    opt_to_string(user_command.amount.map(|a| a.to_mina_string())),
+                ]
+            })
+            .collect()
+    }
 }
 
 pub trait UserCommandTrait {
@@ -203,12 +255,12 @@ impl UserCommandTrait for BlocksQueryBlocksTransactionsUserCommandsExt {
     }
 
     fn get_fee(&self) -> String {
-        self.fee.map_or_else(|| "".to_string(), |o| o.to_string())
+        self.fee.map_or_else(String::new, |fee| fee.format())
     }
 
     fn get_amount(&self) -> String {
         self.amount
-            .map_or_else(|| "".to_string(), |o| o.to_string())
+            .map_or_else(String::new, |amount| amount.format())
     }
 }
 
@@ -221,10 +273,7 @@ impl TableData for Vec<Option<BlocksQueryBlocksSnarkJobs>> {
                         get_snark_block_state_hash(snark),
                         format!("/blocks/{}", get_snark_block_state_hash(snark)),
                     ),
-                    convert_to_title(
-                        convert_to_local_timezone_formatted(&get_snark_date_time(snark)),
-                        get_snark_date_time(snark),
-                    ),
+                    convert_to_date_time(get_snark_date_time(snark)),
                     convert_to_linkable_address(
                         get_snark_prover_username(snark),
                         get_snark_prover(snark),
@@ -235,6 +284,24 @@ impl TableData for Vec<Option<BlocksQueryBlocksSnarkJobs>> {
             })
             .collect::<Vec<_>>()
     }
+
+    fn get_raw_rows(&self) -> Vec<Vec<String>> {
+        self.iter()
+            .flatten()
+            .map(|snark| {
+                vec![
+                    get_snark_block_state_hash(snark),
+                    get_snark_date_time(snark),
+                    get_snark_prover(snark),
+                    opt_to_string(
+                        snark
+                            .fee
+                            .map(|fee| Amount::from_nanomina(fee).to_mina_string()),
+                    ),
+                ]
+            })
+            .collect()
+    }
 }
 
 impl TableData for Vec<Option<BlocksQueryBlocksTransactionsFeeTransfer>> {
@@ -253,6 +320,24 @@ impl TableData for Vec<Option<BlocksQueryBlocksTransactionsFeeTransfer>> {
             })
             .collect::<Vec<_>>()
     }
+
+    fn get_raw_rows(&self) -> Vec<Vec<String>> {
+        self.iter()
+            .flatten()
+            .map(|fee_transfer| {
+                vec![
+                    fee_transfer.get_receipient(),
+                    fee_transfer
+                        .fee
+                        .as_deref()
+                        .and_then(|fee| fee.parse::<Amount>().ok())
+                        .map(|fee| fee.to_mina_string())
+                        .unwrap_or_default(),
+                    fee_transfer.get_type(),
+                ]
+            })
+            .collect()
+    }
 }
 
 pub trait FeeTransferTrait {
@@ -274,7 +359,8 @@ impl FeeTransferTrait for BlocksQueryBlocksTransactionsFeeTransfer {
     fn get_fee(&self) -> String {
         self.fee
             .as_deref()
-            .map(nanomina_str_to_mina)
+            .and_then(|fee| fee.parse::<Amount>().ok())
+            .map(|fee| fee.format())
             .unwrap_or_default()
     }
     fn get_type(&self) -> String {
@@ -284,8 +370,14 @@ impl FeeTransferTrait for BlocksQueryBlocksTransactionsFeeTransfer {
     }
 }
 
-impl HasBlockHeight for BlocksQueryBlocks {
-    fn block_height(&self) -> Option<i64> {
-        self.block_height
+impl Paginated for BlocksQueryBlocks {
+    type Cursor = u64;
+
+    fn cursor(&self) -> Option<u64> {
+        self.block_height.map(|h| h as u64)
+    }
+
+    fn key(&self) -> Option<String> {
+        self.state_hash.clone()
     }
 }
@@ -1,4 +1,5 @@
-use leptos::{html::Textarea, web_sys::SubmitEvent, *};
+use crate::common::client::post_text;
+use leptos::{ev::SubmitEvent, html::Textarea, *};
 
 #[derive(Clone)]
 struct ActionInputs {
@@ -12,13 +13,7 @@ pub fn BroadcastForm(#[prop(into)] endpoint: String) -> impl IntoView {
 
     let submit_action = create_action(|input: &ActionInputs| {
         let input = input.clone();
-        async move {
-            reqwest::Client::new()
-                .post(&input.endpoint)
-                .body(input.value)
-                .send()
-                .await
-        }
+        async move { post_text(&input.endpoint, input.value).await }
     });
 
     view! {
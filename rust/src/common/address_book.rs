@@ -0,0 +1,214 @@
+use super::{functions::parse_csv, models::PublicKey};
+use leptos::*;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// Column headers used for both CSV and JSON import and export.
+pub const ADDRESS_BOOK_HEADERS: [&str; 3] = ["Public Key", "Label", "Tags"];
+
+/// A local label for a public key. Never leaves the browser.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct AddressBookEntry {
+    pub public_key: String,
+    pub label: String,
+    #[serde(default)]
+    pub tags: Vec<String>,
+}
+
+impl AddressBookEntry {
+    pub fn new(public_key: &str, label: &str, tags: &str) -> Result<Self, String> {
+        let public_key = public_key.trim();
+        PublicKey::validate(public_key)?;
+        let label = label.trim();
+        if label.is_empty() {
+            return Err(format!("Missing label for {}", public_key));
+        }
+        Ok(AddressBookEntry {
+            public_key: public_key.to_string(),
+            label: label.to_string(),
+            tags: split_tags(tags),
+        })
+    }
+}
+
+/// Persisted in local storage, in the order entries were added.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[serde(transparent)]
+pub struct AddressBook(pub Vec<AddressBookEntry>);
+
+impl AddressBook {
+    pub fn get(&self, public_key: &str) -> Option<&AddressBookEntry> {
+        self.0.iter().find(|entry| entry.public_key == public_key)
+    }
+
+    /// Replaces the entry for the same key, if any.
+    pub fn upsert(&mut self, entry: AddressBookEntry) {
+        match self
+            .0
+            .iter_mut()
+            .find(|existing| existing.public_key == entry.public_key)
+        {
+            Some(existing) => *existing = entry,
+            None => self.0.push(entry),
+        }
+    }
+
+    pub fn remove(&mut self, public_key: &str) {
+        self.0.retain(|entry| entry.public_key != public_key);
+    }
+
+    pub fn import(&mut self, entries: Vec<AddressBookEntry>) {
+        entries.into_iter().for_each(|entry| self.upsert(entry));
+    }
+}
+
+fn split_tags(tags: &str) -> Vec<String> {
+    tags.split(',')
+        .map(str::trim)
+        .filter(|tag| !tag.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+/// Reads entries exported as CSV or JSON. Any invalid entry fails the whole
+/// import, so that a bad file does not half-apply.
+pub fn parse_address_book(text: &str) -> Result<Vec<AddressBookEntry>, String> {
+    if text.trim_start().starts_with('[') {
+        parse_json(text)
+    } else {
+        parse_csv_entries(text)
+    }
+}
+
+fn parse_csv_entries(text: &str) -> Result<Vec<AddressBookEntry>, String> {
+    let mut rows = parse_csv(text).into_iter();
+    let headers = rows.next().ok_or("The file is empty")?;
+    let column = |name: &str| {
+        headers
+            .iter()
+            .position(|header| header.trim().eq_ignore_ascii_case(name))
+            .ok_or(format!("Missing \"{}\" column", name))
+    };
+    let (key_col, label_col) = (
+        column(ADDRESS_BOOK_HEADERS[0])?,
+        column(ADDRESS_BOOK_HEADERS[1])?,
+    );
+    let tags_col = column(ADDRESS_BOOK_HEADERS[2]).ok();
+    rows.filter(|row| row.iter().any(|field| !field.trim().is_empty()))
+        .map(|row| {
+            let field = |col: usize| row.get(col).map(String::as_str).unwrap_or_default();
+            AddressBookEntry::new(
+                field(key_col),
+                field(label_col),
+                tags_col.map(field).unwrap_or_default(),
+            )
+        })
+        .collect()
+}
+
+fn parse_json(text: &str) -> Result<Vec<AddressBookEntry>, String> {
+    let records: Vec<serde_json::Map<String, Value>> =
+        serde_json::from_str(text).map_err(|e| e.to_string())?;
+    records
+        .iter()
+        .map(|record| {
+            let field = |header: &str, key: &str| record.get(header).or_else(|| record.get(key));
+            let as_str = |value: Option<&Value>| {
+                value
+                    .and_then(Value::as_str)
+                    .unwrap_or_default()
+                    .to_string()
+            };
+            let tags = match field(ADDRESS_BOOK_HEADERS[2], "tags") {
+                Some(Value::Array(tags)) => tags
+                    .iter()
+                    .filter_map(Value::as_str)
+                    .collect::<Vec<_>>()
+                    .join(","),
+                other => as_str(other),
+            };
+            AddressBookEntry::new(
+                &as_str(field(ADDRESS_BOOK_HEADERS[0], "public_key")),
+                &as_str(field(ADDRESS_BOOK_HEADERS[1], "label")),
+                &tags,
+            )
+        })
+        .collect()
+}
+
+/// Provided by `AddressBookProvider`.
+#[derive(Clone, Copy)]
+pub struct AddressBookContext {
+    pub book: Signal<AddressBook>,
+    pub set_book: WriteSignal<AddressBook>,
+}
+
+pub fn use_address_book() -> AddressBookContext {
+    use_context::<AddressBookContext>().expect("Expected there to be an address book provided")
+}
+
+#[cfg(test)]
+mod address_book_tests {
+    use super::*;
+
+    const KEY_A: &str = "B62qrCz3ehCqi8Pn8y3vWC9zYEB9RKsidauv15DeZxhzkxL3bKeba5h";
+    const KEY_B: &str = "B62qrPN5Y5yq8kGE3FbVKbGTdTAJNdtNtB5sNVpxyRwWGcDEhpMzc8g";
+
+    #[test]
+    fn test_entry_validation() {
+        assert!(AddressBookEntry::new("B62qnotakey", "Mine", "").is_err());
+        assert!(AddressBookEntry::new(KEY_A, "  ", "").is_err());
+        assert_eq!(
+            AddressBookEntry::new(KEY_A, " Mine ", "cold, , exchange ").unwrap(),
+            AddressBookEntry {
+                public_key: KEY_A.to_string(),
+                label: "Mine".to_string(),
+                tags: vec!["cold".to_string(), "exchange".to_string()],
+            }
+        );
+    }
+
+    #[test]
+    fn test_upsert_and_remove() {
+        let mut book = AddressBook::default();
+        book.upsert(AddressBookEntry::new(KEY_A, "Old", "").unwrap());
+        book.upsert(AddressBookEntry::new(KEY_B, "Other", "").unwrap());
+        book.upsert(AddressBookEntry::new(KEY_A, "New", "").unwrap());
+        assert_eq!(book.0.len(), 2);
+        assert_eq!(book.get(KEY_A).map(|e| e.label.as_str()), Some("New"));
+        book.remove(KEY_A);
+        assert!(book.get(KEY_A).is_none());
+    }
+
+    #[test]
+    fn test_parse_csv() {
+        let csv = format!(
+            "Public Key,Label,Tags\r\n{},\"Cold, wallet\",\"a,b\"\r\n\r\n{},Exchange,\r\n",
+            KEY_A, KEY_B
+        );
+        let entries = parse_address_book(&csv).unwrap();
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].label, "Cold, wallet");
+        assert_eq!(entries[0].tags, vec!["a", "b"]);
+        assert!(entries[1].tags.is_empty());
+    }
+
+    #[test]
+    fn test_parse_csv_requires_columns() {
+        assert!(parse_address_book("Key,Name\r\n").is_err());
+        assert!(parse_address_book("Public Key,Label\r\nB62qbad,x\r\n").is_err());
+    }
+
+    #[test]
+    fn test_parse_json() {
+        let json = format!(
+            r#"[{{"Public Key": "{}", "Label": "Mine", "Tags": "a, b"}},
+                {{"public_key": "{}", "label": "Other", "tags": ["c"]}}]"#,
+            KEY_A, KEY_B
+        );
+        let entries = parse_address_book(&json).unwrap();
+        assert_eq!(entries[0].tags, vec!["a", "b"]);
+        assert_eq!(entries[1].label, "Other");
+        assert_eq!(entries[1].tags, vec!["c"]);
+    }
+}
@@ -0,0 +1,409 @@
+use super::functions::format_mina;
+use rust_decimal::prelude::*;
+use serde::{Deserialize, Deserializer, Serialize, Serializer, de};
+use std::{
+    fmt,
+    iter::Sum,
+    ops::{Add, AddAssign, Neg, Sub},
+    str::FromStr,
+};
+
+const MINA_SCALE: u32 = 9;
+const NANOMINA_PER_MINA: i128 = 1_000_000_000;
+
+/// An exact amount of MINA, kept as a whole number of nanomina.
+///
+/// Deserializes from a JSON number or a nanomina string, and serializes as an
+/// integer. Numbers are read from their text (serde_json's
+/// `arbitrary_precision`), so large `Float`s stay exact. The indexer's nanomina
+/// `Float` fields map to this type through `mina-explorer.typed.graphql`, see
+/// `test_typed_schema_is_current`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Amount(i128);
+
+impl Amount {
+    pub const ZERO: Amount = Amount(0);
+
+    pub fn from_nanomina(nanomina: impl Into<i128>) -> Self {
+        Amount(nanomina.into())
+    }
+
+    /// Parses a MINA denominated decimal such as `"1.5"`, rounding to the
+    /// nearest nanomina.
+    pub fn from_mina(mina: &str) -> Result<Self, String> {
+        let dec =
+            Decimal::from_str(mina.trim()).map_err(|_| format!("Invalid MINA amount: {}", mina))?;
+        dec.checked_mul(Decimal::from(NANOMINA_PER_MINA))
+            .and_then(|nanomina| nanomina.round().to_i128())
+            .map(Amount)
+            .ok_or_else(|| format!("MINA amount out of range: {}", mina))
+    }
+
+    /// Checks a MINA amount typed into a filter.
+    pub fn validate_mina(mina: &str) -> Result<(), String> {
+        match Amount::from_mina(mina)? {
+            amount if amount.is_negative() => Err(format!("Negative MINA amount: {}", mina)),
+            _ => Ok(()),
+        }
+    }
+
+    pub fn nanomina(self) -> i128 {
+        self.0
+    }
+
+    pub fn is_negative(self) -> bool {
+        self.0 < 0
+    }
+
+    pub fn abs(self) -> Self {
+        Amount(self.0.abs())
+    }
+
+    /// The exact amount in MINA with all nine decimals, e.g. `1.500000000`.
+    pub fn to_mina_string(self) -> String {
+        let sign = if self.is_negative() { "-" } else { "" };
+        let nanomina = self.0.unsigned_abs();
+        format!(
+            "{}{}.{:0width$}",
+            sign,
+            nanomina / NANOMINA_PER_MINA as u128,
+            nanomina % NANOMINA_PER_MINA as u128,
+            width = MINA_SCALE as usize
+        )
+    }
+
+    /// The amount in MINA without trailing zeros, e.g. `1.5`. Unlike `format`
+    /// it does not need a browser, so the server can render it.
+    pub fn to_short_mina_string(self) -> String {
+        let mina = self.to_mina_string();
+        mina.trim_end_matches('0').trim_end_matches('.').to_string()
+    }
+
+    /// The amount in MINA formatted for the browser locale.
+    pub fn format(self) -> String {
+        let formatted = format_mina(self.abs().to_mina_string());
+        if self.is_negative() {
+            format!("-{}", formatted)
+        } else {
+            formatted
+        }
+    }
+
+    /// Only for charts and ratios, where precision does not matter.
+    pub fn to_mina_f64(self) -> f64 {
+        self.0 as f64 / NANOMINA_PER_MINA as f64
+    }
+}
+
+impl fmt::Display for Amount {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.to_mina_string())
+    }
+}
+
+/// Parses a nanomina number, which may be written as a decimal or in
+/// scientific notation.
+impl FromStr for Amount {
+    type Err = String;
+
+    fn from_str(nanomina: &str) -> Result<Self, Self::Err> {
+        let nanomina = nanomina.trim();
+        if nanomina.contains(['e', 'E']) {
+            Decimal::from_scientific(nanomina)
+        } else {
+            Decimal::from_str(nanomina)
+        }
+        .ok()
+        .and_then(|dec| dec.round().to_i128())
+        .map(Amount)
+        .ok_or_else(|| format!("Invalid nanomina amount: {}", nanomina))
+    }
+}
+
+impl Add for Amount {
+    type Output = Amount;
+
+    fn add(self, other: Amount) -> Amount {
+        Amount(self.0 + other.0)
+    }
+}
+
+impl AddAssign for Amount {
+    fn add_assign(&mut self, other: Amount) {
+        self.0 += other.0;
+    }
+}
+
+impl Sub for Amount {
+    type Output = Amount;
+
+    fn sub(self, other: Amount) -> Amount {
+        Amount(self.0 - other.0)
+    }
+}
+
+impl Neg for Amount {
+    type Output = Amount;
+
+    fn neg(self) -> Amount {
+        Amount(-self.0)
+    }
+}
+
+impl Sum for Amount {
+    fn sum<I: Iterator<Item = Amount>>(iter: I) -> Amount {
+        iter.fold(Amount::ZERO, Add::add)
+    }
+}
+
+impl<'a> Sum<&'a Amount> for Amount {
+    fn sum<I: Iterator<Item = &'a Amount>>(iter: I) -> Amount {
+        iter.copied().sum()
+    }
+}
+
+impl Serialize for Amount {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_i128(self.0)
+    }
+}
+
+impl<'de> Deserialize<'de> for Amount {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(AmountVisitor)
+    }
+}
+
+/// The key serde_json uses for a number it passes on as text.
+const ARBITRARY_PRECISION_NUMBER: &str = "$serde_json::private::Number";
+
+struct AmountVisitor;
+
+impl<'de> de::Visitor<'de> for AmountVisitor {
+    type Value = Amount;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "an amount of nanomina")
+    }
+
+    fn visit_i64<E: de::Error>(self, v: i64) -> Result<Amount, E> {
+        Ok(Amount::from_nanomina(v))
+    }
+
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<Amount, E> {
+        Ok(Amount::from_nanomina(v))
+    }
+
+    fn visit_i128<E: de::Error>(self, v: i128) -> Result<Amount, E> {
+        Ok(Amount::from_nanomina(v))
+    }
+
+    /// Only exact below 2^53. serde_json hands over numbers that do not fit a
+    /// `u64` or `i64` as text instead, see `visit_map`.
+    fn visit_f64<E: de::Error>(self, v: f64) -> Result<Amount, E> {
+        Ok(Amount(v.round() as i128))
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Amount, E> {
+        v.parse().map_err(E::custom)
+    }
+
+    /// A number with `arbitrary_precision`, as a map holding its text.
+    fn visit_map<A: de::MapAccess<'de>>(self, mut map: A) -> Result<Amount, A::Error> {
+        match map.next_entry::<String, String>()? {
+            Some((key, number)) if key == ARBITRARY_PRECISION_NUMBER => {
+                number.parse().map_err(de::Error::custom)
+            }
+            _ => Err(de::Error::invalid_type(de::Unexpected::Map, &self)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod amount_tests {
+    use super::*;
+
+    #[test]
+    fn test_to_mina_string() {
+        assert_eq!(
+            Amount::from_nanomina(1_500_000_000).to_mina_string(),
+            "1.500000000"
+        );
+        assert_eq!(Amount::from_nanomina(1).to_mina_string(), "0.000000001");
+        assert_eq!(
+            Amount::from_nanomina(-720_000_000_000i64).to_string(),
+            "-720.000000000"
+        );
+        assert_eq!(Amount::ZERO.to_string(), "0.000000000");
+    }
+
+    #[test]
+    fn test_to_short_mina_string() {
+        assert_eq!(
+            Amount::from_nanomina(1_500_000_000).to_short_mina_string(),
+            "1.5"
+        );
+        assert_eq!(
+            Amount::from_nanomina(-720_000_000_000i64).to_short_mina_string(),
+            "-720"
+        );
+        assert_eq!(
+            Amount::from_nanomina(1).to_short_mina_string(),
+            "0.000000001"
+        );
+        assert_eq!(Amount::ZERO.to_short_mina_string(), "0");
+    }
+
+    #[test]
+    fn test_large_amounts_are_exact() {
+        // Beyond 2^53 nanomina an f64 can no longer hold every value.
+        let balance = Amount::from_nanomina(u64::MAX);
+        assert_eq!(balance.to_string(), "18446744073.709551615");
+        let total: Amount = [balance, balance, Amount::from_nanomina(1)].iter().sum();
+        assert_eq!(total.nanomina(), 2 * u64::MAX as i128 + 1);
+    }
+
+    #[test]
+    fn test_from_mina() {
+        assert_eq!(
+            Amount::from_mina("1.5"),
+            Ok(Amount::from_nanomina(1_500_000_000))
+        );
+        assert_eq!(
+            Amount::from_mina("1105297372.840039233"),
+            Ok(Amount::from_nanomina(1_105_297_372_840_039_233i64))
+        );
+        assert_eq!(Amount::from_mina("0.0000000004"), Ok(Amount::ZERO));
+        assert!(Amount::from_mina("abc").is_err());
+    }
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!(
+            "720000000000".parse(),
+            Ok(Amount::from_nanomina(720_000_000_000i64))
+        );
+        assert_eq!("-5".parse(), Ok(Amount::from_nanomina(-5)));
+        assert!("".parse::<Amount>().is_err());
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        let a = Amount::from_nanomina(10);
+        let b = Amount::from_nanomina(25);
+        assert_eq!(a - b, Amount::from_nanomina(-15));
+        assert_eq!(-(a + b), Amount::from_nanomina(-35));
+        assert_eq!((a - b).abs(), Amount::from_nanomina(15));
+    }
+
+    #[test]
+    fn test_deserialize_scalars() {
+        let amounts: Vec<Amount> =
+            serde_json::from_str(r#"[1000000000, 1.0e9, "1000000000", 18446744073709551615]"#)
+                .unwrap();
+        assert_eq!(amounts[0], Amount::from_nanomina(1_000_000_000));
+        assert_eq!(amounts[1], amounts[0]);
+        assert_eq!(amounts[2], amounts[0]);
+        assert_eq!(amounts[3], Amount::from_nanomina(u64::MAX));
+    }
+
+    #[test]
+    fn test_deserialize_large_numbers() {
+        // Neither fits a u64, and an f64 would round all of them
+        let amounts: Vec<Amount> = serde_json::from_str(
+            r#"[18446744073709551617, 18446744073709551617.0, 1.8446744073709551617e19]"#,
+        )
+        .unwrap();
+        let expected = Amount::from_nanomina(u64::MAX as i128 + 2);
+        assert!(amounts.iter().all(|amount| *amount == expected));
+        let value: serde_json::Value = serde_json::from_str("9007199254740993.0").unwrap();
+        assert_eq!(
+            serde_json::from_value::<Amount>(value).unwrap(),
+            Amount::from_nanomina(9_007_199_254_740_993i64)
+        );
+    }
+
+    #[test]
+    fn test_graphql_amounts_are_exact() {
+        use crate::user_commands::graphql::transactions_query::TransactionsQueryTransactions;
+        // 2^53 + 1, the first integer an f64 cannot represent
+        let txn: TransactionsQueryTransactions =
+            serde_json::from_str(r#"{"amount": 9007199254740993.0, "fee": "9007199254740993"}"#)
+                .unwrap();
+        assert_eq!(
+            txn.amount,
+            Some(Amount::from_nanomina(9_007_199_254_740_993i64))
+        );
+        assert_eq!(txn.fee, txn.amount);
+    }
+
+    const SCHEMA: &str = "graphql/schemas/mina-explorer.graphql";
+    const TYPED_SCHEMA: &str = "graphql/schemas/mina-explorer.typed.graphql";
+    const AMOUNT_FIELDS: [&str; 3] = ["amount", "fee", "totalCurrency"];
+
+    /// Declares the nanomina `Float` fields of the indexer's schema, and the
+    /// filters on them, as the `Amount` scalar.
+    fn type_amounts(schema: &str) -> String {
+        let fields = schema.lines().map(|line| {
+            let Some((name, ty)) = line.trim_start().split_once(": ") else {
+                return line.to_string();
+            };
+            let field = name.split_once('_').map_or(name, |(field, _)| field);
+            if line.starts_with(' ')
+                && AMOUNT_FIELDS.contains(&field)
+                && ty.trim_start_matches('[').starts_with("Float")
+            {
+                line.replacen("Float", "Amount", 1)
+            } else {
+                line.to_string()
+            }
+        });
+        let header = indoc::indoc! {r#"
+            # Generated from mina-explorer.graphql by `rake graphql_schema`. Do not edit.
+
+            """
+            Nanomina. The indexer sends these as `Float`; declaring them as a custom
+            scalar lets them deserialize straight into `Amount` without passing through
+            an `f64`.
+            """
+            scalar Amount
+        "#};
+        std::iter::once(header.to_string())
+            .chain(fields)
+            .collect::<Vec<_>>()
+            .join("\n")
+            + "\n"
+    }
+
+    /// The queries use the typed schema. Refresh it after updating the vendored
+    /// one with `UPDATE_SCHEMA=1 cargo test typed_schema`.
+    #[test]
+    fn test_typed_schema_is_current() {
+        let path = |file| std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join(file);
+        let typed = type_amounts(&std::fs::read_to_string(path(SCHEMA)).unwrap());
+        if std::env::var_os("UPDATE_SCHEMA").is_some() {
+            std::fs::write(path(TYPED_SCHEMA), &typed).unwrap();
+        }
+        assert!(
+            std::fs::read_to_string(path(TYPED_SCHEMA)).unwrap() == typed,
+            "{} is out of date, run `rake graphql_schema`",
+            TYPED_SCHEMA
+        );
+    }
+
+    #[test]
+    fn test_serialize_round_trip() {
+        let amount = Amount::from_nanomina(u64::MAX);
+        let json = serde_json::to_string(&amount).unwrap();
+        assert_eq!(json, "18446744073709551615");
+        assert_eq!(serde_json::from_str::<Amount>(&json).unwrap(), amount);
+    }
+
+    #[test]
+    fn test_validate_mina() {
+        assert!(Amount::validate_mina("10000").is_ok());
+        assert!(Amount::validate_mina("0.5").is_ok());
+        assert!(Amount::validate_mina("-1").is_err());
+        assert!(Amount::validate_mina("ten").is_err());
+    }
+}
@@ -0,0 +1,194 @@
+use sha2::{Digest, Sha256};
+use std::fmt;
+
+const ALPHABET: &[u8; 58] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+const CHECKSUM_LEN: usize = 4;
+
+/// Version bytes Mina prefixes each kind of identifier with before encoding.
+pub const PUBLIC_KEY_VERSION: u8 = 0xcb;
+pub const STATE_HASH_VERSION: u8 = 0x10;
+pub const LEDGER_HASH_VERSION: u8 = 0x05;
+pub const TOKEN_ID_VERSION: u8 = 0x1c;
+/// Signed command hashes from before the Berkeley upgrade (`Ckp...`).
+pub const TXN_HASH_V1_VERSION: u8 = 0x12;
+pub const TXN_HASH_VERSION: u8 = 0x1d;
+pub const MEMO_VERSION: u8 = 0x14;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Base58Error {
+    Empty,
+    InvalidCharacter(char),
+    TooShort,
+    InvalidChecksum,
+    InvalidVersion(u8),
+}
+
+impl fmt::Display for Base58Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Base58Error::Empty => write!(f, "empty"),
+            Base58Error::InvalidCharacter(c) => write!(f, "invalid character '{}'", c),
+            Base58Error::TooShort => write!(f, "too short"),
+            Base58Error::InvalidChecksum => write!(f, "invalid checksum"),
+            Base58Error::InvalidVersion(_) => write!(f, "wrong kind of identifier"),
+        }
+    }
+}
+
+pub fn decode(input: &str) -> Result<Vec<u8>, Base58Error> {
+    if input.is_empty() {
+        return Err(Base58Error::Empty);
+    }
+    // Little-endian base 256 digits of the number `input` encodes.
+    let mut bytes: Vec<u8> = vec![];
+    for c in input.chars() {
+        let mut carry = ALPHABET
+            .iter()
+            .position(|&a| a as char == c)
+            .ok_or(Base58Error::InvalidCharacter(c))? as u32;
+        for byte in bytes.iter_mut() {
+            carry += (*byte as u32) * 58;
+            *byte = carry as u8;
+            carry >>= 8;
+        }
+        while carry > 0 {
+            bytes.push(carry as u8);
+            carry >>= 8;
+        }
+    }
+    // Each leading '1' stands for a leading zero byte.
+    let zeros = input.chars().take_while(|&c| c == '1').count();
+    bytes.extend(std::iter::repeat_n(0, zeros));
+    bytes.reverse();
+    Ok(bytes)
+}
+
+/// Decodes `input`, verifies its checksum and that it starts with one of
+/// `versions`, and returns the payload after the version byte.
+pub fn decode_check(input: &str, versions: &[u8]) -> Result<Vec<u8>, Base58Error> {
+    let bytes = decode(input)?;
+    if bytes.len() <= CHECKSUM_LEN {
+        return Err(Base58Error::TooShort);
+    }
+    let (data, checksum) = bytes.split_at(bytes.len() - CHECKSUM_LEN);
+    if Sha256::digest(Sha256::digest(data))[..CHECKSUM_LEN] != *checksum {
+        return Err(Base58Error::InvalidChecksum);
+    }
+    match data[0] {
+        version if versions.contains(&version) => Ok(data[1..].to_vec()),
+        version => Err(Base58Error::InvalidVersion(version)),
+    }
+}
+
+pub fn encode(bytes: &[u8]) -> String {
+    // Little-endian base 58 digits of the number `bytes` encodes.
+    let mut digits: Vec<u8> = vec![];
+    for &byte in bytes {
+        let mut carry = byte as u32;
+        for digit in digits.iter_mut() {
+            carry += (*digit as u32) << 8;
+            *digit = (carry % 58) as u8;
+            carry /= 58;
+        }
+        while carry > 0 {
+            digits.push((carry % 58) as u8);
+            carry /= 58;
+        }
+    }
+    let zeros = bytes.iter().take_while(|&&byte| byte == 0).count();
+    std::iter::repeat_n('1', zeros)
+        .chain(
+            digits
+                .iter()
+                .rev()
+                .map(|&digit| ALPHABET[digit as usize] as char),
+        )
+        .collect()
+}
+
+/// The inverse of `decode_check`.
+pub fn encode_check(version: u8, payload: &[u8]) -> String {
+    let mut data = vec![version];
+    data.extend_from_slice(payload);
+    let checksum = Sha256::digest(Sha256::digest(&data));
+    data.extend_from_slice(&checksum[..CHECKSUM_LEN]);
+    encode(&data)
+}
+
+#[cfg(test)]
+mod base58_tests {
+    use super::*;
+
+    const PUBLIC_KEY: &str = "B62qrPN5Y5yq8kGE3FbVKbGTdTAJNdtNtB5sNVpxyRwWGcDEhpMzc8g";
+    const STATE_HASH: &str = "3NKeMoncuHab5ScarV5ViyF16cJPT4taWNSaTLS64Dp67wuXigPZ";
+    const LEDGER_HASH: &str = "jxsAidvKvEQJMC7Z2wkLrFGzCqUxpFMRhAj4K5o49eiFLhKSyXL";
+    const TOKEN_ID: &str = "wSHV2S4qX9jFsLjQo8r1BsMLH2ZRKsZx6EJd1sbozGPieEC4Jf";
+    const TXN_HASH: &str = "5JuJ1eRNWdE8jSMmCDoHnAdBGhLyBnCk2gkcvkfCZ7WvrKtGuWHB";
+    const TXN_HASH_V1: &str = "CkpYeG32dVJUjs6iq3oroXWitXar1eBtV3GVFyH5agw7HPp9bG4yQ";
+
+    #[test]
+    fn test_decode() {
+        assert_eq!(decode("1"), Ok(vec![0]));
+        assert_eq!(decode("2g"), Ok(vec![97]));
+        assert_eq!(decode("11111"), Ok(vec![0, 0, 0, 0, 0]));
+        assert_eq!(decode("StV1DL6CwTryKyV"), Ok(b"hello world".to_vec()));
+        assert_eq!(decode("0OIl"), Err(Base58Error::InvalidCharacter('0')));
+        assert_eq!(decode(""), Err(Base58Error::Empty));
+    }
+
+    #[test]
+    fn test_encode() {
+        assert_eq!(encode(&[0]), "1");
+        assert_eq!(encode(&[97]), "2g");
+        assert_eq!(encode(b"hello world"), "StV1DL6CwTryKyV");
+        assert_eq!(encode(&[]), "");
+        for id in [PUBLIC_KEY, STATE_HASH, TXN_HASH_V1] {
+            assert_eq!(encode(&decode(id).unwrap()), id);
+        }
+    }
+
+    #[test]
+    fn test_encode_check() {
+        let payload = decode_check(LEDGER_HASH, &[LEDGER_HASH_VERSION]).unwrap();
+        assert_eq!(encode_check(LEDGER_HASH_VERSION, &payload), LEDGER_HASH);
+    }
+
+    #[test]
+    fn test_decode_check_identifiers() {
+        for (id, version) in [
+            (PUBLIC_KEY, PUBLIC_KEY_VERSION),
+            (STATE_HASH, STATE_HASH_VERSION),
+            (LEDGER_HASH, LEDGER_HASH_VERSION),
+            (TOKEN_ID, TOKEN_ID_VERSION),
+            (TXN_HASH, TXN_HASH_VERSION),
+            (TXN_HASH_V1, TXN_HASH_V1_VERSION),
+        ] {
+            assert!(decode_check(id, &[version]).is_ok(), "{}", id);
+        }
+    }
+
+    #[test]
+    fn test_decode_check_invalid_checksum() {
+        let mistyped = PUBLIC_KEY.replace("Y5yq", "Y5yr");
+        assert_eq!(
+            decode_check(&mistyped, &[PUBLIC_KEY_VERSION]),
+            Err(Base58Error::InvalidChecksum)
+        );
+    }
+
+    #[test]
+    fn test_decode_check_wrong_version() {
+        assert_eq!(
+            decode_check(STATE_HASH, &[PUBLIC_KEY_VERSION]),
+            Err(Base58Error::InvalidVersion(STATE_HASH_VERSION))
+        );
+    }
+
+    #[test]
+    fn test_decode_check_too_short() {
+        assert_eq!(
+            decode_check("2g", &[PUBLIC_KEY_VERSION]),
+            Err(Base58Error::TooShort)
+        );
+    }
+}
@@ -0,0 +1,163 @@
+use chrono::{DateTime, Duration, Utc};
+use std::collections::HashMap;
+
+struct CacheEntry {
+    body: String,
+    fetched_at: DateTime<Utc>,
+}
+
+/// Raw GraphQL response bodies keyed by endpoint, operation and variables.
+/// Entries expire after `ttl`; once `capacity` is reached the oldest entry
+/// makes room for the newest.
+///
+/// Every `clear` starts a new generation. Responses to requests started in an
+/// earlier one are stale and are kept out with `insert_if_current`.
+pub struct QueryCache {
+    entries: HashMap<String, CacheEntry>,
+    ttl: Duration,
+    capacity: usize,
+    generation: u64,
+}
+
+impl QueryCache {
+    pub fn new(ttl: Duration, capacity: usize) -> Self {
+        QueryCache {
+            entries: HashMap::new(),
+            ttl,
+            capacity,
+            generation: 0,
+        }
+    }
+
+    pub fn generation(&self) -> u64 {
+        self.generation
+    }
+
+    pub fn key(endpoint: &str, operation_name: &str, variables: &str) -> String {
+        format!("{}|{}|{}", endpoint, operation_name, variables)
+    }
+
+    pub fn get(&self, key: &str, now: DateTime<Utc>) -> Option<String> {
+        self.entries
+            .get(key)
+            .filter(|entry| now - entry.fetched_at < self.ttl)
+            .map(|entry| entry.body.clone())
+    }
+
+    pub fn insert(&mut self, key: String, body: String, now: DateTime<Utc>) {
+        let ttl = self.ttl;
+        self.entries.retain(|_, entry| now - entry.fetched_at < ttl);
+        if !self.entries.contains_key(&key) && self.entries.len() >= self.capacity {
+            if let Some(oldest) = self
+                .entries
+                .iter()
+                .min_by_key(|(_, entry)| entry.fetched_at)
+                .map(|(key, _)| key.clone())
+            {
+                self.entries.remove(&oldest);
+            }
+        }
+        self.entries.insert(
+            key,
+            CacheEntry {
+                body,
+                fetched_at: now,
+            },
+        );
+    }
+
+    /// Inserts `body` unless the cache was cleared since `generation`.
+    pub fn insert_if_current(
+        &mut self,
+        generation: u64,
+        key: String,
+        body: String,
+        now: DateTime<Utc>,
+    ) {
+        if generation == self.generation {
+            self.insert(key, body, now);
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.generation += 1;
+    }
+}
+
+#[cfg(test)]
+mod query_cache_tests {
+    use super::*;
+
+    fn cache() -> QueryCache {
+        QueryCache::new(Duration::seconds(60), 2)
+    }
+
+    #[test]
+    fn test_hit_within_ttl() {
+        let now = Utc::now();
+        let mut cache = cache();
+        cache.insert("a".to_string(), "body".to_string(), now);
+        assert_eq!(
+            cache.get("a", now + Duration::seconds(59)),
+            Some("body".to_string())
+        );
+    }
+
+    #[test]
+    fn test_miss_after_ttl() {
+        let now = Utc::now();
+        let mut cache = cache();
+        cache.insert("a".to_string(), "body".to_string(), now);
+        assert_eq!(cache.get("a", now + Duration::seconds(60)), None);
+    }
+
+    #[test]
+    fn test_evicts_oldest_at_capacity() {
+        let now = Utc::now();
+        let mut cache = cache();
+        cache.insert("a".to_string(), "1".to_string(), now);
+        cache.insert("b".to_string(), "2".to_string(), now + Duration::seconds(1));
+        cache.insert("c".to_string(), "3".to_string(), now + Duration::seconds(2));
+        assert_eq!(cache.get("a", now + Duration::seconds(2)), None);
+        assert_eq!(
+            cache.get("b", now + Duration::seconds(2)),
+            Some("2".to_string())
+        );
+        assert_eq!(
+            cache.get("c", now + Duration::seconds(2)),
+            Some("3".to_string())
+        );
+    }
+
+    #[test]
+    fn test_clear() {
+        let now = Utc::now();
+        let mut cache = cache();
+        cache.insert("a".to_string(), "body".to_string(), now);
+        cache.clear();
+        assert_eq!(cache.get("a", now), None);
+    }
+
+    #[test]
+    fn test_skips_response_started_before_clear() {
+        let now = Utc::now();
+        let mut cache = cache();
+        let started = cache.generation();
+        cache.clear();
+        cache.insert_if_current(started, "a".to_string(), "stale".to_string(), now);
+        assert_eq!(cache.get("a", now), None);
+
+        let restarted = cache.generation();
+        cache.insert_if_current(restarted, "a".to_string(), "fresh".to_string(), now);
+        assert_eq!(cache.get("a", now), Some("fresh".to_string()));
+    }
+
+    #[test]
+    fn test_key_includes_endpoint() {
+        assert_ne!(
+            QueryCache::key("https://a/graphql", "BlocksQuery", "{}"),
+            QueryCache::key("https://b/graphql", "BlocksQuery", "{}")
+        );
+    }
+}
@@ -0,0 +1,263 @@
+use super::{cache::QueryCache, constants::*, models::MyError, network::Network};
+use chrono::{Duration, Utc};
+use futures::future::{LocalBoxFuture, Shared};
+use graphql_client::{GraphQLQuery, Response};
+use serde::de::DeserializeOwned;
+use std::{cell::RefCell, collections::HashMap};
+#[cfg(not(feature = "mock"))]
+use {futures::future::FutureExt, leptos::logging, std::future::Future};
+
+type InFlight = Shared<LocalBoxFuture<'static, Result<String, MyError>>>;
+
+thread_local! {
+    #[cfg(not(feature = "mock"))]
+    static CLIENT: reqwest::Client = reqwest::Client::new();
+    static CACHE: RefCell<QueryCache> = RefCell::new(QueryCache::new(
+        Duration::milliseconds(QUERY_CACHE_TTL as i64),
+        QUERY_CACHE_CAPACITY,
+    ));
+    static IN_FLIGHT: RefCell<HashMap<String, InFlight>> = RefCell::new(HashMap::new());
+}
+
+#[cfg(not(feature = "mock"))]
+fn client() -> reqwest::Client {
+    CLIENT.with(|client| client.clone())
+}
+
+/// Drops every cached response, e.g. once a new block makes them stale.
+/// Requests still in flight are forgotten too, so that no later request
+/// shares them and their responses do not reach the cache.
+pub fn invalidate_query_cache() {
+    CACHE.with(|cache| cache.borrow_mut().clear());
+    IN_FLIGHT.with(|in_flight| in_flight.borrow_mut().clear());
+}
+
+/// Delay before retrying after the given (zero-based) failed attempt.
+#[cfg(any(test, not(feature = "mock")))]
+fn backoff_delay(attempt: u32) -> std::time::Duration {
+    std::time::Duration::from_millis(QUERY_RETRY_BASE_DELAY << attempt)
+}
+
+#[cfg(all(feature = "ssr", not(feature = "mock")))]
+async fn sleep(duration: std::time::Duration) {
+    tokio::time::sleep(duration).await
+}
+
+#[cfg(not(any(feature = "ssr", feature = "mock")))]
+async fn sleep(duration: std::time::Duration) {
+    let (tx, rx) = futures::channel::oneshot::channel();
+    leptos::set_timeout(
+        move || {
+            let _ = tx.send(());
+        },
+        duration,
+    );
+    let _ = rx.await;
+}
+
+/// Repeats `request` with exponential backoff for as long as it fails with a
+/// retryable error, up to `QUERY_RETRY_ATTEMPTS` extra attempts.
+#[cfg(not(feature = "mock"))]
+async fn with_retry<T, F, Fut>(mut request: F) -> Result<T, MyError>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T, MyError>>,
+{
+    let mut attempt = 0;
+    loop {
+        match request().await {
+            Err(err) if err.is_retryable() && attempt < QUERY_RETRY_ATTEMPTS => {
+                logging::warn!("Request failed ({}), retrying", err);
+                sleep(backoff_delay(attempt)).await;
+                attempt += 1;
+            }
+            result => return result,
+        }
+    }
+}
+
+#[cfg(not(feature = "mock"))]
+fn check_status(response: reqwest::Response) -> Result<reqwest::Response, MyError> {
+    let status = response.status();
+    if status.is_success() {
+        Ok(response)
+    } else {
+        Err(MyError::HttpError {
+            status: status.as_u16(),
+            message: status
+                .canonical_reason()
+                .unwrap_or("Request failed")
+                .to_string(),
+        })
+    }
+}
+
+#[cfg(not(feature = "mock"))]
+async fn post_json(endpoint: String, body: String) -> Result<String, MyError> {
+    with_retry(|| async {
+        let response = client()
+            .post(&endpoint)
+            .header("Content-Type", "application/json")
+            .body(body.clone())
+            .send()
+            .await?;
+        check_status(response)?
+            .text()
+            .await
+            .map_err(|e| MyError::NetworkError(e.to_string()))
+    })
+    .await
+}
+
+/// Posts `body`, sharing the request with any identical one still in flight.
+#[cfg(not(feature = "mock"))]
+async fn post_deduplicated(key: String, endpoint: String, body: String) -> Result<String, MyError> {
+    let request = IN_FLIGHT.with(|in_flight| {
+        in_flight
+            .borrow_mut()
+            .entry(key.clone())
+            .or_insert_with(|| post_json(endpoint, body).boxed_local().shared())
+            .clone()
+    });
+    // Awaiting consumes the handle, so keep one to recognise the entry by.
+    let handle = request.clone();
+    let result = request.await;
+    IN_FLIGHT.with(|in_flight| {
+        let mut in_flight = in_flight.borrow_mut();
+        // A newer request may have taken the key since
+        if in_flight
+            .get(&key)
+            .is_some_and(|stored| stored.ptr_eq(&handle))
+        {
+            in_flight.remove(&key);
+        }
+    });
+    result
+}
+
+#[cfg(not(feature = "mock"))]
+async fn send_graphql(
+    key: String,
+    endpoint: String,
+    _operation_name: &str,
+    body: String,
+) -> Result<String, MyError> {
+    post_deduplicated(key, endpoint, body).await
+}
+
+#[cfg(feature = "mock")]
+async fn send_graphql(
+    _key: String,
+    _endpoint: String,
+    operation_name: &str,
+    _body: String,
+) -> Result<String, MyError> {
+    super::mock::graphql_response(operation_name)
+}
+
+/// Runs a typed GraphQL query against the network's indexer. Successful
+/// responses are cached per operation and variables for `QUERY_CACHE_TTL`.
+pub async fn query_graphql<Q: GraphQLQuery>(
+    network: &Network,
+    variables: Q::Variables,
+) -> Result<Q::ResponseData, MyError> {
+    let body = Q::build_query(variables);
+    let variables_json =
+        serde_json::to_string(&body.variables).map_err(|e| MyError::ParseError(e.to_string()))?;
+    let key = QueryCache::key(
+        &network.graphql_endpoint,
+        body.operation_name,
+        &variables_json,
+    );
+
+    let (cached, generation) = CACHE.with(|cache| {
+        let cache = cache.borrow();
+        (cache.get(&key, Utc::now()), cache.generation())
+    });
+    let is_hit = cached.is_some();
+    let text = match cached {
+        Some(text) => text,
+        None => {
+            let body_json =
+                serde_json::to_string(&body).map_err(|e| MyError::ParseError(e.to_string()))?;
+            send_graphql(
+                key.clone(),
+                network.graphql_endpoint.to_string(),
+                body.operation_name,
+                body_json,
+            )
+            .await?
+        }
+    };
+
+    let response = serde_json::from_str::<Response<Q::ResponseData>>(&text)
+        .map_err(|e| MyError::ParseError(e.to_string()))?;
+
+    if let Some(errors) = response.errors {
+        return Err(MyError::GraphQLError(errors));
+    }
+
+    let data = response
+        .data
+        .ok_or(MyError::GraphQLEmpty("No data available".to_string()))?;
+    // Re-inserting a hit would restart its TTL and keep it from ever expiring
+    if !is_hit {
+        CACHE.with(|cache| {
+            cache
+                .borrow_mut()
+                .insert_if_current(generation, key, text, Utc::now())
+        });
+    }
+    Ok(data)
+}
+
+#[cfg(not(feature = "mock"))]
+async fn get_text(url: &str) -> Result<String, MyError> {
+    let response = with_retry(|| async { check_status(client().get(url).send().await?) }).await?;
+    response
+        .text()
+        .await
+        .map_err(|e| MyError::NetworkError(e.to_string()))
+}
+
+#[cfg(feature = "mock")]
+async fn get_text(url: &str) -> Result<String, MyError> {
+    super::mock::rest_response(url)
+}
+
+/// Fetches and deserializes a JSON document from a REST endpoint.
+pub async fn get_json<T: DeserializeOwned>(url: &str) -> Result<T, MyError> {
+    let text = get_text(url).await?;
+    serde_json::from_str(&text).map_err(|e| MyError::ParseError(e.to_string()))
+}
+
+/// Posts `body` exactly once, since a broadcast must not be repeated, and
+/// returns the response text.
+#[cfg(not(feature = "mock"))]
+pub async fn post_text(url: &str, body: String) -> Result<String, MyError> {
+    let response = client().post(url).body(body).send().await?;
+    check_status(response)?
+        .text()
+        .await
+        .map_err(|e| MyError::NetworkError(e.to_string()))
+}
+
+#[cfg(feature = "mock")]
+pub async fn post_text(url: &str, _body: String) -> Result<String, MyError> {
+    super::mock::rest_response(url)
+}
+
+#[cfg(test)]
+mod client_tests {
+    use super::*;
+
+    #[test]
+    fn test_backoff_delay_doubles() {
+        assert_eq!(
+            backoff_delay(0),
+            std::time::Duration::from_millis(QUERY_RETRY_BASE_DELAY)
+        );
+        assert_eq!(backoff_delay(1), backoff_delay(0) * 2);
+        assert_eq!(backoff_delay(2), backoff_delay(0) * 4);
+    }
+}
@@ -1,66 +1,277 @@
 use super::models::*;
 use crate::{
-    common::{constants::*, functions::*},
+    common::{
+        address_book::*, config::*, constants::*, datetime::*, functions::*, i18n::*, live::*,
+        network::*, pagination::*, preferences::*, table::Validator,
+    },
     icons::*,
 };
+use codee::string::{FromToStringCodec, JsonSerdeCodec};
 use heck::ToKebabCase;
 use leptos::{html::Div, *};
-use leptos_meta::Script;
+use leptos_meta::{Html, Script};
 use leptos_router::{create_query_signal, *};
-use leptos_use::{DebounceOptions, use_debounce_fn_with_options};
-use std::collections::HashMap;
+use leptos_use::{
+    DebounceOptions, UseEventSourceReturn, UseIntervalReturn, UseWebSocketReturn,
+    core::ConnectionReadyState,
+    storage::{UseStorageOptions, use_local_storage, use_local_storage_with_options},
+    use_debounce_fn_with_options, use_element_visibility, use_event_source, use_interval,
+    use_websocket,
+};
+use std::{collections::HashMap, future::Future};
 use web_sys::{Event, MouseEvent, window};
 
+/// "Load Next" for tables of `Paginated` rows. `fetch` loads the rows from a
+/// cursor on, with a row limit; its pages are appended to `data_sig`. With
+/// auto-load on, the next page is fetched whenever the button scrolls into
+/// view.
 #[component]
-pub fn NextBlockPage<T: HasBlockHeight>(
-    data: Vec<Option<T>>,
-    row_limit: Option<u64>,
-) -> impl IntoView {
-    let (_, set_height) = create_query_signal_with_options::<i64>(
-        QUERY_PARAM_HEIGHT,
-        NavigateOptions {
-            scroll: false,
-            ..Default::default()
-        },
-    );
-    let (row_limit_sig, set_row_limit) = create_query_signal_with_options::<u64>(
-        QUERY_PARAM_ROW_LIMIT,
-        NavigateOptions {
-            scroll: false,
-            ..Default::default()
-        },
-    );
-    let mut last_block_height = None;
-    let mut first_block_height = None;
-    if let Some(Some(first_row)) = data.first() {
-        first_block_height = first_row.block_height();
-    }
-    if let Some(Some(last_row)) = data.last() {
-        last_block_height = last_row.block_height();
-    }
-    let not_pageable = first_block_height
-        .zip(last_block_height)
-        .map(|(first_height, last_height)| last_height == first_height)
-        .unwrap_or_default();
-    view! {
-        <div class="w-full flex justify-center items-center p-4">
-            <Button
-                style_variant=ButtonStyleVariant::Tertiary
-                text="Load Next"
-                on_click=move |_| {
-                    if not_pageable {
-                        set_row_limit.set(row_limit_sig.get().map(|rl| rl * 2).or(Some(50)))
-                    } else {
-                        set_height.set(last_block_height)
+pub fn NextPage<T, F, Fut>(
+    data_sig: ReadSignal<Option<Vec<Option<T>>>>,
+    set_data_sig: WriteSignal<Option<Vec<Option<T>>>>,
+    #[prop(into)] row_limit: Signal<Option<u64>>,
+    fetch: F,
+) -> impl IntoView
+where
+    T: Paginated + Clone + 'static,
+    F: Fn(Option<T::Cursor>, u64) -> Fut + Copy + 'static,
+    Fut: Future<Output = Result<Vec<Option<T>>, MyError>> + 'static,
+{
+    let (auto_load, set_auto_load, _) =
+        use_local_storage::<bool, FromToStringCodec>(AUTO_LOAD_STORAGE_KEY);
+    let sentinel = create_node_ref::<Div>();
+    let visible = use_element_visibility(sentinel);
+    let loading = create_rw_signal(false);
+    let error = create_rw_signal(None::<MyError>);
+    // Key of the last row once a short page showed there is nothing after it.
+    let exhausted_after = create_rw_signal(None::<String>);
+
+    let limit = move || row_limit.get().unwrap_or(TABLE_ROW_LIMIT);
+    let has_more = move || {
+        let limit = limit();
+        let exhausted_after = exhausted_after.get();
+        data_sig.with(|data| {
+            let rows = data.as_deref().unwrap_or_default();
+            rows.len() as u64 >= limit
+                && exhausted_after.is_none_or(|key| Some(key) != last_key(rows))
+        })
+    };
+    let load_next = move || {
+        if loading.get_untracked() || !untrack(has_more) {
+            return;
+        }
+        let rows = data_sig.get_untracked().unwrap_or_default();
+        let (start_len, start_key) = (rows.len(), last_key(&rows));
+        let limit = untrack(limit);
+        loading.set(true);
+        error.set(None);
+        spawn_local(async move {
+            match load_next_page(rows, limit, fetch).await {
+                // Drop the page if the table was reloaded in the meantime.
+                Ok((rows, more))
+                    if data_sig.with_untracked(|data| {
+                        let current = data.as_deref().unwrap_or_default();
+                        current.len() == start_len && last_key(current) == start_key
+                    }) =>
+                {
+                    if !more {
+                        exhausted_after.set(last_key(&rows));
                     }
+                    set_data_sig.set(Some(rows));
                 }
-                class_str="ml-2"
-                disabled=data.len() as u64 != row_limit.unwrap_or(TABLE_ROW_LIMIT)
-            />
+                Ok(_) => {}
+                Err(err) => error.set(Some(err)),
+            }
+            loading.set(false);
+        });
+    };
+
+    create_effect(move |_| {
+        if auto_load.get()
+            && visible.get()
+            && !loading.get()
+            && error.with(Option::is_none)
+            && has_more()
+        {
+            load_next();
+        }
+    });
+
+    view! {
+        <div node_ref=sentinel class="w-full flex justify-center items-center p-4">
+            {move || {
+                view! {
+                    <Button
+                        style_variant=ButtonStyleVariant::Tertiary
+                        text=t(if loading.get() { "Loading..." } else { "Load Next" })
+                        on_click=move |_| load_next()
+                        class_str="ml-2"
+                        disabled=loading.get() || !has_more()
+                    />
+                }
+            }}
+            <label class="ml-4 flex items-center text-sm text-slate-500">
+                <input
+                    type="checkbox"
+                    id="auto-load"
+                    class="mr-2"
+                    prop:checked=auto_load
+                    on:change=move |ev| set_auto_load.set(event_target_checked(&ev))
+                />
+                {t("Auto-load")}
+            </label>
+            {move || {
+                error
+                    .get()
+                    .map(|err| {
+                        view! { <span class="ml-4 text-sm text-red-500">{err.to_string()}</span> }
+                    })
+            }}
         </div>
     }
 }
 
+fn last_key<T: Paginated>(rows: &[Option<T>]) -> Option<String> {
+    rows.iter().rev().flatten().next().and_then(|row| row.key())
+}
+
+#[component]
+pub fn AppConfigProvider(children: ChildrenFn) -> impl IntoView {
+    let resource = create_blocking_resource(|| (), |_| async move { load_app_config().await });
+    let (stored_network, set_stored_network, _) =
+        use_local_storage::<String, FromToStringCodec>(NETWORK_STORAGE_KEY);
+    // The server has no local storage, but is sent the pick in a cookie
+    let saved_network = move || {
+        if cfg!(feature = "ssr") {
+            use_context::<RequestPreferences>().and_then(|prefs| prefs.network)
+        } else {
+            Some(stored_network.get_untracked()).filter(|id| !id.is_empty())
+        }
+    };
+    let location = use_location();
+    let children = store_value(children);
+
+    view! {
+        <Suspense>
+            {move || {
+                resource
+                    .get()
+                    .map(|config| {
+                        let requested = network_from_search(&location.search.get_untracked())
+                            .or_else(saved_network);
+                        let network = config.select_network(requested.as_deref());
+                        set_stored_network.set(network.id.clone());
+                        if !cfg!(feature = "ssr") {
+                            set_preference_cookie(NETWORK_STORAGE_KEY, &network.id);
+                        }
+                        provide_context(store_value(config));
+                        provide_context(store_value(network));
+                        children.with_value(|children| children())
+                    })
+            }}
+        </Suspense>
+    }
+}
+
+/// Provides the `Locale` picked in the header, or the browser's when none was
+/// picked. Unsupported languages fall back to English.
+#[component]
+pub fn LocaleProvider(children: Children) -> impl IntoView {
+    let picked = if cfg!(feature = "ssr") {
+        use_context::<RequestPreferences>().and_then(|prefs| prefs.locale)
+    } else {
+        let (stored_locale, _, _) =
+            use_local_storage::<String, FromToStringCodec>(LOCALE_STORAGE_KEY);
+        // Also mirrors picks saved before the server read them from a cookie
+        match_locale(&stored_locale.get_untracked())
+            .inspect(|locale| set_preference_cookie(LOCALE_STORAGE_KEY, locale))
+            .or_else(|| match_locale(&get_browser_locale()))
+    };
+    let locale = picked.unwrap_or(DEFAULT_LOCALE);
+    provide_context(Locale(locale));
+
+    view! {
+        <Html lang=locale />
+        {children()}
+    }
+}
+
+/// Provides the `TimeSettingsContext`, kept in local storage. Pages the server
+/// rendered are hydrated with the defaults it used, and then updated.
+#[component]
+pub fn TimeSettingsProvider(children: Children) -> impl IntoView {
+    let (settings, set_settings, _) = use_local_storage_with_options::<TimeSettings, JsonSerdeCodec>(
+        TIME_SETTINGS_STORAGE_KEY,
+        UseStorageOptions::default().delay_during_hydration(true),
+    );
+    provide_context(TimeSettingsContext {
+        settings,
+        set_settings,
+    });
+    children()
+}
+
+/// Provides the `AddressBookContext`, kept in local storage. Hydrated like
+/// `TimeSettingsProvider`.
+#[component]
+pub fn AddressBookProvider(children: Children) -> impl IntoView {
+    let (book, set_book, _) = use_local_storage_with_options::<AddressBook, JsonSerdeCodec>(
+        ADDRESS_BOOK_STORAGE_KEY,
+        UseStorageOptions::default().delay_during_hydration(true),
+    );
+    provide_context(AddressBookContext { book, set_book });
+    children()
+}
+
+/// Provides `LiveUpdates` for the selected network. New blocks are pushed
+/// over the network's `live_endpoint` when it has one; otherwise, or while
+/// the stream is down, the summary is polled every `LIVE_POLL_INTERVAL`.
+#[component]
+pub fn LiveUpdatesProvider(children: Children) -> impl IntoView {
+    let source =
+        use_network().with_value(|n| LiveSource::from_endpoint(n.live_endpoint.as_deref()));
+    let (message, ready_state) = match source {
+        LiveSource::WebSocket(url) => {
+            let UseWebSocketReturn {
+                message,
+                ready_state,
+                ..
+            } = use_websocket::<NewBlockEvent, NewBlockEvent, JsonSerdeCodec>(&url);
+            (message, ready_state)
+        }
+        LiveSource::EventSource(url) => {
+            let UseEventSourceReturn {
+                data, ready_state, ..
+            } = use_event_source::<NewBlockEvent, JsonSerdeCodec>(&url);
+            (data, ready_state)
+        }
+        LiveSource::Polling => (
+            Signal::derive(|| None),
+            Signal::derive(|| ConnectionReadyState::Closed),
+        ),
+    };
+    let visible = use_page_visible();
+    let UseIntervalReturn { counter, .. } = use_interval(LIVE_POLL_INTERVAL);
+    let poll = create_memo(move |previous: Option<&u64>| {
+        let tick = counter.get();
+        let streaming = ready_state.get() == ConnectionReadyState::Open;
+        match previous {
+            Some(previous) if streaming || !visible.get() => *previous,
+            _ => tick,
+        }
+    });
+
+    let live = LiveUpdates::new(poll.into());
+    create_effect(move |_| {
+        if let Some(event) = message.get() {
+            live.announce(event);
+        }
+    });
+    provide_context(live);
+
+    children()
+}
+
 #[component]
 pub fn RowLimit() -> impl IntoView {
     view! {
@@ -191,7 +402,7 @@ pub fn SummaryItem(
                 class="row-start-2 text-sm text-slate-500 font-semibold flex justify-start items-start"
                 for=id.clone()
             >
-                {label}
+                {t(&label)}
             </label>
         </div>
     }
@@ -211,7 +422,7 @@ pub fn AppSection(children: Children) -> impl IntoView {
 pub fn AppHeading(#[prop(into)] heading: String) -> impl IntoView {
     view! {
         <h1 class="md:rounded-lg h-16 pl-8 text-xl bg-table-section flex justify-start items-center">
-            {heading}
+            {t(&heading)}
         </h1>
     }
 }
@@ -247,7 +458,7 @@ pub fn UrlParamSelectMenu(
     view! {
         {label
             .map(|l| {
-                view! { <label class="text-xs font-medium text-black">{l}</label> }.into_view()
+                view! { <label class="text-xs font-medium text-black">{t(&l)}</label> }.into_view()
             })
             .unwrap_or_else(|| ().into_view())}
         <select
@@ -310,19 +521,75 @@ pub fn UrlParamSelectMenu(
 }
 
 #[component]
-pub fn ErrorView<E: std::fmt::Debug>(err: E) -> impl IntoView {
-    view! { <div class="error">{format!("Error: {:#?}", err)}</div> }
+pub fn ErrorView(
+    err: MyError,
+    #[prop(optional_no_strip)] on_retry: Option<Callback<()>>,
+) -> impl IntoView {
+    let kind = err.kind();
+    let (title, hint) = match kind {
+        ErrorKind::NotFound => ("Not Found", "Nothing matches what you are looking for."),
+        ErrorKind::Unavailable => (
+            "Indexer Unavailable",
+            "The indexer could not be reached. Please try again shortly.",
+        ),
+        ErrorKind::BadInput => ("Bad Input", "Check the values you entered and try again."),
+        ErrorKind::Unexpected => (
+            "Something Went Wrong",
+            "The indexer returned an unexpected response.",
+        ),
+    };
+    let paths = err.graphql_paths();
+    let can_retry = matches!(kind, ErrorKind::Unavailable | ErrorKind::Unexpected);
+
+    view! {
+        <div class="error w-full flex flex-col items-center justify-center p-8 text-center">
+            <span class="text-lg font-bold text-slate-500">{title}</span>
+            <span class="text-sm text-slate-400">{hint}</span>
+            <span class="text-xs font-mono text-slate-400 mt-2 break-all">
+                {err.to_string()}
+                {(!paths.is_empty()).then(|| format!(" (at {})", paths.join(", ")))}
+            </span>
+            {on_retry
+                .filter(|_| can_retry)
+                .map(|on_retry| {
+                    view! {
+                        <Button
+                            class_str="mt-4"
+                            text="Retry"
+                            on_click=move |_| on_retry.call(())
+                        />
+                    }
+                })}
+        </div>
+    }
 }
 
+/// Renders `children` only while the route param `param` passes `validate`.
+/// Otherwise the problem with it is shown in place of the page.
 #[component]
-pub fn PageContainer(children: Children) -> impl IntoView {
-    let el = create_node_ref::<Div>();
+pub fn ValidRouteParam(
+    #[prop(default = "id")] param: &'static str,
+    validate: Validator,
+    children: ChildrenFn,
+) -> impl IntoView {
+    let params = use_params_map();
+    let validity =
+        create_memo(move |_| params.with(|p| validate(p.get(param).map_or("", |v| v.as_str()))));
+    move || match validity.get() {
+        Ok(()) => children().into_view(),
+        Err(err) => view! {
+            <PageContainer>
+                <ErrorView err=MyError::InvalidInput(err) />
+            </PageContainer>
+        }
+        .into_view(),
+    }
+}
 
+#[component]
+pub fn PageContainer(children: Children) -> impl IntoView {
     view! {
-        <div
-            node_ref=el
-            class="grid grid-cols-1 auto-rows-min bg-secondary-background p-2 sm:px-0 grow min-h-[85vh]"
-        >
+        <div class="grid grid-cols-1 auto-rows-min bg-secondary-background p-2 sm:px-0 grow min-h-[85vh]">
             {children()}
         </div>
     }
@@ -346,6 +613,8 @@ pub fn NavEntryIcon(nav_entry: NavEntry) -> impl IntoView {
             NavIcon::More => view! { <MoreIcon /> },
             NavIcon::Delegates => view! { <DelegateIcon /> },
             NavIcon::Leaderboard => view! { <LeaderboardIcon /> },
+            NavIcon::Watchlist => view! { <StarIcon /> },
+            NavIcon::AddressBook => view! { <UserIcon /> },
         }}
     }
 }
@@ -391,7 +660,7 @@ where
     view! {
         <a on:click=on_click class=move || link_class.get() href=href>
             <NavEntryIcon nav_entry />
-            <span class="ml-0.5">{text}</span>
+            <span class="ml-0.5">{t(&text)}</span>
         </a>
     }
 }
@@ -423,7 +692,7 @@ pub fn TabLink(nav_entry: NavEntry) -> impl IntoView {
             href=href
         >
             <NavEntryIcon nav_entry />
-            <div class="ml-0.5">{text}</div>
+            <div class="ml-0.5">{t(&text)}</div>
             {match number_bubble {
                 Some(number) => {
                     view! {
@@ -0,0 +1,283 @@
+use super::{
+    constants::*,
+    models::MyError,
+    network::{DEVNET_NETWORK_ID, MAINNET_NETWORK_ID, Network},
+};
+use leptos::*;
+use serde::{Deserialize, Serialize};
+
+const CONFIG_PATH: &str = "config.json";
+
+/// Loaded at startup from `/config.json` so a single build can be pointed at
+/// any indexer. Any key missing from the file falls back to the values baked in
+/// at compile time (see `build.rs`). The top level endpoints are mainnet's;
+/// `networks` lists any others the user may switch to, and may redefine the
+/// built-in mainnet and devnet.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[serde(default)]
+pub struct AppConfig {
+    pub graphql_endpoint: String,
+    pub rest_endpoint: String,
+    pub live_endpoint: Option<String>,
+    pub networks: Vec<Network>,
+}
+
+impl Default for AppConfig {
+    fn default() -> Self {
+        AppConfig {
+            graphql_endpoint: GRAPHQL_ENDPOINT.to_string(),
+            rest_endpoint: REST_ENDPOINT.to_string(),
+            live_endpoint: None,
+            networks: vec![],
+        }
+    }
+}
+
+impl AppConfig {
+    /// Mainnet first, then the configured networks and devnet last. Either
+    /// built-in is left out if the config file redefines it.
+    pub fn networks(&self) -> Vec<Network> {
+        let mut networks = self.networks.clone();
+        let is_configured = |id: &str| self.networks.iter().any(|n| n.id == id);
+        if !is_configured(MAINNET_NETWORK_ID) {
+            networks.insert(
+                0,
+                Network {
+                    live_endpoint: self.live_endpoint.clone(),
+                    ..Network::mainnet(&self.graphql_endpoint, &self.rest_endpoint)
+                },
+            );
+        }
+        if !is_configured(DEVNET_NETWORK_ID) {
+            networks.push(Network::devnet(
+                DEVNET_GRAPHQL_ENDPOINT,
+                DEVNET_REST_ENDPOINT,
+            ));
+        }
+        networks
+    }
+
+    pub fn select_network(&self, id: Option<&str>) -> Network {
+        let networks = self.networks();
+        id.and_then(|id| networks.iter().find(|n| n.id == id))
+            .unwrap_or(&networks[0])
+            .clone()
+    }
+}
+
+/// The server reads the same file from the directory it serves.
+#[cfg(feature = "ssr")]
+async fn fetch_app_config() -> Result<AppConfig, MyError> {
+    let path = crate::server::site_root().join(CONFIG_PATH);
+    let json = tokio::fs::read_to_string(&path)
+        .await
+        .map_err(|e| MyError::NetworkError(e.to_string()))?;
+    serde_json::from_str(&json).map_err(|e| MyError::ParseError(e.to_string()))
+}
+
+/// The URL of `config.json` in the directory of `base_uri`, the base URL of
+/// the document. Trunk sets it to the site's public URL, so that the file is
+/// found next to `index.html` when the site is served under a sub-path.
+#[cfg(any(test, not(feature = "ssr")))]
+fn config_url(base_uri: &str) -> String {
+    let base = base_uri.split(['?', '#']).next().unwrap_or_default();
+    let dir = base.rfind('/').map_or(base, |end| &base[..=end]);
+    format!("{}{}", dir, CONFIG_PATH)
+}
+
+#[cfg(not(feature = "ssr"))]
+async fn fetch_app_config() -> Result<AppConfig, MyError> {
+    let base_uri = document()
+        .base_uri()
+        .ok()
+        .flatten()
+        .ok_or_else(|| MyError::UrlParseError("Unable to determine base URL".to_string()))?;
+    super::client::get_json(&config_url(&base_uri)).await
+}
+
+/// The endpoints the chart scripts read, as a script defining `config`.
+/// `</` is escaped so that no endpoint can end the script element.
+pub fn config_script(network: &Network) -> String {
+    let config = serde_json::json!({
+        "graphql_endpoint": network.graphql_endpoint,
+        "rest_endpoint": network.rest_endpoint,
+    });
+    format!(
+        "const config = {};",
+        config.to_string().replace("</", "<\\/")
+    )
+}
+
+pub async fn load_app_config() -> AppConfig {
+    match fetch_app_config().await {
+        Ok(config) => config,
+        Err(err) => {
+            logging::log!(
+                "Using built-in endpoints, {} not loaded: {:?}",
+                CONFIG_PATH,
+                err
+            );
+            AppConfig::default()
+        }
+    }
+}
+
+pub fn use_app_config() -> StoredValue<AppConfig> {
+    use_context::<StoredValue<AppConfig>>().expect("Expected there to be an app config provided")
+}
+
+#[cfg(test)]
+mod app_config_tests {
+    use super::*;
+
+    #[test]
+    fn test_full_config() {
+        let config: AppConfig = serde_json::from_str(
+            r#"{"graphql_endpoint": "https://devnet.example.com/graphql", "rest_endpoint": "https://devnet.example.com"}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            config,
+            AppConfig {
+                graphql_endpoint: "https://devnet.example.com/graphql".to_string(),
+                rest_endpoint: "https://devnet.example.com".to_string(),
+                live_endpoint: None,
+                networks: vec![],
+            }
+        );
+    }
+
+    #[test]
+    fn test_partial_config_falls_back() {
+        let config: AppConfig =
+            serde_json::from_str(r#"{"rest_endpoint": "https://devnet.example.com"}"#).unwrap();
+        assert_eq!(config.graphql_endpoint, GRAPHQL_ENDPOINT);
+        assert_eq!(config.rest_endpoint, "https://devnet.example.com");
+    }
+
+    #[test]
+    fn test_config_url() {
+        assert_eq!(
+            config_url("https://minasearch.com/"),
+            "https://minasearch.com/config.json"
+        );
+        assert_eq!(
+            config_url("https://example.com/explorer/"),
+            "https://example.com/explorer/config.json"
+        );
+        assert_eq!(
+            config_url("https://example.com/explorer/index.html?network=devnet#top"),
+            "https://example.com/explorer/config.json"
+        );
+    }
+
+    #[test]
+    fn test_config_script_escapes_endpoints() {
+        let network = Network::mainnet(
+            r#"https://example.com/"</script><script>alert(1)</script>"#,
+            "https://example.com",
+        );
+        assert_eq!(
+            config_script(&network),
+            r#"const config = {"graphql_endpoint":"https://example.com/\"<\/script><script>alert(1)<\/script>","rest_endpoint":"https://example.com"};"#
+        );
+    }
+
+    #[test]
+    fn test_empty_config_falls_back() {
+        let config: AppConfig = serde_json::from_str("{}").unwrap();
+        assert_eq!(config, AppConfig::default());
+    }
+
+    fn devnet() -> Network {
+        Network {
+            id: "devnet".to_string(),
+            name: "Devnet".to_string(),
+            graphql_endpoint: "https://devnet.example.com/graphql".to_string(),
+            rest_endpoint: "https://devnet.example.com".to_string(),
+            chain_id: "abc123".to_string(),
+            genesis_state_hash: "3NKdevnet".to_string(),
+            previous_chain: None,
+            live_endpoint: None,
+            ..Network::devnet(DEVNET_GRAPHQL_ENDPOINT, DEVNET_REST_ENDPOINT)
+        }
+    }
+
+    #[test]
+    fn test_networks_start_with_mainnet() {
+        let config = AppConfig {
+            networks: vec![devnet()],
+            ..Default::default()
+        };
+        let ids = config
+            .networks()
+            .into_iter()
+            .map(|n| n.id)
+            .collect::<Vec<_>>();
+        assert_eq!(ids, vec![MAINNET_NETWORK_ID, "devnet"]);
+    }
+
+    #[test]
+    fn test_configured_mainnet_replaces_builtin() {
+        let mainnet = Network {
+            id: MAINNET_NETWORK_ID.to_string(),
+            ..devnet()
+        };
+        let config = AppConfig {
+            networks: vec![mainnet.clone()],
+            ..Default::default()
+        };
+        assert_eq!(config.networks()[0], mainnet);
+        assert_eq!(config.networks().len(), 2);
+    }
+
+    #[test]
+    fn test_builtin_devnet() {
+        let config = AppConfig::default();
+        let ids = config
+            .networks()
+            .into_iter()
+            .map(|n| n.id)
+            .collect::<Vec<_>>();
+        assert_eq!(ids, vec![MAINNET_NETWORK_ID, DEVNET_NETWORK_ID]);
+        assert_eq!(
+            config
+                .select_network(Some(DEVNET_NETWORK_ID))
+                .graphql_endpoint,
+            DEVNET_GRAPHQL_ENDPOINT
+        );
+    }
+
+    #[test]
+    fn test_live_endpoint_applies_to_mainnet() {
+        let config: AppConfig =
+            serde_json::from_str(r#"{"live_endpoint": "wss://api.example.com/live"}"#).unwrap();
+        assert_eq!(
+            config.select_network(None).live_endpoint.as_deref(),
+            Some("wss://api.example.com/live")
+        );
+    }
+
+    #[test]
+    fn test_select_network() {
+        let config = AppConfig {
+            networks: vec![devnet()],
+            ..Default::default()
+        };
+        assert_eq!(config.select_network(Some("devnet")), devnet());
+        assert_eq!(
+            config.select_network(Some("unknown")).id,
+            MAINNET_NETWORK_ID
+        );
+        assert_eq!(config.select_network(None).id, MAINNET_NETWORK_ID);
+    }
+
+    #[test]
+    fn test_network_from_json() {
+        let config: AppConfig = serde_json::from_str(
+            r#"{"networks": [{"id": "devnet", "name": "Devnet", "graphql_endpoint": "https://devnet.example.com/graphql", "rest_endpoint": "https://devnet.example.com", "chain_id": "abc123", "genesis_state_hash": "3NKdevnet"}]}"#,
+        )
+        .unwrap();
+        assert_eq!(config.networks, vec![devnet()]);
+    }
+}
@@ -1,11 +1,19 @@
 pub const GRAPHQL_ENDPOINT: &str = env!("GRAPHQL_URL");
 pub const REST_ENDPOINT: &str = env!("REST_URL");
+pub const DEVNET_GRAPHQL_ENDPOINT: &str = env!("DEVNET_GRAPHQL_URL");
+pub const DEVNET_REST_ENDPOINT: &str = env!("DEVNET_REST_URL");
 pub const MINA_EXPLORER_ENDPOINT: &str = "https://api.minaexplorer.com";
-pub const EPOCH_SLOTS: u16 = 7140;
 pub const DEFAULT_USER_INPUT_DEBOUNCE_INTERNVAL: f64 = 500.0;
 pub const GLOBAL_SEARCH_PLACEHOLDER_TEXT: &str = "Paste -> Enter -> Explore!";
-pub const LIVE_RELOAD_INTERVAL: u64 = 6000000;
+pub const LIVE_POLL_INTERVAL: u64 = 60000;
+pub const QUERY_CACHE_TTL: u64 = 60000;
+pub const QUERY_CACHE_CAPACITY: usize = 200;
+#[cfg(not(feature = "mock"))]
+pub const QUERY_RETRY_ATTEMPTS: u32 = 3;
+#[cfg(any(test, not(feature = "mock")))]
+pub const QUERY_RETRY_BASE_DELAY: u64 = 500;
 pub const TABLE_ROW_LIMIT: u64 = 25;
+pub const SEARCH_RESULT_LIMIT: i64 = 5;
 pub const COMMIT_HASH: &str = env!("COMMIT_HASH");
 pub const LINK_HOVER_STATE: &str = "hover:text-granola-orange hover:underline hover:decoration-2 ";
 pub const TABLE_COL_NUMERIC_WIDTH: &str = "150px";
@@ -16,6 +24,18 @@ pub const TABLE_COL_USERNAME_WIDTH: &str = "200rem";
 pub const TABLE_COL_LARGE_BALANCE: &str = "250px";
 pub const BLOCKCHAIN_SUMMARY_STORAGE_KEY: &str = "blockchain-summary";
 pub const BLOCKS_STORAGE_KEY: &str = "blocks";
+pub const AUTO_LOAD_STORAGE_KEY: &str = "auto-load";
+pub const ADDRESS_BOOK_STORAGE_KEY: &str = "address-book";
+pub const LOCALE_STORAGE_KEY: &str = "locale";
+pub const SITE_NAME: &str = "Minasearch";
+pub const TIME_SETTINGS_STORAGE_KEY: &str = "time-settings";
+pub const WATCHLIST_STORAGE_KEY: &str = "watchlist";
+pub const NOTIFICATION_RULES_STORAGE_KEY: &str = "notification-rules";
+pub const NOTIFICATIONS_ENABLED_STORAGE_KEY: &str = "notifications-enabled";
+pub const RECENT_ITEMS_STORAGE_KEY: &str = "recent-items";
+pub const TABLE_LAYOUT_STORAGE_KEY: &str = "table-layout";
+pub const TABLE_PRESETS_STORAGE_KEY: &str = "table-presets";
+pub const RECENT_SEARCHES_STORAGE_KEY: &str = "recent-searches";
 pub const STAKE_DELEGATION_TYPE: &str = "Stake Delegation";
 pub const LHS_MAX_DIGIT_PADDING: usize = 3;
 pub const LHS_MAX_SPACE_FEES: usize = 2;
@@ -28,16 +48,17 @@ pub const STATUS_SEARCH_OPTION_FAILED: &str = "Failed";
 pub const STATUS_SEARCH_OPTION_ALL: &str = "";
 pub const DIRECTION_IN: &str = "In";
 pub const DIRECTION_OUT: &str = "Out";
-pub const MINA_TOKEN_ADDRESS: &str = "wSHV2S4qX9jFsLjQo8r1BsMLH2ZRKsZx6EJd1sbozGPieEC4Jf";
-pub const MAINNET_STATE_HASH: &str = "3NKeMoncuHab5ScarV5ViyF16cJPT4taWNSaTLS64Dp67wuXigPZ";
-pub const HARDFORK_STATE_HASH: &str = "3NK4BpDSekaqsG6tx8Nse2zJchRft2JpnbvMiog55WCr5xJZaKeP";
-pub const MAINNET_2_CHAIN_ID: &str = "a7351a";
-pub const MAINNET_1_CHAIN_ID: &str = "5f704c";
-pub const LAST_EPOCH_OF_MAINNET_CHAIN: u64 = 80;
 
 // Query Params
 pub const QUERY_PARAM_BLOCKHEIGHT_GTE: &str = "q-blockheight-gte";
 pub const QUERY_PARAM_BLOCKHEIGHT_LTE: &str = "q-blockheight-lte";
+pub const QUERY_PARAM_DATE_GTE: &str = "q-date-gte";
+pub const QUERY_PARAM_DATE_LTE: &str = "q-date-lte";
+pub const QUERY_PARAM_AMOUNT_GTE: &str = "q-amount-gte";
+pub const QUERY_PARAM_AMOUNT_LTE: &str = "q-amount-lte";
+pub const QUERY_PARAM_FEE_GTE: &str = "q-fee-gte";
+pub const QUERY_PARAM_FEE_LTE: &str = "q-fee-lte";
+pub const QUERY_PARAM_FILTER: &str = "filter";
 pub const QUERY_PARAM_RECEIPIENT: &str = "q-recipient";
 pub const QUERY_PARAM_HEIGHT: &str = "q-height";
 pub const QUERY_PARAM_STATE_HASH: &str = "q-state-hash";
@@ -58,7 +79,10 @@ pub const QUERY_PARAM_ID: &str = "q-id";
 pub const QUERY_PARAM_NONCE: &str = "q-nonce";
 pub const QUERY_PARAM_DIRECTION: &str = "q-direction";
 pub const QUERY_PARAM_TXN_HASH: &str = "q-txn-hash";
+pub const QUERY_PARAM_MEMO: &str = "q-memo";
 pub const QUERY_PARAM_COUNTERPARTY: &str = "q-counterparty";
 pub const QUERY_PARAM_ACCOUNT: &str = "q-account";
 pub const QUERY_PARAM_PROVER: &str = "q-prover";
 pub const QUERY_PARAM_TXN_TYPE: &str = "txn-type";
+pub const QUERY_PARAM_NETWORK: &str = "network";
+pub const QUERY_PARAM_PRESET: &str = "preset";
@@ -0,0 +1,220 @@
+#[cfg(not(feature = "ssr"))]
+use super::functions::wall_time;
+use super::functions::{format_date_time, print_time_since};
+#[cfg(not(feature = "ssr"))]
+use chrono::Timelike;
+use chrono::{DateTime, Duration, NaiveDate, NaiveDateTime, NaiveTime, Utc};
+use leptos::*;
+use serde::{Deserialize, Serialize};
+
+/// The zone absolute times are shown in.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+pub enum TimeZoneSetting {
+    #[default]
+    Local,
+    Utc,
+    /// An IANA zone name such as `Europe/Berlin`.
+    Custom(String),
+}
+
+impl TimeZoneSetting {
+    /// The IANA zone to format in, or `None` for the browser's own.
+    pub fn iana(&self) -> Option<&str> {
+        match self {
+            TimeZoneSetting::Local => None,
+            TimeZoneSetting::Utc => Some("UTC"),
+            TimeZoneSetting::Custom(zone) => Some(zone),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Default, PartialEq)]
+pub enum TimeDisplay {
+    #[default]
+    Absolute,
+    Relative,
+}
+
+/// How dates are shown across the explorer. Persisted in local storage.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[serde(default)]
+pub struct TimeSettings {
+    pub time_zone: TimeZoneSetting,
+    pub display: TimeDisplay,
+}
+
+impl TimeSettings {
+    /// Falls back to the browser's zone if the chosen one is unknown, and to
+    /// the raw timestamp if it cannot be parsed at all.
+    pub fn format_absolute(&self, utc_date_str: &str) -> String {
+        format_date_time(utc_date_str, self.time_zone.iana())
+            .or_else(|| format_date_time(utc_date_str, None))
+            .unwrap_or_else(|| utc_date_str.to_string())
+    }
+
+    pub fn format(&self, utc_date_str: &str) -> String {
+        match self.display {
+            TimeDisplay::Absolute => self.format_absolute(utc_date_str),
+            TimeDisplay::Relative => print_time_since(utc_date_str),
+        }
+    }
+
+    /// Whichever form `format` does not show.
+    pub fn format_alternate(&self, utc_date_str: &str) -> String {
+        match self.display {
+            TimeDisplay::Absolute => print_time_since(utc_date_str),
+            TimeDisplay::Relative => self.format_absolute(utc_date_str),
+        }
+    }
+}
+
+/// Provided by `TimeSettingsProvider`.
+#[derive(Clone, Copy)]
+pub struct TimeSettingsContext {
+    pub settings: Signal<TimeSettings>,
+    pub set_settings: WriteSignal<TimeSettings>,
+}
+
+pub fn use_time_settings() -> TimeSettingsContext {
+    use_context::<TimeSettingsContext>().expect("Expected there to be time settings provided")
+}
+
+fn parse_date(date: &str) -> Result<NaiveDate, String> {
+    NaiveDate::parse_from_str(date.trim(), "%Y-%m-%d")
+        .map_err(|_| format!("Invalid date: {}", date))
+}
+
+/// Reads a `YYYY-MM-DD` date filter as the start of that day in `time_zone`,
+/// or as its last millisecond for an upper bound.
+pub fn parse_date_bound(
+    date: &str,
+    end_of_day: bool,
+    time_zone: &TimeZoneSetting,
+) -> Result<DateTime<Utc>, String> {
+    let time = if end_of_day {
+        NaiveTime::from_hms_milli_opt(23, 59, 59, 999)
+    } else {
+        NaiveTime::from_hms_opt(0, 0, 0)
+    };
+    let local = parse_date(date)?.and_time(time.unwrap_or_default());
+    Ok(local_to_utc(local, |utc| {
+        zone_offset(utc, time_zone).unwrap_or_default()
+    }))
+}
+
+/// The instant at which a zone with the given UTC `offset` shows `local`.
+/// The offset is looked up again at the first estimate, as it may differ
+/// there when a daylight saving change falls in between.
+fn local_to_utc(local: NaiveDateTime, offset: impl Fn(DateTime<Utc>) -> Duration) -> DateTime<Utc> {
+    let guess = local.and_utc();
+    guess - offset(guess - offset(guess))
+}
+
+/// How far clocks in `time_zone` are ahead of UTC at `utc`. Unknown zones fall
+/// back to the browser's, like `TimeSettings::format_absolute`.
+#[cfg(not(feature = "ssr"))]
+fn zone_offset(utc: DateTime<Utc>, time_zone: &TimeZoneSetting) -> Option<Duration> {
+    if *time_zone == TimeZoneSetting::Utc {
+        return Some(Duration::zero());
+    }
+    let utc = utc.with_nanosecond(0)?;
+    wall_time(utc, time_zone.iana())
+        .or_else(|| wall_time(utc, None))
+        .map(|wall| wall - utc.naive_utc())
+}
+
+/// The server knows neither the browser's zone nor the IANA database, so it
+/// reads dates as UTC.
+#[cfg(feature = "ssr")]
+fn zone_offset(_utc: DateTime<Utc>, _time_zone: &TimeZoneSetting) -> Option<Duration> {
+    None
+}
+
+pub fn validate_date(date: &str) -> Result<(), String> {
+    parse_date(date).map(|_| ())
+}
+
+#[cfg(test)]
+mod datetime_tests {
+    use super::*;
+
+    #[test]
+    fn test_time_zone_iana() {
+        assert_eq!(TimeZoneSetting::Local.iana(), None);
+        assert_eq!(TimeZoneSetting::Utc.iana(), Some("UTC"));
+        assert_eq!(
+            TimeZoneSetting::Custom("Asia/Tokyo".to_string()).iana(),
+            Some("Asia/Tokyo")
+        );
+    }
+
+    #[test]
+    fn test_settings_round_trip() {
+        let settings = TimeSettings {
+            time_zone: TimeZoneSetting::Custom("Europe/Berlin".to_string()),
+            display: TimeDisplay::Relative,
+        };
+        let json = serde_json::to_string(&settings).unwrap();
+        assert_eq!(
+            serde_json::from_str::<TimeSettings>(&json).unwrap(),
+            settings
+        );
+    }
+
+    #[test]
+    fn test_settings_default_missing_fields() {
+        let settings: TimeSettings = serde_json::from_str(r#"{"display": "Relative"}"#).unwrap();
+        assert_eq!(settings.time_zone, TimeZoneSetting::Local);
+        assert_eq!(settings.display, TimeDisplay::Relative);
+    }
+
+    #[test]
+    fn test_parse_date_bound() {
+        let utc = TimeZoneSetting::Utc;
+        assert_eq!(
+            parse_date_bound("2024-03-01", false, &utc)
+                .unwrap()
+                .to_rfc3339(),
+            "2024-03-01T00:00:00+00:00"
+        );
+        assert_eq!(
+            parse_date_bound(" 2024-03-01 ", true, &utc)
+                .unwrap()
+                .to_rfc3339_opts(chrono::SecondsFormat::Millis, true),
+            "2024-03-01T23:59:59.999Z"
+        );
+        assert!(parse_date_bound("2024-02-30", false, &utc).is_err());
+        assert!(validate_date("03/01/2024").is_err());
+    }
+
+    #[test]
+    fn test_local_to_utc() {
+        let local = |date: &str| {
+            NaiveDate::parse_from_str(date, "%Y-%m-%d")
+                .unwrap()
+                .and_hms_opt(0, 0, 0)
+                .unwrap()
+        };
+        let tokyo = |_| Duration::hours(9);
+        assert_eq!(
+            local_to_utc(local("2024-03-01"), tokyo).to_rfc3339(),
+            "2024-02-29T15:00:00+00:00"
+        );
+        // A zone that moves from UTC-5 to UTC-4 at 2024-03-10T07:00Z
+        let new_york = |utc: DateTime<Utc>| {
+            if utc < "2024-03-10T07:00:00Z".parse::<DateTime<Utc>>().unwrap() {
+                Duration::hours(-5)
+            } else {
+                Duration::hours(-4)
+            }
+        };
+        assert_eq!(
+            local_to_utc(local("2024-03-10"), new_york).to_rfc3339(),
+            "2024-03-10T05:00:00+00:00"
+        );
+        assert_eq!(
+            local_to_utc(local("2024-03-11"), new_york).to_rfc3339(),
+            "2024-03-11T04:00:00+00:00"
+        );
+    }
+}
@@ -1,11 +1,22 @@
 use super::models::*;
-use crate::common::{components::CopyToClipboard, constants::*};
+use crate::common::{
+    address_book::use_address_book,
+    amount::Amount,
+    components::CopyToClipboard,
+    constants::*,
+    datetime::{TimeZoneSetting, parse_date_bound, use_time_settings},
+    i18n::{t, tn},
+    network::use_mina_token_address,
+};
 use chrono::{DateTime, Duration, Utc};
+#[cfg(not(feature = "ssr"))]
+use chrono::{NaiveDate, NaiveDateTime};
 use leptos::*;
-use rust_decimal::prelude::*;
 use serde_json::Value;
 use wasm_bindgen::{JsValue, prelude::*};
-use web_sys::js_sys::{Date, Intl::NumberFormat, Object, Reflect, *};
+use web_sys::js_sys::*;
+#[cfg(not(feature = "ssr"))]
+use web_sys::js_sys::{Date, Intl::NumberFormat, Object, Reflect};
 
 #[wasm_bindgen]
 #[allow(non_snake_case)]
@@ -20,56 +31,122 @@ pub fn get_browser_locale() -> String {
     LANGUAGE.with(String::clone)
 }
 
-#[wasm_bindgen]
-pub fn convert_to_local_timezone_formatted(utc_date_str: &str) -> String {
-    // Create a JS Date object from the UTC date string
+/// Formats a UTC timestamp as local date and time in the IANA `time_zone`, or
+/// in the browser's zone when `None`. Returns `None` for an unknown zone or an
+/// unparseable timestamp.
+#[cfg(not(feature = "ssr"))]
+pub fn format_date_time(utc_date_str: &str, time_zone: Option<&str>) -> Option<String> {
     let date = Date::new(&JsValue::from_str(utc_date_str));
 
-    // Define options for the toLocaleString method to get YYYY-MM-DD HH:mm:ss
-    // format
-    let options = Object::new();
-    Reflect::set(
-        &options,
-        &JsValue::from_str("year"),
-        &JsValue::from_str("numeric"),
-    )
-    .unwrap();
-    Reflect::set(
-        &options,
-        &JsValue::from_str("month"),
-        &JsValue::from_str("2-digit"),
-    )
-    .unwrap();
-    Reflect::set(
-        &options,
-        &JsValue::from_str("day"),
-        &JsValue::from_str("2-digit"),
-    )
-    .unwrap();
-    Reflect::set(
-        &options,
-        &JsValue::from_str("hour"),
-        &JsValue::from_str("2-digit"),
-    )
-    .unwrap();
-    Reflect::set(
-        &options,
-        &JsValue::from_str("minute"),
-        &JsValue::from_str("2-digit"),
-    )
-    .unwrap();
-    Reflect::set(
-        &options,
-        &JsValue::from_str("second"),
-        &JsValue::from_str("2-digit"),
+    // YYYY-MM-DD HH:mm:ss, with the zone spelled out so that times copied
+    // between people in different zones can still be compared.
+    let formatter = date_time_format(
+        &get_browser_locale(),
+        &[
+            ("year", "numeric"),
+            ("month", "2-digit"),
+            ("day", "2-digit"),
+            ("hour", "2-digit"),
+            ("minute", "2-digit"),
+            ("second", "2-digit"),
+            ("timeZoneName", "short"),
+        ],
+        time_zone,
+    )?;
+    formatter
+        .format()
+        .call1(&JsValue::NULL, &date)
+        .ok()?
+        .as_string()
+}
+
+/// The server has neither the browser's zone nor the IANA database, so it
+/// shows UTC until the page is hydrated.
+#[cfg(feature = "ssr")]
+pub fn format_date_time(utc_date_str: &str, _time_zone: Option<&str>) -> Option<String> {
+    DateTime::parse_from_rfc3339(utc_date_str)
+        .ok()
+        .map(|date| date.to_utc().format("%Y-%m-%d %H:%M:%S UTC").to_string())
+}
+
+/// The wall-clock time in the IANA `time_zone`, or in the browser's zone when
+/// `None`, at the instant `utc`. Returns `None` for an unknown zone.
+#[cfg(not(feature = "ssr"))]
+pub fn wall_time(utc: DateTime<Utc>, time_zone: Option<&str>) -> Option<NaiveDateTime> {
+    let formatter = date_time_format(
+        "en-US",
+        &[
+            ("year", "numeric"),
+            ("month", "numeric"),
+            ("day", "numeric"),
+            ("hour", "numeric"),
+            ("minute", "numeric"),
+            ("second", "numeric"),
+            ("hourCycle", "h23"),
+        ],
+        time_zone,
+    )?;
+    let parts = formatter.format_to_parts(&Date::new(&JsValue::from_f64(
+        utc.timestamp_millis() as f64
+    )));
+    let part = |kind: &str| {
+        parts.iter().find_map(|part| {
+            let get = |key| {
+                Reflect::get(&part, &JsValue::from_str(key))
+                    .ok()?
+                    .as_string()
+            };
+            (get("type")? == kind)
+                .then(|| get("value")?.parse::<u32>().ok())
+                .flatten()
+        })
+    };
+    NaiveDate::from_ymd_opt(part("year")? as i32, part("month")?, part("day")?)?.and_hms_opt(
+        part("hour")?,
+        part("minute")?,
+        part("second")?,
     )
-    .unwrap();
+}
 
-    // Use toLocaleString with the options to get the formatted string
-    date.to_locale_string(&get_browser_locale(), &options)
-        .into()
+/// An `Intl.DateTimeFormat` with the option `fields` in the IANA `time_zone`, or
+/// in the browser's zone when `None`. Returns `None` for an unknown zone.
+#[cfg(not(feature = "ssr"))]
+fn date_time_format(
+    locale: &str,
+    fields: &[(&str, &str)],
+    time_zone: Option<&str>,
+) -> Option<Intl::DateTimeFormat> {
+    let options = Object::new();
+    for (key, value) in fields {
+        Reflect::set(&options, &JsValue::from_str(key), &JsValue::from_str(value)).unwrap();
+    }
+    if let Some(zone) = time_zone {
+        Reflect::set(
+            &options,
+            &JsValue::from_str("timeZone"),
+            &JsValue::from_str(zone),
+        )
+        .unwrap();
+    }
+
+    // Constructed through `Reflect` because an unknown zone throws, which
+    // `Intl::DateTimeFormat::new` would not let us catch.
+    let intl = Reflect::get(&global(), &JsValue::from_str("Intl")).ok()?;
+    let constructor: Function = Reflect::get(&intl, &JsValue::from_str("DateTimeFormat"))
+        .ok()?
+        .dyn_into()
+        .ok()?;
+    let locales = Array::of1(&JsValue::from_str(locale));
+    Reflect::construct(&constructor, &Array::of2(&locales, &options))
+        .ok()
+        .map(JsCast::unchecked_into)
+}
+
+pub fn is_valid_time_zone(time_zone: &str) -> bool {
+    format_date_time("1970-01-01T00:00:00Z", Some(time_zone)).is_some()
 }
 
+#[cfg(not(feature = "ssr"))]
 pub fn format_number_helper(number: &str, max_significant_digits: Option<u32>) -> String {
     let locale_array = Array::new();
     locale_array.push(&JsValue::from_str(&get_browser_locale()));
@@ -107,6 +184,53 @@ pub fn format_number_helper(number: &str, max_significant_digits: Option<u32>) -
     }
 }
 
+/// The server has no `Intl`, so it formats numbers the way `en` does.
+#[cfg(feature = "ssr")]
+pub fn format_number_helper(number: &str, max_significant_digits: Option<u32>) -> String {
+    match max_significant_digits.filter(|digits| (1..=21).contains(digits)) {
+        Some(digits) => format_decimal_en(number, 1, digits),
+        None => format_decimal_en(number, 0, 3),
+    }
+}
+
+/// `number` rounded to at most `max_fraction` decimals, padded to at least
+/// `min_fraction`, with its integer digits grouped by commas. Like
+/// `Intl.NumberFormat` in `en`, except that unparseable input is returned as
+/// it is.
+#[cfg(any(test, feature = "ssr"))]
+fn format_decimal_en(number: &str, min_fraction: u32, max_fraction: u32) -> String {
+    let Ok(decimal) = rust_decimal::Decimal::from_str_exact(number.trim()) else {
+        return number.to_string();
+    };
+    let rounded = decimal
+        .round_dp_with_strategy(
+            max_fraction,
+            rust_decimal::RoundingStrategy::MidpointAwayFromZero,
+        )
+        .normalize();
+    let digits = rounded.abs().to_string();
+    let (integer, fraction) = digits.split_once('.').unwrap_or((&digits, ""));
+    let mut grouped = String::new();
+    for (i, digit) in integer.chars().enumerate() {
+        if i > 0 && (integer.len() - i) % 3 == 0 {
+            grouped.push(',');
+        }
+        grouped.push(digit);
+    }
+    let fraction = format!("{:0<width$}", fraction, width = min_fraction as usize);
+    format!(
+        "{}{}{}{}",
+        if rounded.is_sign_negative() && !rounded.is_zero() {
+            "-"
+        } else {
+            ""
+        },
+        grouped,
+        if fraction.is_empty() { "" } else { "." },
+        fraction
+    )
+}
+
 #[wasm_bindgen]
 pub fn format_number(number: String) -> String {
     format_number_helper(&number, None) // No significant digits limit
@@ -117,6 +241,21 @@ pub fn format_mina(number: String) -> String {
     format_number_helper(&number, Some(9)) // Use 9 significant digits
 }
 
+#[cfg(test)]
+mod format_decimal_en_tests {
+    use super::format_decimal_en;
+
+    #[test]
+    fn test_format_decimal_en() {
+        assert_eq!(format_decimal_en("1234567.8915", 0, 3), "1,234,567.892");
+        assert_eq!(format_decimal_en("-999.9996", 0, 3), "-1,000");
+        assert_eq!(format_decimal_en("100", 1, 9), "100.0");
+        assert_eq!(format_decimal_en("0.000000001", 1, 9), "0.000000001");
+        assert_eq!(format_decimal_en("-0.0001", 0, 3), "0");
+        assert_eq!(format_decimal_en("abc", 0, 3), "abc");
+    }
+}
+
 fn split_number(number: &str) -> Result<(char, Vec<&str>), String> {
     let delim: char;
     if number.contains('.') && number.contains(',') {
@@ -329,37 +468,71 @@ pub fn convert_to_span(data: String) -> HtmlElement<html::AnyElement> {
         .into()
 }
 
-pub fn convert_to_title(data: String, title: String) -> HtmlElement<html::AnyElement> {
+/// A timestamp shown according to the user's `TimeSettings`, with the other
+/// form (relative or absolute) as its tooltip.
+pub fn convert_to_date_time(date_time: String) -> HtmlElement<html::AnyElement> {
+    let settings = use_time_settings().settings;
+    let title = date_time.clone();
     html::span()
-        .child(data)
+        .child(move || settings.with(|settings| settings.format(&date_time)))
         .attr("class", "cursor-help")
-        .attr("title", title)
+        .attr("title", move || {
+            settings.with(|settings| settings.format_alternate(&title))
+        })
         .into()
 }
 
 pub fn convert_to_linkable_address(
     username: Option<String>,
-    address: impl Into<PublicKey>,
+    address: impl Into<String>,
 ) -> HtmlElement<html::AnyElement> {
+    // Keys that fail the checksum are shown as they are, without a link
     let address = address.into();
+    let Ok(address) = PublicKey::try_from(address.as_str()) else {
+        return convert_to_span(address);
+    };
     let address_str = address.as_str();
     let href = format!(
         "/addresses/accounts/{}/{}/spotlight",
-        MINA_TOKEN_ADDRESS, address_str
+        use_mina_token_address(),
+        address_str
     );
 
+    let label = convert_to_address_book_label(address_str);
     match username {
         Some(username) => convert_array_to_span(vec![
+            label,
             convert_to_link(username, href.to_string()),
             convert_to_copy_link(address_str.to_string(), href)
                 .attr("class", "text-xs text-slate-400"),
         ])
         .attr("class", "w-full text-ellipsis overflow-hidden"),
-        None => convert_to_copy_link(address_str.to_string(), href)
-            .attr("class", "text-xs text-slate-400"),
+        None => convert_array_to_span(vec![
+            label,
+            convert_to_copy_link(address_str.to_string(), href)
+                .attr("class", "text-xs text-slate-400"),
+        ]),
     }
 }
 
+/// The local address book label for `public_key`, with its tags on hover.
+/// Empty while the key has no entry.
+pub fn convert_to_address_book_label(public_key: &str) -> HtmlElement<html::AnyElement> {
+    let book = use_address_book().book;
+    let public_key = public_key.to_string();
+    html::span()
+        .attr("class", "empty:hidden mr-2 shrink-0")
+        .child(move || {
+            book.with(|book| {
+                book.get(&public_key).map(|entry| {
+                    convert_to_pill(entry.label.clone(), ColorVariant::Blue)
+                        .attr("title", entry.tags.join(", "))
+                })
+            })
+        })
+        .into()
+}
+
 pub fn convert_array_to_span(
     els: Vec<HtmlElement<html::AnyElement>>,
 ) -> HtmlElement<html::AnyElement> {
@@ -454,30 +627,6 @@ pub fn convert_to_tooltip(tooltip: String) -> HtmlElement<html::AnyElement> {
     }.into()
 }
 
-const MINA_SCALE: u32 = 9;
-
-pub fn nanomina_str_to_mina(n_str: &str) -> String {
-    let dec = Decimal::from_str(n_str).unwrap();
-    nanomina_to_mina(dec.to_u64().unwrap())
-}
-
-pub fn nanomina_to_mina(num: u64) -> String {
-    let mut dec = Decimal::from(num);
-    dec.set_scale(MINA_SCALE).unwrap();
-    let num_str = dec.to_string();
-    format_mina(num_str)
-}
-
-pub fn nanomina_to_mina_i64(num: i64) -> String {
-    let abs_num = num.unsigned_abs();
-    let formatted = nanomina_to_mina(abs_num);
-    if num.is_negative() {
-        format!("-{}", formatted)
-    } else {
-        formatted
-    }
-}
-
 pub fn convert_to_copy_link(data: String, href: String) -> HtmlElement<html::AnyElement> {
     view! {
         <span class="w-full text-ellipsis overflow-hidden">
@@ -626,23 +775,23 @@ fn format_duration(duration: &Duration) -> String {
     let minutes = (seconds % (60 * 60)) / 60;
 
     let parts = [
-        (years, "yr"),
-        (months, "mo"),
-        (days, "d"),
-        (hours, "h"),
-        (minutes, "min"),
+        (years, "{count} yr"),
+        (months, "{count} mo"),
+        (days, "{count} d"),
+        (hours, "{count} h"),
+        (minutes, "{count} min"),
     ];
 
     let filtered_parts: Vec<_> = parts
         .iter()
         .filter(|&&(value, _)| value > 0)
-        .map(|&(value, name)| format!("{} {}", value, name))
+        .map(|&(value, key)| tn(key, value as u64))
         .collect();
 
     let num_parts = filtered_parts.len();
 
     match num_parts {
-        0 => "just now".to_string(),
+        0 => t("just now"),
         1..=2 => filtered_parts.join(" "),
         _ => filtered_parts[..2].join(" "),
     }
@@ -651,7 +800,7 @@ fn format_duration(duration: &Duration) -> String {
 pub fn print_time_since(timestamp: &str) -> String {
     let past_time = match timestamp.parse::<DateTime<Utc>>() {
         Ok(time) => time,
-        Err(_e) => return "Unknown".to_string(),
+        Err(_e) => return t("Unknown"),
     };
 
     let now = Utc::now();
@@ -861,6 +1010,64 @@ mod normalize_number_format_tests {
     }
 }
 
+/// The range filters set in the URL, with dates read in `time_zone`. Invalid
+/// values are ignored, as the inputs refuse to set them.
+pub fn get_range_filters(
+    query_params_map: &leptos_router::ParamsMap,
+    time_zone: &TimeZoneSetting,
+) -> RangeFilters {
+    let get = |param: &str| query_params_map.get(param).map(String::as_str);
+    let date = |param, end_of_day| {
+        get(param).and_then(|d| parse_date_bound(d, end_of_day, time_zone).ok())
+    };
+    let mina = |param| get(param).and_then(|mina| Amount::from_mina(mina).ok());
+    RangeFilters {
+        date_time: Range {
+            gte: date(QUERY_PARAM_DATE_GTE, false),
+            lte: date(QUERY_PARAM_DATE_LTE, true),
+        },
+        amount: Range {
+            gte: mina(QUERY_PARAM_AMOUNT_GTE),
+            lte: mina(QUERY_PARAM_AMOUNT_LTE),
+        },
+        fee: Range {
+            gte: mina(QUERY_PARAM_FEE_GTE),
+            lte: mina(QUERY_PARAM_FEE_LTE),
+        },
+    }
+}
+
+/// Whether any valid range filter is set in the URL.
+pub fn has_range_filters(query_params_map: &leptos_router::ParamsMap) -> bool {
+    get_range_filters(query_params_map, &TimeZoneSetting::Utc).is_set()
+}
+
+#[cfg(test)]
+mod get_range_filters_tests {
+    use super::*;
+
+    #[test]
+    fn test_get_range_filters() {
+        let mut map = leptos_router::ParamsMap::new();
+        assert!(!has_range_filters(&map));
+        map.insert(QUERY_PARAM_DATE_LTE.to_string(), "2024-03-01".to_string());
+        map.insert(QUERY_PARAM_AMOUNT_GTE.to_string(), "10000".to_string());
+        map.insert(QUERY_PARAM_FEE_GTE.to_string(), "bogus".to_string());
+        assert!(has_range_filters(&map));
+        let utc = TimeZoneSetting::Utc;
+        let filters = get_range_filters(&map, &utc);
+        assert_eq!(
+            filters.date_time.lte,
+            Some(parse_date_bound("2024-03-01", true, &utc).unwrap())
+        );
+        assert_eq!(
+            filters.amount.gte,
+            Some(Amount::from_nanomina(10_000_000_000_000i64))
+        );
+        assert_eq!(filters.fee, Range::default());
+    }
+}
+
 const MAXIMUM_BLOCK_RANGE: u64 = 2000;
 pub fn validate_block_height_range(
     blockheight_gte_opt: Option<u64>,
@@ -918,13 +1125,6 @@ mod validate_block_height_range_tests {
     }
 }
 
-#[wasm_bindgen(
-    inline_js = "export function get_unix_timestamp() { return Math.floor(Date.now() / 1000); }"
-)]
-extern "C" {
-    pub fn get_unix_timestamp() -> f64;
-}
-
 pub fn get_button_style_variation(style_variant: &ButtonStyleVariant) -> &str {
     match style_variant {
         ButtonStyleVariant::Primary => "text-white bg-granola-orange",
@@ -952,3 +1152,200 @@ pub fn format_json_array_pretty(vec: Vec<Option<String>>) -> Result<String, serd
     let pretty = serde_json::to_string_pretty(&json_value)?;
     Ok(pretty)
 }
+
+/// Puts the rows of `incoming` that are not already in `current` in front of
+/// it, keeping at most `limit` rows. Returns the rows and how many were added.
+pub fn prepend_new<T, K, F>(
+    current: Vec<T>,
+    incoming: Vec<T>,
+    limit: usize,
+    key: F,
+) -> (Vec<T>, usize)
+where
+    K: PartialEq,
+    F: Fn(&T) -> Option<K>,
+{
+    let existing = current.iter().filter_map(&key).collect::<Vec<_>>();
+    let new_rows = incoming
+        .into_iter()
+        .filter(|row| key(row).is_some_and(|k| !existing.contains(&k)))
+        .collect::<Vec<_>>();
+    let added = new_rows.len();
+    let rows = new_rows.into_iter().chain(current).take(limit).collect();
+    (rows, added)
+}
+
+#[cfg(test)]
+mod prepend_new_tests {
+    use super::prepend_new;
+
+    #[test]
+    fn test_prepends_unseen_rows() {
+        let (rows, added) = prepend_new(vec![3, 2, 1], vec![5, 4, 3], 10, |n| Some(*n));
+        assert_eq!(rows, vec![5, 4, 3, 2, 1]);
+        assert_eq!(added, 2);
+    }
+
+    #[test]
+    fn test_keeps_limit() {
+        let (rows, added) = prepend_new(vec![3, 2, 1], vec![4], 3, |n| Some(*n));
+        assert_eq!(rows, vec![4, 3, 2]);
+        assert_eq!(added, 1);
+    }
+
+    #[test]
+    fn test_skips_rows_without_key() {
+        let (rows, added) =
+            prepend_new(vec![Some(1)], vec![None, Some(2)], 10, |n: &Option<i32>| *n);
+        assert_eq!(rows, vec![Some(2), Some(1)]);
+        assert_eq!(added, 1);
+    }
+}
+
+pub fn opt_to_string<T: ToString>(value: Option<T>) -> String {
+    value.map_or_else(String::new, |v| v.to_string())
+}
+
+/// Renders rows as RFC 4180 CSV with `headers` as the first line.
+pub fn to_csv(headers: &[String], rows: &[Vec<String>]) -> String {
+    fn escape(field: &str) -> String {
+        if field.contains([',', '"', '\n', '\r']) {
+            format!("\"{}\"", field.replace('"', "\"\""))
+        } else {
+            field.to_string()
+        }
+    }
+    std::iter::once(headers.to_vec())
+        .chain(rows.iter().cloned())
+        .map(|row| row.iter().map(|f| escape(f)).collect::<Vec<_>>().join(","))
+        .map(|line| line + "\r\n")
+        .collect()
+}
+
+/// Reads RFC 4180 CSV, as written by `to_csv`, into rows of fields.
+pub fn parse_csv(text: &str) -> Vec<Vec<String>> {
+    let mut rows = vec![];
+    let mut row = vec![];
+    let mut field = String::new();
+    let mut in_quotes = false;
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        match (c, in_quotes) {
+            ('"', true) if chars.peek() == Some(&'"') => {
+                field.push('"');
+                chars.next();
+            }
+            ('"', _) => in_quotes = !in_quotes,
+            (',', false) => row.push(std::mem::take(&mut field)),
+            ('\r', false) => {}
+            ('\n', false) => {
+                row.push(std::mem::take(&mut field));
+                rows.push(std::mem::take(&mut row));
+            }
+            (c, _) => field.push(c),
+        }
+    }
+    if !field.is_empty() || !row.is_empty() {
+        row.push(field);
+        rows.push(row);
+    }
+    rows
+}
+
+/// Renders rows as a JSON array of objects keyed by `headers`.
+pub fn to_json_records(headers: &[String], rows: &[Vec<String>]) -> String {
+    let records = rows
+        .iter()
+        .map(|row| {
+            headers
+                .iter()
+                .cloned()
+                .zip(row.iter().cloned().map(Value::String))
+                .collect::<serde_json::Map<_, _>>()
+        })
+        .map(Value::Object)
+        .collect::<Vec<_>>();
+    serde_json::to_string_pretty(&Value::Array(records)).unwrap_or_default()
+}
+
+/// Hands `content` to the browser as a file download.
+pub fn download_file(filename: &str, mime_type: &str, content: &str) {
+    let parts = Array::of1(&JsValue::from_str(content));
+    let options = web_sys::BlobPropertyBag::new();
+    options.set_type(mime_type);
+    let Ok(blob) = web_sys::Blob::new_with_str_sequence_and_options(&parts, &options) else {
+        logging::error!("Unable to create {}", filename);
+        return;
+    };
+    let Ok(url) = web_sys::Url::create_object_url_with_blob(&blob) else {
+        logging::error!("Unable to create a download link for {}", filename);
+        return;
+    };
+    let anchor = document()
+        .create_element("a")
+        .unwrap()
+        .unchecked_into::<web_sys::HtmlAnchorElement>();
+    anchor.set_href(&url);
+    anchor.set_download(filename);
+    anchor.click();
+    let _ = web_sys::Url::revoke_object_url(&url);
+}
+
+/// Reads a file picked by the user and hands its text to `on_load`.
+pub fn read_file_text(file: web_sys::File, on_load: impl FnOnce(String) + 'static) {
+    let Ok(reader) = web_sys::FileReader::new() else {
+        logging::error!("Unable to read {}", file.name());
+        return;
+    };
+    let result_reader = reader.clone();
+    let on_reader_load = Closure::once(move || {
+        if let Some(text) = result_reader.result().ok().and_then(|r| r.as_string()) {
+            on_load(text);
+        }
+    });
+    reader.set_onload(Some(on_reader_load.as_ref().unchecked_ref()));
+    if reader.read_as_text(&file).is_err() {
+        logging::error!("Unable to read {}", file.name());
+    }
+    on_reader_load.forget();
+}
+
+#[cfg(test)]
+mod export_tests {
+    use super::*;
+
+    fn headers() -> Vec<String> {
+        vec!["Height".to_string(), "Memo".to_string()]
+    }
+
+    #[test]
+    fn test_to_csv_escapes_fields() {
+        let rows = vec![
+            vec!["1".to_string(), "plain".to_string()],
+            vec!["2".to_string(), "a, \"quoted\" memo".to_string()],
+        ];
+        assert_eq!(
+            to_csv(&headers(), &rows),
+            "Height,Memo\r\n1,plain\r\n2,\"a, \"\"quoted\"\" memo\"\r\n"
+        );
+    }
+
+    #[test]
+    fn test_parse_csv_round_trip() {
+        let rows = vec![
+            vec!["1".to_string(), "a, \"quoted\"\nmemo".to_string()],
+            vec!["2".to_string(), "".to_string()],
+        ];
+        let mut parsed = parse_csv(&to_csv(&headers(), &rows));
+        assert_eq!(parsed.remove(0), headers());
+        assert_eq!(parsed, rows);
+    }
+
+    #[test]
+    fn test_to_json_records() {
+        let rows = vec![vec!["1".to_string(), "plain".to_string()]];
+        let value: Value = serde_json::from_str(&to_json_records(&headers(), &rows)).unwrap();
+        assert_eq!(value[0]["Height"], "1");
+        assert_eq!(value[0]["Memo"], "plain");
+    }
+}
@@ -0,0 +1,169 @@
+use leptos::*;
+use serde::Deserialize;
+use std::collections::HashMap;
+
+pub const DEFAULT_LOCALE: &str = "en";
+
+/// The supported locales as (code, name shown in the picker, catalog).
+/// Catalogs are keyed by the English source text. Anything missing falls back
+/// to the English catalog and then to the key itself.
+const CATALOGS: [(&str, &str, &str); 4] = [
+    ("en", "English", include_str!("../../locales/en.json")),
+    ("es", "Español", include_str!("../../locales/es.json")),
+    ("pt", "Português", include_str!("../../locales/pt.json")),
+    ("zh", "中文", include_str!("../../locales/zh.json")),
+];
+
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(untagged)]
+enum Message {
+    Text(String),
+    /// Forms keyed by CLDR plural category (`one`, `other`, ...), with
+    /// `{count}` standing for the number.
+    Plural(HashMap<String, String>),
+}
+
+type Catalog = HashMap<String, Message>;
+
+thread_local! {
+    static PARSED_CATALOGS: HashMap<&'static str, Catalog> = CATALOGS
+        .iter()
+        .map(|(code, _, json)| {
+            let catalog = serde_json::from_str(json)
+                .unwrap_or_else(|err| panic!("Invalid catalog for {}: {}", code, err));
+            (*code, catalog)
+        })
+        .collect();
+}
+
+/// The locale the UI is rendered in, provided by `LocaleProvider`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Locale(pub &'static str);
+
+pub fn locales() -> Vec<(&'static str, &'static str)> {
+    CATALOGS
+        .iter()
+        .map(|(code, name, _)| (*code, *name))
+        .collect()
+}
+
+/// The supported locale for a BCP 47 tag such as `es-MX`, if any.
+pub fn match_locale(tag: &str) -> Option<&'static str> {
+    let language = tag.split(['-', '_']).next()?.to_lowercase();
+    CATALOGS
+        .iter()
+        .map(|(code, _, _)| *code)
+        .find(|code| *code == language)
+}
+
+fn plural_category(locale: &str, count: u64) -> &'static str {
+    match locale {
+        "zh" => "other",
+        "pt" if count <= 1 => "one",
+        _ if count == 1 => "one",
+        _ => "other",
+    }
+}
+
+fn lookup<T>(locale: &str, key: &str, f: impl Fn(&Message) -> Option<T>) -> Option<T> {
+    PARSED_CATALOGS.with(|catalogs| {
+        [locale, DEFAULT_LOCALE]
+            .iter()
+            .filter_map(|code| catalogs.get(code)?.get(key))
+            .find_map(f)
+    })
+}
+
+pub fn translate(locale: &str, key: &str) -> String {
+    lookup(locale, key, |message| match message {
+        Message::Text(text) => Some(text.clone()),
+        Message::Plural(_) => None,
+    })
+    .unwrap_or_else(|| key.to_string())
+}
+
+/// The plural form of `key` for `count`, with `{count}` replaced by `shown`.
+fn translate_plural_as(locale: &str, key: &str, count: u64, shown: &str) -> String {
+    let category = plural_category(locale, count);
+    lookup(locale, key, |message| match message {
+        Message::Plural(forms) => forms.get(category).or_else(|| forms.get("other")).cloned(),
+        Message::Text(text) => Some(text.clone()),
+    })
+    .unwrap_or_else(|| key.to_string())
+    .replace("{count}", shown)
+}
+
+pub fn translate_plural(locale: &str, key: &str, count: u64) -> String {
+    translate_plural_as(locale, key, count, &count.to_string())
+}
+
+pub fn use_locale() -> Locale {
+    use_context::<Locale>().unwrap_or(Locale(DEFAULT_LOCALE))
+}
+
+/// Translates `key` into the current locale.
+pub fn t(key: &str) -> String {
+    translate(use_locale().0, key)
+}
+
+/// Translates `key` into the current locale, in the plural form for `count`.
+pub fn tn(key: &str, count: u64) -> String {
+    translate_plural(use_locale().0, key, count)
+}
+
+/// Like `tn`, but shows `count` as the already formatted `shown`.
+pub fn tn_as(key: &str, count: u64, shown: &str) -> String {
+    translate_plural_as(use_locale().0, key, count, shown)
+}
+
+#[cfg(test)]
+mod i18n_tests {
+    use super::*;
+
+    #[test]
+    fn test_catalogs_parse() {
+        PARSED_CATALOGS.with(|catalogs| assert_eq!(catalogs.len(), CATALOGS.len()));
+    }
+
+    #[test]
+    fn test_catalogs_only_translate_known_keys() {
+        PARSED_CATALOGS.with(|catalogs| {
+            let english = &catalogs[DEFAULT_LOCALE];
+            for (code, catalog) in catalogs {
+                for key in catalog.keys() {
+                    assert!(english.contains_key(key), "{}: unknown key {:?}", code, key);
+                }
+            }
+        });
+    }
+
+    #[test]
+    fn test_match_locale() {
+        assert_eq!(match_locale("es-MX"), Some("es"));
+        assert_eq!(match_locale("pt_BR"), Some("pt"));
+        assert_eq!(match_locale("zh-Hans-CN"), Some("zh"));
+        assert_eq!(match_locale("EN"), Some("en"));
+        assert_eq!(match_locale("fr-FR"), None);
+        assert_eq!(match_locale(""), None);
+    }
+
+    #[test]
+    fn test_translate_falls_back_to_english() {
+        assert_eq!(translate("es", "Blocks"), "Bloques");
+        assert_eq!(translate("fr", "Blocks"), "Blocks");
+        assert_eq!(translate("es", "No such message"), "No such message");
+    }
+
+    #[test]
+    fn test_translate_plural() {
+        assert_eq!(translate_plural("en", "{count} records", 1), "1 record");
+        assert_eq!(translate_plural("en", "{count} records", 0), "0 records");
+        assert_eq!(translate_plural("es", "{count} records", 2), "2 registros");
+        assert_eq!(translate_plural("pt", "{count} records", 0), "0 registro");
+        assert_eq!(translate_plural("zh", "{count} records", 1), "1 条记录");
+        assert_eq!(
+            translate_plural_as("en", "{count} records", 1200, "1,200"),
+            "1,200 records"
+        );
+    }
+}
@@ -0,0 +1,133 @@
+use super::client::invalidate_query_cache;
+use leptos::*;
+use leptos_use::use_document_visibility;
+use serde::{Deserialize, Serialize};
+
+/// A new canonical tip, as announced by the indexer's live stream or noticed
+/// while polling the summary endpoint.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+pub struct NewBlockEvent {
+    pub blockchain_length: u64,
+    #[serde(default)]
+    pub state_hash: Option<String>,
+}
+
+impl NewBlockEvent {
+    pub fn is_ahead_of(&self, current: Option<&NewBlockEvent>) -> bool {
+        current.is_none_or(|current| self.blockchain_length > current.blockchain_length)
+    }
+}
+
+/// Where new-block events come from for a network.
+#[derive(Debug, Clone, PartialEq)]
+pub enum LiveSource {
+    WebSocket(String),
+    EventSource(String),
+    Polling,
+}
+
+impl LiveSource {
+    pub fn from_endpoint(endpoint: Option<&str>) -> Self {
+        match endpoint {
+            Some(url) if url.starts_with("ws://") || url.starts_with("wss://") => {
+                LiveSource::WebSocket(url.to_string())
+            }
+            Some(url) if !url.is_empty() => LiveSource::EventSource(url.to_string()),
+            _ => LiveSource::Polling,
+        }
+    }
+}
+
+/// Shared by every component that shows live data. See `LiveUpdatesProvider`.
+#[derive(Clone, Copy)]
+pub struct LiveUpdates {
+    latest_block: RwSignal<Option<NewBlockEvent>>,
+    poll: Signal<u64>,
+}
+
+impl LiveUpdates {
+    pub fn new(poll: Signal<u64>) -> Self {
+        LiveUpdates {
+            latest_block: create_rw_signal(None),
+            poll,
+        }
+    }
+
+    pub fn latest_height(&self) -> Option<u64> {
+        self.latest_block
+            .with(|block| block.as_ref().map(|b| b.blockchain_length))
+    }
+
+    /// Changes on every new block and, while no stream is connected, on every
+    /// polling tick. Resources that show live data use it as their source.
+    pub fn refresh_key(&self) -> (Option<u64>, u64) {
+        (self.latest_height(), self.poll.get())
+    }
+
+    /// Records `event` if it moves the tip forward. Cached query results are
+    /// dropped first so whatever refetches next sees the new block.
+    pub fn announce(&self, event: NewBlockEvent) {
+        let is_new = self
+            .latest_block
+            .with_untracked(|current| event.is_ahead_of(current.as_ref()));
+        if is_new {
+            invalidate_query_cache();
+            self.latest_block.set(Some(event));
+        }
+    }
+}
+
+pub fn use_live_updates() -> LiveUpdates {
+    use_context::<LiveUpdates>().expect("Expected there to be live updates provided")
+}
+
+/// Whether the page is shown, so that polling it is worthwhile. The server
+/// cannot tell and renders every page as shown.
+pub fn use_page_visible() -> Signal<bool> {
+    let visibility = use_document_visibility();
+    Signal::derive(move || {
+        cfg!(feature = "ssr") || visibility.get() == web_sys::VisibilityState::Visible
+    })
+}
+
+#[cfg(test)]
+mod live_tests {
+    use super::*;
+
+    fn block(blockchain_length: u64) -> NewBlockEvent {
+        NewBlockEvent {
+            blockchain_length,
+            state_hash: None,
+        }
+    }
+
+    #[test]
+    fn test_is_ahead_of() {
+        assert!(block(10).is_ahead_of(None));
+        assert!(block(11).is_ahead_of(Some(&block(10))));
+        assert!(!block(10).is_ahead_of(Some(&block(10))));
+        assert!(!block(9).is_ahead_of(Some(&block(10))));
+    }
+
+    #[test]
+    fn test_live_source_from_endpoint() {
+        assert_eq!(LiveSource::from_endpoint(None), LiveSource::Polling);
+        assert_eq!(LiveSource::from_endpoint(Some("")), LiveSource::Polling);
+        assert_eq!(
+            LiveSource::from_endpoint(Some("wss://api.example.com/live")),
+            LiveSource::WebSocket("wss://api.example.com/live".to_string())
+        );
+        assert_eq!(
+            LiveSource::from_endpoint(Some("https://api.example.com/live")),
+            LiveSource::EventSource("https://api.example.com/live".to_string())
+        );
+    }
+
+    #[test]
+    fn test_event_from_json() {
+        let event: NewBlockEvent =
+            serde_json::from_str(r#"{"blockchain_length": 42, "state_hash": "3NK"}"#).unwrap();
+        assert_eq!(event.blockchain_length, 42);
+        assert_eq!(event.state_hash.as_deref(), Some("3NK"));
+    }
+}
@@ -0,0 +1,241 @@
+use super::{
+    base58::{MEMO_VERSION, decode_check, encode_check},
+    i18n::t,
+};
+use leptos::*;
+
+const MEMO_DIGEST_TAG: u8 = 0x00;
+const MEMO_BYTES_TAG: u8 = 0x01;
+/// Memo content is padded to this many bytes.
+const MEMO_LEN: usize = 32;
+
+/// How a memo is shown.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum MemoFormat {
+    #[default]
+    Decoded,
+    Raw,
+    Hex,
+}
+
+impl MemoFormat {
+    pub const ALL: [MemoFormat; 3] = [MemoFormat::Decoded, MemoFormat::Raw, MemoFormat::Hex];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            MemoFormat::Decoded => "Decoded",
+            MemoFormat::Raw => "Raw",
+            MemoFormat::Hex => "Hex",
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum MemoContent {
+    Bytes(Vec<u8>),
+    Digest(Vec<u8>),
+}
+
+impl MemoContent {
+    fn bytes(&self) -> &[u8] {
+        match self {
+            MemoContent::Bytes(bytes) | MemoContent::Digest(bytes) => bytes,
+        }
+    }
+}
+
+/// Decodes a Base58 memo. `None` for anything else, as the indexer returns
+/// some memos already decoded.
+pub fn decode_memo(memo: &str) -> Option<MemoContent> {
+    let payload = decode_check(memo, &[MEMO_VERSION]).ok()?;
+    match payload.as_slice() {
+        [MEMO_BYTES_TAG, len, content @ ..]
+            if content.len() == MEMO_LEN && *len as usize <= MEMO_LEN =>
+        {
+            Some(MemoContent::Bytes(content[..*len as usize].to_vec()))
+        }
+        [MEMO_DIGEST_TAG, _, content @ ..] if content.len() == MEMO_LEN => {
+            Some(MemoContent::Digest(content.to_vec()))
+        }
+        _ => None,
+    }
+}
+
+/// Encodes `text` the way the node does. `None` if it doesn't fit in a memo.
+pub fn encode_memo(text: &str) -> Option<String> {
+    let bytes = text.as_bytes();
+    if bytes.len() > MEMO_LEN {
+        return None;
+    }
+    let mut payload = vec![MEMO_BYTES_TAG, bytes.len() as u8];
+    payload.extend_from_slice(bytes);
+    payload.resize(MEMO_LEN + 2, 0);
+    Some(encode_check(MEMO_VERSION, &payload))
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    if bytes.is_empty() {
+        return String::new();
+    }
+    bytes
+        .iter()
+        .fold("0x".to_string(), |hex, byte| format!("{}{:02x}", hex, byte))
+}
+
+/// Decoded memos that aren't printable UTF-8, and digests, are shown as hex.
+pub fn format_memo(memo: &str, format: MemoFormat) -> String {
+    let decoded = decode_memo(memo);
+    match format {
+        MemoFormat::Raw => memo.to_string(),
+        MemoFormat::Hex => to_hex(decoded.as_ref().map_or(memo.as_bytes(), MemoContent::bytes)),
+        MemoFormat::Decoded => match decoded {
+            Some(MemoContent::Bytes(bytes)) => match String::from_utf8(bytes) {
+                Ok(text) if !text.chars().any(char::is_control) => text,
+                Ok(text) => to_hex(text.as_bytes()),
+                Err(err) => to_hex(err.as_bytes()),
+            },
+            Some(MemoContent::Digest(bytes)) => to_hex(&bytes),
+            None => memo.to_string(),
+        },
+    }
+}
+
+/// The values a memo filter queries for: `memo` as entered plus its Base58
+/// form, or its text if it was entered in Base58, so that it matches however
+/// the indexer stored the memo.
+pub fn memo_search_values(memo: &str) -> Vec<String> {
+    let memo = memo.trim();
+    let other = match decode_memo(memo) {
+        Some(MemoContent::Bytes(bytes)) => String::from_utf8(bytes).ok(),
+        Some(MemoContent::Digest(_)) => None,
+        None => encode_memo(memo),
+    };
+    std::iter::once(memo.to_string()).chain(other).collect()
+}
+
+pub fn validate_memo(memo: &str) -> Result<(), String> {
+    if decode_memo(memo).is_some() || memo.len() <= MEMO_LEN {
+        Ok(())
+    } else {
+        Err(format!("Memos are at most {} bytes", MEMO_LEN))
+    }
+}
+
+/// A memo with a toggle between its decoded, raw and hex forms.
+#[component]
+pub fn MemoDisplay(memo: String) -> impl IntoView {
+    let (format, set_format) = create_signal(MemoFormat::default());
+    let memo = store_value(memo);
+
+    view! {
+        <span class="memo-display flex flex-wrap items-center gap-2">
+            <span class="memo-value text-xs px-2 py-0.5 rounded-full bg-slate-100 font-mono break-all">
+                {move || memo.with_value(|memo| format_memo(memo, format.get()))}
+            </span>
+            <span class="memo-formats flex text-xs">
+                {MemoFormat::ALL
+                    .into_iter()
+                    .map(|option| {
+                        view! {
+                            <button
+                                class="px-1 text-slate-400 hover:text-granola-orange"
+                                class=("text-granola-orange", move || format.get() == option)
+                                class=("font-semibold", move || format.get() == option)
+                                on:click=move |_| set_format.set(option)
+                            >
+                                {t(option.label())}
+                            </button>
+                        }
+                    })
+                    .collect_view()}
+            </span>
+        </span>
+    }
+}
+
+#[cfg(test)]
+mod memo_tests {
+    use super::*;
+
+    const EMPTY_MEMO: &str = "E4YM2vTHhWEg66xpj52JErHUBU4pZ1yageL4TVDDpTTSsv8mK6YaH";
+    const TEXT_MEMO: &str = "E4Z6ZzFrSLzvpdJEZPVdh5KdTaMooCgNWSrhm89d4exKq3cqD5G97";
+    const BINARY_MEMO: &str = "E4YUoTd3cE77fi3G9nwPZHaySMnLSrTB3fPDH1qADX4XnQG3GaptQ";
+    const DIGEST_MEMO: &str = "E4QqcB3BTbriwf22c3B5YoZ7XyYJ3FchRvmBySZxca1D22jzP4S7c";
+
+    #[test]
+    fn test_decode_memo() {
+        assert_eq!(decode_memo(EMPTY_MEMO), Some(MemoContent::Bytes(vec![])));
+        assert_eq!(
+            decode_memo(TEXT_MEMO),
+            Some(MemoContent::Bytes(b"exchange deposit 12345".to_vec()))
+        );
+        assert_eq!(
+            decode_memo(DIGEST_MEMO),
+            Some(MemoContent::Digest((0..32).collect()))
+        );
+        assert_eq!(decode_memo("SDK payment"), None);
+        // A valid Base58 identifier of another kind
+        assert_eq!(
+            decode_memo("3NKeMoncuHab5ScarV5ViyF16cJPT4taWNSaTLS64Dp67wuXigPZ"),
+            None
+        );
+    }
+
+    #[test]
+    fn test_encode_memo() {
+        assert_eq!(encode_memo("").as_deref(), Some(EMPTY_MEMO));
+        assert_eq!(
+            encode_memo("exchange deposit 12345").as_deref(),
+            Some(TEXT_MEMO)
+        );
+        assert_eq!(encode_memo(&"x".repeat(33)), None);
+    }
+
+    #[test]
+    fn test_format_memo() {
+        assert_eq!(format_memo(EMPTY_MEMO, MemoFormat::Decoded), "");
+        assert_eq!(
+            format_memo(TEXT_MEMO, MemoFormat::Decoded),
+            "exchange deposit 12345"
+        );
+        assert_eq!(format_memo(TEXT_MEMO, MemoFormat::Raw), TEXT_MEMO);
+        assert_eq!(
+            format_memo(TEXT_MEMO, MemoFormat::Hex),
+            "0x65786368616e6765206465706f736974203132333435"
+        );
+        assert_eq!(format_memo(BINARY_MEMO, MemoFormat::Decoded), "0xff0010");
+        assert_eq!(
+            format_memo(DIGEST_MEMO, MemoFormat::Decoded),
+            format_memo(DIGEST_MEMO, MemoFormat::Hex)
+        );
+        assert_eq!(
+            format_memo("SDK payment", MemoFormat::Decoded),
+            "SDK payment"
+        );
+        assert_eq!(format_memo("SDK", MemoFormat::Hex), "0x53444b");
+    }
+
+    #[test]
+    fn test_memo_search_values() {
+        assert_eq!(
+            memo_search_values(" exchange deposit 12345 "),
+            vec!["exchange deposit 12345".to_string(), TEXT_MEMO.to_string()]
+        );
+        assert_eq!(
+            memo_search_values(TEXT_MEMO),
+            vec![TEXT_MEMO.to_string(), "exchange deposit 12345".to_string()]
+        );
+        assert_eq!(
+            memo_search_values(DIGEST_MEMO),
+            vec![DIGEST_MEMO.to_string()]
+        );
+        assert_eq!(memo_search_values(&"x".repeat(33)).len(), 1);
+    }
+
+    #[test]
+    fn test_validate_memo() {
+        assert!(validate_memo("exchange deposit 12345").is_ok());
+        assert!(validate_memo(TEXT_MEMO).is_ok());
+        assert!(validate_memo(&"x".repeat(33)).is_err());
+    }
+}
@@ -0,0 +1,276 @@
+//! Answers every request from the recorded JSON in `fixtures/` instead of the
+//! network when built with the `mock` feature, so that every page can be
+//! developed without an indexer.
+//!
+//! Fixtures are grouped by scenario. Pick one with the `mock-scenario` query
+//! param, e.g. `/blocks?mock-scenario=orphaned-blocks`. The choice is kept in
+//! local storage. Anything a scenario has no fixture for comes from `default`.
+#![cfg_attr(not(feature = "mock"), allow(dead_code))]
+
+use super::{constants::*, models::MyError};
+
+macro_rules! fixtures {
+    ($($scenario:literal / $name:literal),* $(,)?) => {
+        &[$((
+            $scenario,
+            $name,
+            include_str!(concat!("../../fixtures/", $scenario, "/", $name, ".json")),
+        )),*]
+    };
+}
+
+pub const DEFAULT_SCENARIO: &str = "default";
+#[cfg(not(feature = "ssr"))]
+const QUERY_PARAM_MOCK_SCENARIO: &str = "mock-scenario";
+#[cfg(not(feature = "ssr"))]
+const MOCK_SCENARIO_STORAGE_KEY: &str = "mock-scenario";
+
+/// (scenario, GraphQL operation or REST resource, JSON)
+const FIXTURES: &[(&str, &str, &str)] = fixtures![
+    "default" / "AccountActivityQuery",
+    "default" / "AccountsQuery",
+    "default" / "BlockProducersStatQuery",
+    "default" / "BlocksQuery",
+    "default" / "EpochQuery",
+    "default" / "EpochSummaryQuery",
+    "default" / "InternalCommandsQuery",
+    "default" / "LedgerHashQuery",
+    "default" / "SnarkFeesQuery",
+    "default" / "SnarksQuery",
+    "default" / "StakingLedgersQuery",
+    "default" / "TokenHoldersQuery",
+    "default" / "TokenSymbolQuery",
+    "default" / "TokensQuery",
+    "default" / "TopSnarkersQuery",
+    "default" / "TopStakersQuery",
+    "default" / "TransactionsQuery",
+    "default" / "broadcast",
+    "default" / "pooledUserCommands",
+    "default" / "summary",
+    "orphaned-blocks" / "BlocksQuery",
+    "orphaned-blocks" / "TransactionsQuery",
+    "failed-transactions" / "AccountActivityQuery",
+    "failed-transactions" / "BlocksQuery",
+    "failed-transactions" / "TransactionsQuery",
+    "zkapps" / "AccountActivityQuery",
+    "zkapps" / "BlocksQuery",
+    "zkapps" / "TransactionsQuery",
+];
+
+fn fixture(scenario: &str, name: &str) -> Option<&'static str> {
+    [scenario, DEFAULT_SCENARIO].iter().find_map(|scenario| {
+        FIXTURES
+            .iter()
+            .find(|(s, n, _)| s == scenario && *n == name)
+            .map(|(_, _, json)| *json)
+    })
+}
+
+fn not_found(request: &str) -> MyError {
+    MyError::HttpError {
+        status: 404,
+        message: format!("No mock fixture for {}", request),
+    }
+}
+
+fn graphql_response_for(scenario: &str, operation_name: &str) -> Result<String, MyError> {
+    fixture(scenario, operation_name)
+        .map(|data| format!(r#"{{"data":{}}}"#, data))
+        .ok_or_else(|| not_found(operation_name))
+}
+
+fn rest_response_for(scenario: &str, url: &str) -> Result<String, MyError> {
+    let name = if url.ends_with("/summary") {
+        "summary"
+    } else if url.contains("pooledUserCommands") {
+        "pooledUserCommands"
+    } else if url.starts_with(MINA_EXPLORER_ENDPOINT) {
+        "broadcast"
+    } else {
+        return Err(not_found(url));
+    };
+    fixture(scenario, name)
+        .map(str::to_string)
+        .ok_or_else(|| not_found(url))
+}
+
+/// The server has neither the URL nor local storage at hand when a resource
+/// loads, so it renders the default scenario.
+#[cfg(feature = "ssr")]
+fn current_scenario() -> String {
+    DEFAULT_SCENARIO.to_string()
+}
+
+/// The scenario named in the URL, or else the one last named.
+#[cfg(not(feature = "ssr"))]
+fn current_scenario() -> String {
+    let window = leptos::window();
+    let storage = window.local_storage().ok().flatten();
+    let from_url = window
+        .location()
+        .href()
+        .ok()
+        .and_then(|href| web_sys::Url::new(&href).ok())
+        .and_then(|url| url.search_params().get(QUERY_PARAM_MOCK_SCENARIO));
+    match (from_url, storage) {
+        (Some(scenario), Some(storage)) => {
+            let _ = storage.set_item(MOCK_SCENARIO_STORAGE_KEY, &scenario);
+            scenario
+        }
+        (Some(scenario), None) => scenario,
+        (None, Some(storage)) => storage
+            .get_item(MOCK_SCENARIO_STORAGE_KEY)
+            .ok()
+            .flatten()
+            .unwrap_or(DEFAULT_SCENARIO.to_string()),
+        (None, None) => DEFAULT_SCENARIO.to_string(),
+    }
+}
+
+pub fn graphql_response(operation_name: &str) -> Result<String, MyError> {
+    graphql_response_for(&current_scenario(), operation_name)
+}
+
+/// Answers GET requests and broadcasts.
+pub fn rest_response(url: &str) -> Result<String, MyError> {
+    rest_response_for(&current_scenario(), url)
+}
+
+#[cfg(test)]
+mod mock_tests {
+    use super::*;
+    use crate::{
+        account_activity::graphql::AccountActivityQuery,
+        accounts::graphql::{AccountsQuery, TokenSymbolQuery},
+        analytics::{
+            graphql::{SnarkFeesQuery, TopSnarkersQuery},
+            staker_leaderboard::graphql::TopStakersQuery,
+        },
+        blocks::graphql::BlocksQuery,
+        internal_commands::graphql::InternalCommandsQuery,
+        snarks::graphql::SnarksQuery,
+        stakes::graphql::{EpochQuery, EpochSummaryQuery, LedgerHashQuery, StakingLedgersQuery},
+        summary::{graphql::BlockProducersStatQuery, models::BlockchainSummary},
+        token_holders::graphql::TokenHoldersQuery,
+        tokens::graphql::TokensQuery,
+        user_commands::{graphql::TransactionsQuery, models::PooledUserCommandsResponse},
+    };
+    use graphql_client::{GraphQLQuery, Response};
+    use serde::de::DeserializeOwned;
+
+    const QUERIES: [&str; 15] = [
+        include_str!("../../graphql/queries/account_dialog.graphql"),
+        include_str!("../../graphql/queries/accounts.graphql"),
+        include_str!("../../graphql/queries/block_producers_stat.graphql"),
+        include_str!("../../graphql/queries/blocks.graphql"),
+        include_str!("../../graphql/queries/internal_commands.graphql"),
+        include_str!("../../graphql/queries/snark_fees.graphql"),
+        include_str!("../../graphql/queries/snarker_leaderboard.graphql"),
+        include_str!("../../graphql/queries/snarks.graphql"),
+        include_str!("../../graphql/queries/staker_leaderboard.graphql"),
+        include_str!("../../graphql/queries/staking_epochs.graphql"),
+        include_str!("../../graphql/queries/staking_ledgers.graphql"),
+        include_str!("../../graphql/queries/token_holders.graphql"),
+        include_str!("../../graphql/queries/token_symbol.graphql"),
+        include_str!("../../graphql/queries/tokens.graphql"),
+        include_str!("../../graphql/queries/transactions.graphql"),
+    ];
+
+    fn parse_graphql<Q: GraphQLQuery>(scenario: &str, name: &str) -> Result<(), String> {
+        let text = graphql_response_for(scenario, name).map_err(|e| e.to_string())?;
+        let response: Response<Q::ResponseData> =
+            serde_json::from_str(&text).map_err(|e| e.to_string())?;
+        response.data.map(|_| ()).ok_or("no data".to_string())
+    }
+
+    fn parse_rest<T: DeserializeOwned>(json: &str) -> Result<(), String> {
+        serde_json::from_str::<T>(json)
+            .map(|_| ())
+            .map_err(|e| e.to_string())
+    }
+
+    #[test]
+    fn test_every_query_has_a_default_fixture() {
+        for query in QUERIES {
+            for operation in query
+                .lines()
+                .filter_map(|line| line.strip_prefix("query "))
+                .filter_map(|rest| rest.split(['(', ' ', '{']).next())
+            {
+                assert!(
+                    fixture(DEFAULT_SCENARIO, operation).is_some(),
+                    "No fixture for {}",
+                    operation
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_fixtures_match_the_queries() {
+        for (scenario, name, json) in FIXTURES {
+            let result = match *name {
+                "AccountActivityQuery" => parse_graphql::<AccountActivityQuery>(scenario, name),
+                "AccountsQuery" => parse_graphql::<AccountsQuery>(scenario, name),
+                "BlockProducersStatQuery" => {
+                    parse_graphql::<BlockProducersStatQuery>(scenario, name)
+                }
+                "BlocksQuery" => parse_graphql::<BlocksQuery>(scenario, name),
+                "EpochQuery" => parse_graphql::<EpochQuery>(scenario, name),
+                "EpochSummaryQuery" => parse_graphql::<EpochSummaryQuery>(scenario, name),
+                "InternalCommandsQuery" => parse_graphql::<InternalCommandsQuery>(scenario, name),
+                "LedgerHashQuery" => parse_graphql::<LedgerHashQuery>(scenario, name),
+                "SnarkFeesQuery" => parse_graphql::<SnarkFeesQuery>(scenario, name),
+                "SnarksQuery" => parse_graphql::<SnarksQuery>(scenario, name),
+                "StakingLedgersQuery" => parse_graphql::<StakingLedgersQuery>(scenario, name),
+                "TokenHoldersQuery" => parse_graphql::<TokenHoldersQuery>(scenario, name),
+                "TokenSymbolQuery" => parse_graphql::<TokenSymbolQuery>(scenario, name),
+                "TokensQuery" => parse_graphql::<TokensQuery>(scenario, name),
+                "TopSnarkersQuery" => parse_graphql::<TopSnarkersQuery>(scenario, name),
+                "TopStakersQuery" => parse_graphql::<TopStakersQuery>(scenario, name),
+                "TransactionsQuery" => parse_graphql::<TransactionsQuery>(scenario, name),
+                "summary" => parse_rest::<BlockchainSummary>(json),
+                "pooledUserCommands" => parse_rest::<PooledUserCommandsResponse>(json),
+                "broadcast" => parse_rest::<serde_json::Value>(json),
+                other => Err(format!("unknown fixture {}", other)),
+            };
+            assert_eq!(result, Ok(()), "{}/{}", scenario, name);
+        }
+    }
+
+    #[test]
+    fn test_scenario_falls_back_to_default() {
+        assert_eq!(
+            fixture("zkapps", "SnarksQuery"),
+            fixture(DEFAULT_SCENARIO, "SnarksQuery")
+        );
+        assert_ne!(
+            fixture("zkapps", "TransactionsQuery"),
+            fixture(DEFAULT_SCENARIO, "TransactionsQuery")
+        );
+        assert!(fixture("no-such-scenario", "BlocksQuery").is_some());
+    }
+
+    #[test]
+    fn test_rest_routes() {
+        assert!(rest_response_for(DEFAULT_SCENARIO, "https://api.minasearch.com/summary").is_ok());
+        assert!(
+            rest_response_for(
+                DEFAULT_SCENARIO,
+                "https://proxy.minaexplorer.com/graphql?query={pooledUserCommands{id}}"
+            )
+            .is_ok()
+        );
+        assert!(
+            rest_response_for(
+                DEFAULT_SCENARIO,
+                &format!("{}/broadcast/transaction", MINA_EXPLORER_ENDPOINT)
+            )
+            .is_ok()
+        );
+        assert!(matches!(
+            rest_response_for(DEFAULT_SCENARIO, "https://example.com/config.json"),
+            Err(MyError::HttpError { status: 404, .. })
+        ));
+    }
+}
@@ -1,7 +1,25 @@
+pub mod address_book;
+pub mod amount;
+pub mod base58;
+pub mod cache;
+pub mod client;
 pub mod components;
+pub mod config;
 pub mod constants;
+pub mod datetime;
 pub mod functions;
+pub mod i18n;
+pub mod live;
+pub mod memo;
+#[cfg(any(feature = "mock", test))]
+pub mod mock;
 pub mod models;
+pub mod network;
+pub mod pagination;
+pub mod preferences;
+pub mod presets;
 pub mod search;
+pub mod shortcuts;
+pub mod social;
 pub mod spotlight;
 pub mod table;
@@ -1,71 +1,85 @@
+use super::{amount::Amount, base58::*};
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use std::{fmt, str::FromStr};
 
-#[derive(Debug, Clone, Serialize, PartialEq, Eq, PartialOrd, Ord)]
-pub struct PublicKey(String);
+/// Defines a newtype for a Base58Check encoded identifier. Only strings that
+/// decode with a valid checksum and one of the given version bytes are
+/// accepted.
+macro_rules! base58_identifier {
+    ($name:ident, $description:literal, [$($version:expr),+]) => {
+        #[derive(Debug, Clone, Serialize, PartialEq, Eq, PartialOrd, Ord)]
+        pub struct $name(String);
+
+        impl $name {
+            pub fn new(id: impl Into<String>) -> Result<Self, String> {
+                let id = id.into();
+                match decode_check(&id, &[$($version),+]) {
+                    Ok(_) => Ok(Self(id)),
+                    Err(err) => Err(format!("Invalid {}: {}", $description, err)),
+                }
+            }
 
-impl PublicKey {
-    pub const LEN: usize = 55;
-    pub const PREFIX: &'static str = "B62q";
+            #[allow(dead_code)]
+            pub fn validate(id: &str) -> Result<(), String> {
+                Self::new(id).map(|_| ())
+            }
 
-    pub fn new(pk: impl Into<String>) -> Result<Self, String> {
-        let pk = pk.into();
-        if Self::is_valid(&pk) {
-            Ok(Self(pk))
-        } else {
-            Err(format!("Invalid public key: {}", pk))
+            pub fn as_str(&self) -> &str {
+                &self.0
+            }
         }
-    }
 
-    fn is_valid(pk: &str) -> bool {
-        pk.starts_with(Self::PREFIX) && pk.len() == Self::LEN
-    }
+        impl FromStr for $name {
+            type Err = String;
 
-    pub fn as_str(&self) -> &str {
-        &self.0
-    }
-}
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                Self::new(s)
+            }
+        }
 
-impl FromStr for PublicKey {
-    type Err = String;
+        impl TryFrom<String> for $name {
+            type Error = String;
 
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Self::new(s)
-    }
-}
+            fn try_from(id: String) -> Result<Self, Self::Error> {
+                Self::new(id)
+            }
+        }
 
-impl fmt::Display for PublicKey {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}", self.0)
-    }
-}
+        impl TryFrom<&str> for $name {
+            type Error = String;
 
-impl AsRef<str> for PublicKey {
-    fn as_ref(&self) -> &str {
-        self.as_str()
-    }
-}
+            fn try_from(id: &str) -> Result<Self, Self::Error> {
+                Self::new(id)
+            }
+        }
 
-impl From<String> for PublicKey {
-    fn from(val: String) -> Self {
-        let expectation = format!(
-            "String must be a valid public key ({} chars, starts with '{}')",
-            PublicKey::LEN,
-            PublicKey::PREFIX
-        );
-        PublicKey::new(val).expect(&expectation)
-    }
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "{}", self.0)
+            }
+        }
+
+        impl AsRef<str> for $name {
+            fn as_ref(&self) -> &str {
+                self.as_str()
+            }
+        }
+    };
 }
 
-impl From<&str> for PublicKey {
-    fn from(val: &str) -> Self {
-        let expectation = format!(
-            "String must be a valid public key ({} chars, starts with '{}')",
-            PublicKey::LEN,
-            PublicKey::PREFIX
-        );
-        PublicKey::new(val).expect(&expectation)
-    }
+base58_identifier!(PublicKey, "public key", [PUBLIC_KEY_VERSION]);
+base58_identifier!(StateHash, "state hash", [STATE_HASH_VERSION]);
+base58_identifier!(
+    TxnHash,
+    "transaction hash",
+    [TXN_HASH_VERSION, TXN_HASH_V1_VERSION]
+);
+base58_identifier!(LedgerHash, "ledger hash", [LEDGER_HASH_VERSION]);
+base58_identifier!(TokenId, "token ID", [TOKEN_ID_VERSION]);
+
+impl PublicKey {
+    pub const PREFIX: &'static str = "B62q";
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
@@ -107,16 +121,186 @@ pub enum ButtonStyleVariant {
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub enum MyError {
+    /// The request never produced a response (offline, DNS, CORS, ...).
     NetworkError(String),
-    ParseError(String), // other error variants
+    /// The server answered with a non-success status.
+    HttpError {
+        status: u16,
+        message: String,
+    },
+    /// The response could not be decoded.
+    ParseError(String),
+    /// The request was rejected before being sent.
+    InvalidInput(String),
     GraphQLError(Vec<graphql_client::Error>),
     GraphQLEmpty(String),
     UrlParseError(String),
 }
 
+/// How an error should be presented to the user.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ErrorKind {
+    NotFound,
+    Unavailable,
+    BadInput,
+    Unexpected,
+}
+
+impl MyError {
+    /// Whether sending the same request again could succeed.
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            MyError::NetworkError(_) => true,
+            MyError::HttpError { status, .. } => matches!(status, 408 | 429 | 500..),
+            _ => false,
+        }
+    }
+
+    pub fn kind(&self) -> ErrorKind {
+        match self {
+            MyError::GraphQLEmpty(_) | MyError::HttpError { status: 404, .. } => {
+                ErrorKind::NotFound
+            }
+            MyError::InvalidInput(_) | MyError::UrlParseError(_) => ErrorKind::BadInput,
+            MyError::HttpError {
+                status: 400..=499, ..
+            } if !self.is_retryable() => ErrorKind::BadInput,
+            _ if self.is_retryable() => ErrorKind::Unavailable,
+            _ => ErrorKind::Unexpected,
+        }
+    }
+
+    /// The response paths (e.g. `blocks.0.creator`) the GraphQL errors point at.
+    pub fn graphql_paths(&self) -> Vec<String> {
+        match self {
+            MyError::GraphQLError(errors) => errors
+                .iter()
+                .filter_map(|error| error.path.as_ref())
+                .map(|path| {
+                    path.iter()
+                        .map(|fragment| fragment.to_string())
+                        .collect::<Vec<_>>()
+                        .join(".")
+                })
+                .collect(),
+            _ => vec![],
+        }
+    }
+}
+
+impl fmt::Display for MyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MyError::NetworkError(message)
+            | MyError::ParseError(message)
+            | MyError::InvalidInput(message)
+            | MyError::GraphQLEmpty(message)
+            | MyError::UrlParseError(message) => write!(f, "{}", message),
+            MyError::HttpError { status, message } => write!(f, "HTTP {}: {}", status, message),
+            MyError::GraphQLError(errors) => write!(
+                f,
+                "{}",
+                errors
+                    .iter()
+                    .map(|error| error.message.to_string())
+                    .collect::<Vec<_>>()
+                    .join("; ")
+            ),
+        }
+    }
+}
+
 impl From<reqwest::Error> for MyError {
     fn from(err: reqwest::Error) -> Self {
-        MyError::NetworkError(err.to_string())
+        match err.status() {
+            Some(status) => MyError::HttpError {
+                status: status.as_u16(),
+                message: err.to_string(),
+            },
+            None => MyError::NetworkError(err.to_string()),
+        }
+    }
+}
+
+/// Inclusive bounds set through a range column's `-gte` and `-lte` params.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Range<T> {
+    pub gte: Option<T>,
+    pub lte: Option<T>,
+}
+
+impl<T> Range<T> {
+    pub fn is_set(&self) -> bool {
+        self.gte.is_some() || self.lte.is_some()
+    }
+}
+
+/// The range filters of a table. Each loader applies those its query supports.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct RangeFilters {
+    pub date_time: Range<DateTime<Utc>>,
+    pub amount: Range<Amount>,
+    pub fee: Range<Amount>,
+}
+
+impl RangeFilters {
+    pub fn is_set(&self) -> bool {
+        self.date_time.is_set() || self.amount.is_set() || self.fee.is_set()
+    }
+}
+
+#[cfg(test)]
+mod my_error_tests {
+    use super::*;
+    use graphql_client::PathFragment;
+
+    fn http(status: u16) -> MyError {
+        MyError::HttpError {
+            status,
+            message: String::new(),
+        }
+    }
+
+    #[test]
+    fn test_retryable() {
+        assert!(MyError::NetworkError("offline".into()).is_retryable());
+        assert!(http(503).is_retryable());
+        assert!(http(429).is_retryable());
+        assert!(!http(404).is_retryable());
+        assert!(!MyError::ParseError("bad json".into()).is_retryable());
+    }
+
+    #[test]
+    fn test_kind() {
+        assert_eq!(http(404).kind(), ErrorKind::NotFound);
+        assert_eq!(
+            MyError::GraphQLEmpty("none".into()).kind(),
+            ErrorKind::NotFound
+        );
+        assert_eq!(http(400).kind(), ErrorKind::BadInput);
+        assert_eq!(
+            MyError::InvalidInput("epoch".into()).kind(),
+            ErrorKind::BadInput
+        );
+        assert_eq!(http(502).kind(), ErrorKind::Unavailable);
+        assert_eq!(http(429).kind(), ErrorKind::Unavailable);
+        assert_eq!(MyError::GraphQLError(vec![]).kind(), ErrorKind::Unexpected);
+    }
+
+    #[test]
+    fn test_graphql_paths() {
+        let error = MyError::GraphQLError(vec![graphql_client::Error {
+            message: "boom".to_string(),
+            locations: None,
+            path: Some(vec![
+                PathFragment::Key("blocks".to_string()),
+                PathFragment::Index(0),
+                PathFragment::Key("creator".to_string()),
+            ]),
+            extensions: None,
+        }]);
+        assert_eq!(error.graphql_paths(), vec!["blocks.0.creator".to_string()]);
+        assert_eq!(error.to_string(), "boom");
     }
 }
 
@@ -232,10 +416,8 @@ pub enum NavIcon {
     More,
     Delegates,
     Leaderboard,
-}
-
-pub trait HasBlockHeight {
-    fn block_height(&self) -> Option<i64>;
+    Watchlist,
+    AddressBook,
 }
 
 #[derive(Clone, Default, Debug, PartialEq)]
@@ -0,0 +1,212 @@
+use super::constants::*;
+use leptos::*;
+use serde::{Deserialize, Serialize};
+
+pub const MAINNET_NETWORK_ID: &str = "mainnet";
+pub const DEVNET_NETWORK_ID: &str = "devnet";
+pub const NETWORK_STORAGE_KEY: &str = "network";
+
+const MAINNET_STATE_HASH: &str = "3NKeMoncuHab5ScarV5ViyF16cJPT4taWNSaTLS64Dp67wuXigPZ";
+const HARDFORK_STATE_HASH: &str = "3NK4BpDSekaqsG6tx8Nse2zJchRft2JpnbvMiog55WCr5xJZaKeP";
+const MAINNET_2_CHAIN_ID: &str = "a7351a";
+const MAINNET_1_CHAIN_ID: &str = "5f704c";
+const DEVNET_STATE_HASH: &str = "3NL93SipJfAMNDBRfQ8Uo8LPovC74mnJZfZYB5SK7mTtkL72dsPx";
+const DEVNET_CHAIN_ID: &str = "299361";
+
+fn default_mina_token_address() -> String {
+    "wSHV2S4qX9jFsLjQo8r1BsMLH2ZRKsZx6EJd1sbozGPieEC4Jf".to_string()
+}
+
+fn default_epoch_slots() -> u16 {
+    7140
+}
+
+fn default_finality_depth() -> u64 {
+    290
+}
+
+/// The chain a network ran on before its last hardfork. Its data stays
+/// queryable, so pages that browse by epoch need to know where it ended.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+pub struct PreviousChain {
+    pub chain_id: String,
+    pub genesis_state_hash: String,
+    pub last_epoch: u64,
+}
+
+/// A Mina network the explorer can be pointed at, along with the chain facts
+/// that used to be hardcoded for mainnet.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+pub struct Network {
+    pub id: String,
+    pub name: String,
+    pub graphql_endpoint: String,
+    pub rest_endpoint: String,
+    pub chain_id: String,
+    pub genesis_state_hash: String,
+    #[serde(default)]
+    pub previous_chain: Option<PreviousChain>,
+    /// WebSocket (`ws://`, `wss://`) or server-sent events URL announcing new
+    /// blocks. Without one the explorer polls for them.
+    #[serde(default)]
+    pub live_endpoint: Option<String>,
+    /// ID of the native MINA token, which account pages are keyed by.
+    #[serde(default = "default_mina_token_address")]
+    pub mina_token_address: String,
+    #[serde(default = "default_epoch_slots")]
+    pub epoch_slots: u16,
+    /// Blocks buried this deep (k) are final.
+    #[serde(default = "default_finality_depth")]
+    pub finality_depth: u64,
+}
+
+impl Network {
+    pub fn mainnet(graphql_endpoint: &str, rest_endpoint: &str) -> Self {
+        Network {
+            id: MAINNET_NETWORK_ID.to_string(),
+            name: "Mainnet".to_string(),
+            graphql_endpoint: graphql_endpoint.to_string(),
+            rest_endpoint: rest_endpoint.to_string(),
+            chain_id: MAINNET_2_CHAIN_ID.to_string(),
+            genesis_state_hash: HARDFORK_STATE_HASH.to_string(),
+            previous_chain: Some(PreviousChain {
+                chain_id: MAINNET_1_CHAIN_ID.to_string(),
+                genesis_state_hash: MAINNET_STATE_HASH.to_string(),
+                last_epoch: 79,
+            }),
+            live_endpoint: None,
+            mina_token_address: default_mina_token_address(),
+            epoch_slots: default_epoch_slots(),
+            finality_depth: default_finality_depth(),
+        }
+    }
+
+    pub fn devnet(graphql_endpoint: &str, rest_endpoint: &str) -> Self {
+        Network {
+            id: DEVNET_NETWORK_ID.to_string(),
+            name: "Devnet".to_string(),
+            chain_id: DEVNET_CHAIN_ID.to_string(),
+            genesis_state_hash: DEVNET_STATE_HASH.to_string(),
+            previous_chain: None,
+            ..Network::mainnet(graphql_endpoint, rest_endpoint)
+        }
+    }
+
+    /// Namespaces a local storage key so cached data from one network is never
+    /// shown while browsing another.
+    pub fn storage_key(&self, key: &str) -> String {
+        format!("{}-{}", key, self.id)
+    }
+
+    pub fn chain_id(&self, post_fork: bool) -> String {
+        match (&self.previous_chain, post_fork) {
+            (Some(previous), false) => previous.chain_id.to_string(),
+            _ => self.chain_id.to_string(),
+        }
+    }
+
+    pub fn genesis_state_hash(&self, post_fork: bool) -> String {
+        match (&self.previous_chain, post_fork) {
+            (Some(previous), false) => previous.genesis_state_hash.to_string(),
+            _ => self.genesis_state_hash.to_string(),
+        }
+    }
+}
+
+/// Picks the network named in the `network` query param of `search`
+/// (e.g. `?network=devnet`), if any.
+pub fn network_from_search(search: &str) -> Option<String> {
+    search
+        .trim_start_matches('?')
+        .split('&')
+        .filter_map(|pair| pair.split_once('='))
+        .find(|(key, _)| *key == QUERY_PARAM_NETWORK)
+        .map(|(_, value)| value.to_string())
+        .filter(|value| !value.is_empty())
+}
+
+/// `search` with its `network` query param set to `id`, keeping every other
+/// param as it was.
+pub fn search_with_network(search: &str, id: &str) -> String {
+    let mut params = search
+        .trim_start_matches('?')
+        .split('&')
+        .filter(|pair| !pair.is_empty() && pair.split('=').next() != Some(QUERY_PARAM_NETWORK))
+        .map(str::to_string)
+        .collect::<Vec<_>>();
+    params.push(format!("{}={}", QUERY_PARAM_NETWORK, id));
+    format!("?{}", params.join("&"))
+}
+
+pub fn use_network() -> StoredValue<Network> {
+    use_context::<StoredValue<Network>>().expect("Expected there to be a network provided")
+}
+
+pub fn use_network_storage_key(key: &str) -> String {
+    use_network().with_value(|network| network.storage_key(key))
+}
+
+pub fn use_mina_token_address() -> String {
+    use_network().with_value(|network| network.mina_token_address.clone())
+}
+
+#[cfg(test)]
+mod network_tests {
+    use super::*;
+
+    #[test]
+    fn test_network_from_search() {
+        assert_eq!(network_from_search(""), None);
+        assert_eq!(network_from_search("?network="), None);
+        assert_eq!(
+            network_from_search("?network=devnet"),
+            Some("devnet".to_string())
+        );
+        assert_eq!(
+            network_from_search("?epoch=3&network=testnet&post-fork=true"),
+            Some("testnet".to_string())
+        );
+    }
+
+    #[test]
+    fn test_search_with_network() {
+        assert_eq!(search_with_network("", "devnet"), "?network=devnet");
+        assert_eq!(
+            search_with_network("?network=mainnet", "devnet"),
+            "?network=devnet"
+        );
+        assert_eq!(
+            search_with_network("?q-status=applied&network=mainnet&row-limit=50", "devnet"),
+            "?q-status=applied&row-limit=50&network=devnet"
+        );
+    }
+
+    #[test]
+    fn test_mainnet_chains() {
+        let mainnet = Network::mainnet(GRAPHQL_ENDPOINT, REST_ENDPOINT);
+        assert_eq!(mainnet.chain_id(true), MAINNET_2_CHAIN_ID);
+        assert_eq!(mainnet.chain_id(false), MAINNET_1_CHAIN_ID);
+        assert_eq!(mainnet.genesis_state_hash(true), HARDFORK_STATE_HASH);
+        assert_eq!(mainnet.genesis_state_hash(false), MAINNET_STATE_HASH);
+    }
+
+    #[test]
+    fn test_devnet_chain() {
+        let devnet = Network::devnet(DEVNET_GRAPHQL_ENDPOINT, DEVNET_REST_ENDPOINT);
+        assert_eq!(devnet.chain_id(false), DEVNET_CHAIN_ID);
+        assert_eq!(devnet.genesis_state_hash(false), DEVNET_STATE_HASH);
+        assert_eq!(
+            devnet.mina_token_address,
+            Network::mainnet(GRAPHQL_ENDPOINT, REST_ENDPOINT).mina_token_address
+        );
+    }
+
+    #[test]
+    fn test_storage_key() {
+        let mainnet = Network::mainnet(GRAPHQL_ENDPOINT, REST_ENDPOINT);
+        assert_eq!(
+            mainnet.storage_key(BLOCKCHAIN_SUMMARY_STORAGE_KEY),
+            "blockchain-summary-mainnet"
+        );
+    }
+}
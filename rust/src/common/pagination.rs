@@ -0,0 +1,175 @@
+use super::models::MyError;
+use std::{collections::HashMap, future::Future};
+
+/// A row of a table that is paged through with "Load Next".
+///
+/// Pages are requested with an inclusive upper bound on `cursor` (block
+/// height, balance, stake, ...), so the rows sharing the last cursor value
+/// come back at the top of the next page. `key` tells those rows apart from
+/// the new ones.
+pub trait Paginated {
+    type Cursor: Clone;
+
+    fn cursor(&self) -> Option<Self::Cursor>;
+
+    /// Identifies the row among those with the same cursor.
+    fn key(&self) -> Option<String>;
+}
+
+/// The cursor to request the page after `rows` with.
+pub fn next_cursor<T: Paginated>(rows: &[Option<T>]) -> Option<T::Cursor> {
+    rows.iter().rev().flatten().find_map(|row| row.cursor())
+}
+
+/// Appends the rows of `page` that are not already in `current`, returning
+/// the merged rows and how many were added. Keys are matched as a multiset,
+/// so rows that are genuinely identical are each kept once.
+pub fn append_page<T: Paginated>(
+    mut current: Vec<Option<T>>,
+    page: Vec<Option<T>>,
+) -> (Vec<Option<T>>, usize) {
+    let mut seen: HashMap<String, usize> = HashMap::new();
+    for key in current.iter().flatten().filter_map(|row| row.key()) {
+        *seen.entry(key).or_default() += 1;
+    }
+    let mut added = 0;
+    for row in page.into_iter().flatten() {
+        let count = row.key().and_then(|key| seen.get_mut(&key));
+        if let Some(count) = count.filter(|count| **count > 0) {
+            *count -= 1;
+            continue;
+        }
+        current.push(Some(row));
+        added += 1;
+    }
+    (current, added)
+}
+
+/// Fetches the page after `rows`. When every row returned was already shown
+/// (more rows share the last cursor than fit on a page) the limit is doubled
+/// until new rows turn up or the results run out. Returns the merged rows and
+/// whether there may be more to load.
+pub async fn load_next_page<T, F, Fut>(
+    rows: Vec<Option<T>>,
+    row_limit: u64,
+    fetch: F,
+) -> Result<(Vec<Option<T>>, bool), MyError>
+where
+    T: Paginated,
+    F: Fn(Option<T::Cursor>, u64) -> Fut,
+    Fut: Future<Output = Result<Vec<Option<T>>, MyError>>,
+{
+    let cursor = next_cursor(&rows);
+    let mut limit = row_limit.max(1);
+    let mut rows = rows;
+    loop {
+        let page = fetch(cursor.clone(), limit).await?;
+        let is_full = page.len() as u64 >= limit;
+        let (merged, added) = append_page(rows, page);
+        if added > 0 || !is_full {
+            return Ok((merged, is_full));
+        }
+        rows = merged;
+        limit *= 2;
+    }
+}
+
+#[cfg(test)]
+mod pagination_tests {
+    use super::*;
+    use futures::executor::block_on;
+
+    #[derive(Clone, Debug, PartialEq)]
+    struct Row(u64, &'static str);
+
+    impl Paginated for Row {
+        type Cursor = u64;
+
+        fn cursor(&self) -> Option<u64> {
+            Some(self.0)
+        }
+
+        fn key(&self) -> Option<String> {
+            Some(self.1.to_string())
+        }
+    }
+
+    type Page = Result<Vec<Option<Row>>, MyError>;
+
+    fn rows(rows: &[(u64, &'static str)]) -> Vec<Option<Row>> {
+        rows.iter().map(|(c, k)| Some(Row(*c, k))).collect()
+    }
+
+    /// Serves `all` (sorted by descending cursor) the way the indexer does.
+    fn fetch_from(all: Vec<Option<Row>>) -> impl Fn(Option<u64>, u64) -> std::future::Ready<Page> {
+        move |cursor, limit| {
+            std::future::ready(Ok(all
+                .iter()
+                .filter(|row| cursor.is_none_or(|c| row.as_ref().is_some_and(|r| r.0 <= c)))
+                .take(limit as usize)
+                .cloned()
+                .collect()))
+        }
+    }
+
+    #[test]
+    fn test_next_cursor() {
+        assert_eq!(next_cursor(&rows(&[(5, "a"), (4, "b")])), Some(4));
+        assert_eq!(next_cursor(&[Some(Row(5, "a")), None]), Some(5));
+        assert_eq!(next_cursor::<Row>(&[]), None);
+    }
+
+    #[test]
+    fn test_append_page_skips_seen_rows() {
+        let (merged, added) = append_page(
+            rows(&[(5, "a"), (4, "b")]),
+            rows(&[(4, "b"), (4, "c"), (3, "d")]),
+        );
+        assert_eq!(added, 2);
+        assert_eq!(merged, rows(&[(5, "a"), (4, "b"), (4, "c"), (3, "d")]));
+    }
+
+    #[test]
+    fn test_append_page_keeps_identical_rows() {
+        let (merged, added) = append_page(rows(&[(4, "a")]), rows(&[(4, "a"), (4, "a")]));
+        assert_eq!(added, 1);
+        assert_eq!(merged, rows(&[(4, "a"), (4, "a")]));
+    }
+
+    #[test]
+    fn test_load_next_page() {
+        let all = rows(&[(5, "a"), (4, "b"), (4, "c"), (3, "d"), (2, "e")]);
+        let (merged, more) = block_on(load_next_page(
+            rows(&[(5, "a"), (4, "b")]),
+            2,
+            fetch_from(all),
+        ))
+        .unwrap();
+        assert!(more);
+        assert_eq!(merged, rows(&[(5, "a"), (4, "b"), (4, "c")]));
+    }
+
+    #[test]
+    fn test_load_next_page_with_ties_beyond_limit() {
+        let all = rows(&[(4, "a"), (4, "b"), (4, "c"), (4, "d"), (3, "e")]);
+        let (merged, _) = block_on(load_next_page(
+            rows(&[(4, "a"), (4, "b")]),
+            2,
+            fetch_from(all.clone()),
+        ))
+        .unwrap();
+        assert_eq!(merged, rows(&[(4, "a"), (4, "b"), (4, "c"), (4, "d")]));
+        let (merged, more) = block_on(load_next_page(merged, 2, fetch_from(all.clone()))).unwrap();
+        assert!(!more);
+        assert_eq!(merged, all);
+    }
+
+    #[test]
+    fn test_load_next_page_exhausted() {
+        let all = rows(&[(5, "a"), (4, "b")]);
+        let (merged, more) =
+            block_on(load_next_page(all.clone(), 2, fetch_from(all.clone()))).unwrap();
+        assert!(!more);
+        assert_eq!(merged, all);
+    }
+}
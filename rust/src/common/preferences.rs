@@ -0,0 +1,88 @@
+//! Preferences that decide how a page renders, such as its locale, are kept
+//! in local storage and mirrored into cookies of the same name. The `ssr`
+//! server reads them from the request so that it renders the page the way
+//! the browser will hydrate it.
+
+#[cfg(any(test, feature = "ssr"))]
+use super::{constants::LOCALE_STORAGE_KEY, i18n::match_locale, network::NETWORK_STORAGE_KEY};
+
+/// The preferences sent with the request the server is rendering.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct RequestPreferences {
+    /// The locale cookie, or else the language the browser asks for first.
+    pub locale: Option<&'static str>,
+    pub network: Option<String>,
+}
+
+#[cfg(any(test, feature = "ssr"))]
+impl RequestPreferences {
+    pub fn from_headers(cookie: Option<&str>, accept_language: Option<&str>) -> Self {
+        let cookie = |name| cookie.and_then(|header| cookie_value(header, name));
+        // Only the first language, as the browser falls back to English
+        // rather than to the next one it accepts.
+        let accepted = accept_language
+            .and_then(|header| header.split([',', ';']).next())
+            .and_then(|tag| match_locale(tag.trim()));
+        RequestPreferences {
+            locale: cookie(LOCALE_STORAGE_KEY)
+                .and_then(match_locale)
+                .or(accepted),
+            network: cookie(NETWORK_STORAGE_KEY)
+                .filter(|id| !id.is_empty())
+                .map(str::to_string),
+        }
+    }
+}
+
+#[cfg(any(test, feature = "ssr"))]
+fn cookie_value<'a>(header: &'a str, name: &str) -> Option<&'a str> {
+    header
+        .split(';')
+        .filter_map(|pair| pair.trim().split_once('='))
+        .find(|(key, _)| *key == name)
+        .map(|(_, value)| value)
+}
+
+/// Mirrors the preference saved under `name` into a cookie for the server.
+pub fn set_preference_cookie(name: &str, value: &str) {
+    use wasm_bindgen::JsCast;
+
+    let document = leptos::document().unchecked_into::<web_sys::HtmlDocument>();
+    let _ = document.set_cookie(&format!(
+        "{}={}; path=/; max-age=31536000; samesite=lax",
+        name, value
+    ));
+}
+
+#[cfg(test)]
+mod preferences_tests {
+    use super::*;
+
+    #[test]
+    fn test_from_headers() {
+        assert_eq!(
+            RequestPreferences::from_headers(None, None),
+            RequestPreferences::default()
+        );
+        assert_eq!(
+            RequestPreferences::from_headers(
+                Some("_ga=GA1.1; locale=pt; network=devnet"),
+                Some("es-MX,es;q=0.9")
+            ),
+            RequestPreferences {
+                locale: Some("pt"),
+                network: Some("devnet".to_string()),
+            }
+        );
+        // Unsupported picks fall back to the header, and the header to none
+        assert_eq!(
+            RequestPreferences::from_headers(Some("locale=xx; network="), Some("es-MX,es;q=0.9"))
+                .locale,
+            Some("es")
+        );
+        assert_eq!(
+            RequestPreferences::from_headers(None, Some("de-DE,es;q=0.9")).locale,
+            None
+        );
+    }
+}
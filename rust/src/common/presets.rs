@@ -0,0 +1,287 @@
+use super::{components::*, constants::*, i18n::t, models::ButtonStyleVariant};
+use crate::icons::CloseIcon;
+use codee::string::JsonSerdeCodec;
+use leptos::*;
+use leptos_router::*;
+use leptos_use::storage::use_local_storage;
+use serde::{Deserialize, Serialize};
+
+const MAX_RECENT_SEARCHES: usize = 20;
+
+/// A named set of a table's query params: column filters, sort, row limit
+/// and the like. Persisted in local storage per table.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct FilterPreset {
+    pub name: String,
+    pub params: Vec<(String, String)>,
+}
+
+impl FilterPreset {
+    pub fn new(name: &str, params: Vec<(String, String)>) -> Result<Self, String> {
+        let name = name.trim();
+        if name.is_empty() {
+            return Err("Missing preset name".to_string());
+        }
+        Ok(FilterPreset {
+            name: name.to_string(),
+            params,
+        })
+    }
+
+    pub fn to_params_map(&self) -> ParamsMap {
+        let mut map = ParamsMap::new();
+        for (key, value) in &self.params {
+            map.insert(key.clone(), value.clone());
+        }
+        map
+    }
+}
+
+/// The params of `map` a preset keeps, sorted by key so that presets compare
+/// equal regardless of the order the params were set in. The network is
+/// left to the page the preset is applied on.
+pub fn preset_params(map: &ParamsMap) -> Vec<(String, String)> {
+    let mut params = map
+        .0
+        .iter()
+        .filter(|(key, _)| {
+            key.as_str() != QUERY_PARAM_NETWORK && key.as_str() != QUERY_PARAM_PRESET
+        })
+        .map(|(key, value)| (key.clone(), value.clone()))
+        .collect::<Vec<_>>();
+    params.sort();
+    params
+}
+
+/// Replaces the preset of the same name, if any.
+pub fn upsert_preset(presets: &mut Vec<FilterPreset>, preset: FilterPreset) {
+    match presets
+        .iter_mut()
+        .find(|existing| existing.name == preset.name)
+    {
+        Some(existing) => *existing = preset,
+        None => presets.push(preset),
+    }
+}
+
+/// Moves `term` to the front, keeping at most `MAX_RECENT_SEARCHES`.
+pub fn push_recent_search(searches: &mut Vec<String>, term: &str) {
+    let term = term.trim();
+    if term.is_empty() {
+        return;
+    }
+    searches.retain(|existing| existing != term);
+    searches.insert(0, term.to_string());
+    searches.truncate(MAX_RECENT_SEARCHES);
+}
+
+/// Saves and applies named presets of the current page's query params.
+/// Links copied from here carry the preset's name, which is suggested when
+/// saving the preset on the receiving end. Presets are kept per `table_id`,
+/// the same id that keys the table's column layout.
+#[component]
+pub fn FilterPresets(table_id: String) -> impl IntoView {
+    let (presets, set_presets, _) = use_local_storage::<Vec<FilterPreset>, JsonSerdeCodec>(
+        format!("{}-{}", TABLE_PRESETS_STORAGE_KEY, table_id),
+    );
+    let query_map = use_query_map();
+    let pathname = use_location().pathname;
+    let navigate = use_navigate();
+    let (open, set_open) = create_signal(false);
+    let (name, set_name) = create_signal(String::new());
+    let (error, set_error) = create_signal(None::<String>);
+    let (copied, set_copied) = create_signal(None::<String>);
+    let current = Memo::new(move |_| query_map.with(preset_params));
+    let active = move || {
+        presets.with(|presets| {
+            presets
+                .iter()
+                .find(|preset| current.with(|params| preset.params == *params))
+                .map(|preset| preset.name.clone())
+        })
+    };
+
+    // Keeps the page's network so a preset doesn't switch networks.
+    let href = move |preset: &FilterPreset, shared: bool| {
+        let mut map = preset.to_params_map();
+        if let Some(network) = query_map.with_untracked(|map| map.get(QUERY_PARAM_NETWORK).cloned())
+        {
+            map.insert(QUERY_PARAM_NETWORK.to_string(), network);
+        }
+        if shared {
+            map.insert(QUERY_PARAM_PRESET.to_string(), preset.name.clone());
+        }
+        format!("{}{}", pathname.get_untracked(), map.to_query_string())
+    };
+    let apply = Callback::new(move |preset: FilterPreset| {
+        navigate(&href(&preset, false), Default::default());
+        set_open.set(false);
+    });
+    let copy_link = Callback::new(move |preset: FilterPreset| {
+        let origin = window().location().origin().unwrap_or_default();
+        let _ = window().navigator().clipboard().write_text(&format!(
+            "{}{}",
+            origin,
+            href(&preset, true)
+        ));
+        set_copied.set(Some(preset.name));
+    });
+    let save = move |_| match FilterPreset::new(&name.get_untracked(), current.get_untracked()) {
+        Ok(preset) => {
+            set_presets.update(|presets| upsert_preset(presets, preset));
+            set_name.set(String::new());
+            set_error.set(None);
+        }
+        Err(err) => set_error.set(Some(t(&err))),
+    };
+    let toggle = move |_| {
+        if !open.get_untracked() && name.get_untracked().is_empty() {
+            if let Some(shared) =
+                query_map.with_untracked(|map| map.get(QUERY_PARAM_PRESET).cloned())
+            {
+                set_name.set(shared);
+            }
+        }
+        set_copied.set(None);
+        set_open.update(|open| *open = !*open);
+    };
+
+    view! {
+        <div class="filter-presets relative flex items-center ml-2">
+            {move || {
+                view! {
+                    <Button
+                        style_variant=ButtonStyleVariant::Tertiary
+                        text=active().unwrap_or_else(|| t("Presets"))
+                        on_click=toggle
+                    />
+                }
+            }}
+            <Show when=move || open.get()>
+                <div class="fixed inset-0 z-10" on:click=move |_| set_open.set(false) />
+                <div class="absolute right-0 top-10 z-20 w-72 rounded-md bg-white shadow-lg p-3 flex flex-col gap-2 text-sm">
+                    {move || {
+                        let active = active();
+                        presets
+                            .get()
+                            .into_iter()
+                            .map(|preset| {
+                                let is_active = active.as_ref() == Some(&preset.name);
+                                let is_copied = copied.get().as_ref() == Some(&preset.name);
+                                let (to_apply, to_copy, to_remove) = (
+                                    preset.clone(),
+                                    preset.clone(),
+                                    preset.name.clone(),
+                                );
+                                view! {
+                                    <div class="flex justify-between items-center gap-2">
+                                        <button
+                                            class="grow truncate text-left hover:text-granola-orange"
+                                            class=("font-semibold", is_active)
+                                            on:click=move |_| apply.call(to_apply.clone())
+                                        >
+                                            {preset.name.clone()}
+                                        </button>
+                                        <button
+                                            class="text-xs text-granola-orange whitespace-nowrap"
+                                            on:click=move |_| copy_link.call(to_copy.clone())
+                                        >
+                                            {if is_copied { t("Copied") } else { t("Copy link") }}
+                                        </button>
+                                        <button
+                                            title=t("Remove")
+                                            on:click=move |_| {
+                                                set_presets
+                                                    .update(|presets| {
+                                                        presets.retain(|preset| preset.name != to_remove)
+                                                    })
+                                            }
+                                        >
+                                            <CloseIcon width=12 />
+                                        </button>
+                                    </div>
+                                }
+                            })
+                            .collect_view()
+                    }}
+                    <div class="flex items-center gap-2 pt-2 border-t border-slate-100">
+                        <input
+                            type="text"
+                            class="h-8 px-2 grow min-w-0 border border-[#DADCE0] rounded-md"
+                            placeholder=t("Preset name")
+                            prop:value=name
+                            on:input=move |ev| set_name.set(event_target_value(&ev))
+                        />
+                        <Button text=t("Save") on_click=save />
+                    </div>
+                    {move || error.get().map(|err| view! { <p class="text-red-600">{err}</p> })}
+                </div>
+            </Show>
+        </div>
+    }
+}
+
+#[cfg(test)]
+mod presets_tests {
+    use super::*;
+
+    fn params(pairs: &[(&str, &str)]) -> ParamsMap {
+        let mut map = ParamsMap::new();
+        for (key, value) in pairs {
+            map.insert(key.to_string(), value.to_string());
+        }
+        map
+    }
+
+    #[test]
+    fn test_preset_params() {
+        let map = params(&[
+            ("row-limit", "100"),
+            (QUERY_PARAM_NETWORK, "devnet"),
+            ("q-status", "Applied"),
+            (QUERY_PARAM_PRESET, "Daily"),
+        ]);
+        assert_eq!(
+            preset_params(&map),
+            vec![
+                ("q-status".to_string(), "Applied".to_string()),
+                ("row-limit".to_string(), "100".to_string()),
+            ]
+        );
+        assert_eq!(
+            preset_params(&params(&[("a", "1"), ("b", "2")])),
+            preset_params(&params(&[("b", "2"), ("a", "1")]))
+        );
+    }
+
+    #[test]
+    fn test_upsert_preset() {
+        assert!(FilterPreset::new("  ", vec![]).is_err());
+        let mut presets = vec![];
+        upsert_preset(&mut presets, FilterPreset::new(" Daily ", vec![]).unwrap());
+        upsert_preset(&mut presets, FilterPreset::new("Other", vec![]).unwrap());
+        let updated = FilterPreset::new("Daily", vec![("row-limit".into(), "50".into())]).unwrap();
+        upsert_preset(&mut presets, updated.clone());
+        assert_eq!(presets.len(), 2);
+        assert_eq!(presets[0], updated);
+        assert_eq!(
+            presets[0].to_params_map().get("row-limit"),
+            Some(&"50".to_string())
+        );
+    }
+
+    #[test]
+    fn test_push_recent_search() {
+        let mut searches = (0..MAX_RECENT_SEARCHES)
+            .map(|n| n.to_string())
+            .collect::<Vec<_>>();
+        push_recent_search(&mut searches, " 3 ");
+        assert_eq!(searches[0], "3");
+        assert_eq!(searches.len(), MAX_RECENT_SEARCHES);
+        push_recent_search(&mut searches, "new");
+        assert_eq!(searches[0], "new");
+        assert_eq!(searches.len(), MAX_RECENT_SEARCHES);
+        push_recent_search(&mut searches, "  ");
+        assert_eq!(searches[0], "new");
+    }
+}
@@ -1,172 +1,395 @@
-use super::models::MyError;
+use super::models::{LedgerHash, MyError, PublicKey, StateHash, TokenId, TxnHash};
 use crate::{
-    common::{components::*, constants::*},
+    accounts::graphql::{AccountsQuery, accounts_query},
+    blocks::graphql::{BlocksQuery, blocks_query},
+    common::{
+        client::query_graphql, components::*, constants::*, i18n::t, network::*,
+        presets::push_recent_search,
+    },
     icons::*,
+    stakes::graphql::{EpochQuery, LedgerHashQuery, epoch_query, ledger_hash_query},
 };
+use codee::string::JsonSerdeCodec;
 use leptos::*;
-use serde::*;
+use leptos_use::{DebounceOptions, storage::use_local_storage, use_debounce_fn_with_options};
 
-#[derive(Deserialize, Serialize, Debug, Clone)]
-#[serde(rename_all = "camelCase")]
-struct EpochData {
-    pub epoch: Option<u64>,
-    pub ledger_hash: Option<String>,
+/// Something the search bar can take the user to.
+#[derive(Clone, Debug, PartialEq)]
+pub enum SearchResult {
+    Account {
+        public_key: String,
+        username: Option<String>,
+    },
+    Block {
+        state_hash: String,
+        height: Option<i64>,
+        canonical: Option<bool>,
+    },
+    Transaction(String),
+    Epoch(u64),
+    Token(String),
 }
 
-#[derive(Deserialize, Serialize, Debug, Clone)]
-struct StakeData {
-    pub stakes: Vec<EpochData>,
+impl SearchResult {
+    pub fn group(&self) -> &'static str {
+        match self {
+            SearchResult::Account { .. } => "Accounts",
+            SearchResult::Block { .. } => "Blocks",
+            SearchResult::Transaction(_) => "Transactions",
+            SearchResult::Epoch(_) => "Staking Ledgers",
+            SearchResult::Token(_) => "Tokens",
+        }
+    }
+
+    pub fn href(&self, network: &Network) -> String {
+        match self {
+            SearchResult::Account { public_key, .. } => {
+                format!(
+                    "/addresses/accounts/{}/{}",
+                    network.mina_token_address, public_key
+                )
+            }
+            SearchResult::Block { state_hash, .. } => format!("/blocks/{}", state_hash),
+            SearchResult::Transaction(hash) => format!("/commands/{}", hash),
+            SearchResult::Epoch(epoch) => format!("/staking-ledgers?epoch={}", epoch),
+            SearchResult::Token(id) => format!("/tokens?{}={}", QUERY_PARAM_ID, id),
+        }
+    }
+
+    pub fn label(&self) -> String {
+        match self {
+            SearchResult::Account {
+                public_key,
+                username: Some(username),
+            } => format!("{} ({})", username, public_key),
+            SearchResult::Account { public_key, .. } => public_key.to_string(),
+            SearchResult::Block {
+                state_hash,
+                height: Some(height),
+                canonical,
+            } => format!(
+                "{} {}{}",
+                height,
+                state_hash,
+                if *canonical == Some(false) {
+                    " (non-canonical)"
+                } else {
+                    ""
+                }
+            ),
+            SearchResult::Block { state_hash, .. } => state_hash.to_string(),
+            SearchResult::Transaction(hash) => hash.to_string(),
+            SearchResult::Epoch(epoch) => format!("Epoch {}", epoch),
+            SearchResult::Token(id) => id.to_string(),
+        }
+    }
+}
+
+/// What a search term can refer to, judging by its shape alone.
+#[derive(Debug, PartialEq)]
+enum SearchTerm {
+    PublicKey(String),
+    StateHash(String),
+    TxnHash(String),
+    LedgerHash(String),
+    TokenId(String),
+    /// A block height or an epoch.
+    Number(u64),
+    /// Anything else is looked up as a username.
+    Text(String),
+    /// Looks like an identifier but doesn't decode as one.
+    Invalid(String),
+    Empty,
+}
+
+impl SearchTerm {
+    fn parse(value: &str) -> Self {
+        match value.trim() {
+            "" => SearchTerm::Empty,
+            val if val.starts_with(PublicKey::PREFIX) => {
+                Self::checked(PublicKey::new(val).map(|_| SearchTerm::PublicKey(val.to_string())))
+            }
+            val if val.starts_with("3N") => {
+                Self::checked(StateHash::new(val).map(|_| SearchTerm::StateHash(val.to_string())))
+            }
+            val if val.starts_with("5J") || val.starts_with("Ckp") => {
+                Self::checked(TxnHash::new(val).map(|_| SearchTerm::TxnHash(val.to_string())))
+            }
+            // Usernames may start with these too, so only the valid ones count.
+            val if LedgerHash::new(val).is_ok() => SearchTerm::LedgerHash(val.to_string()),
+            val if TokenId::new(val).is_ok() => SearchTerm::TokenId(val.to_string()),
+            val => match val.parse::<u64>() {
+                Ok(number) => SearchTerm::Number(number),
+                Err(_) => SearchTerm::Text(val.to_string()),
+            },
+        }
+    }
+
+    fn checked(term: Result<SearchTerm, String>) -> Self {
+        term.unwrap_or_else(SearchTerm::Invalid)
+    }
 }
 
-#[derive(Deserialize, Serialize, Debug, Clone)]
-struct EpochConversionResponse {
-    pub data: StakeData,
+/// Why `value` can't match anything, if it is a malformed identifier.
+pub fn search_error(value: &str) -> Option<String> {
+    match SearchTerm::parse(value) {
+        SearchTerm::Invalid(err) => Some(err),
+        _ => None,
+    }
 }
 
-async fn load_ledger_hash_data(epoch_opt: Option<u64>) -> Result<EpochConversionResponse, MyError> {
+async fn load_ledger_hash_data(
+    network: &Network,
+    epoch_opt: Option<u64>,
+) -> Result<ledger_hash_query::ResponseData, MyError> {
     match epoch_opt {
-        None => Err(MyError::ParseError("epoch not supplied".to_string())),
+        None => Err(MyError::InvalidInput("epoch not supplied".to_string())),
         Some(epoch) => {
-            let query_body = format!(
-                r#"{{"query":"query LedgerHashQuery($epoch: Int, $limit: Int) {{ stakes(query: {{epoch: $epoch}}, limit: $limit) {{ ledgerHash }}}}", "variables":{{"epoch":{}, "limit":{}}},"operationName":"LedgerHashQuery"}}"#,
-                epoch, 1
-            );
-            let client = reqwest::Client::new();
-            let response = client
-                .post(GRAPHQL_ENDPOINT)
-                .body(query_body)
-                .send()
-                .await
-                .map_err(|e| MyError::NetworkError(e.to_string()))?;
-
-            if response.status().is_success() {
-                let summary = response
-                    .json::<EpochConversionResponse>()
-                    .await
-                    .map_err(|e| MyError::ParseError(e.to_string()))?;
-                Ok(summary)
-            } else {
-                Err(MyError::NetworkError("Failed to fetch data".into()))
-            }
+            let variables = ledger_hash_query::Variables {
+                epoch: epoch as i64,
+            };
+            query_graphql::<LedgerHashQuery>(network, variables).await
         }
     }
 }
 
 async fn load_epoch_data(
+    network: &Network,
     ledger_hash_opt: Option<String>,
-) -> Result<EpochConversionResponse, MyError> {
+) -> Result<epoch_query::ResponseData, MyError> {
     match ledger_hash_opt {
-        None => Err(MyError::ParseError("ledger hash not supplied".to_string())),
+        None => Err(MyError::InvalidInput(
+            "ledger hash not supplied".to_string(),
+        )),
         Some(ledger_hash) => {
-            let query_body = format!(
-                r#"{{"query":"query EpochQuery($ledgerHash: String, $limit: Int) {{ stakes(query: {{ledgerHash: $ledgerHash}}, limit: $limit) {{ epoch }}}}", "variables":{{"ledgerHash":"{}", "limit":{}}},"operationName":"EpochQuery"}}"#,
-                ledger_hash, 0
+            let variables = epoch_query::Variables { ledger_hash };
+            query_graphql::<EpochQuery>(network, variables).await
+        }
+    }
+}
+
+async fn load_blocks_at_height(
+    network: &Network,
+    height: u64,
+) -> Result<blocks_query::ResponseData, MyError> {
+    let variables = blocks_query::Variables {
+        sort_by: blocks_query::BlockSortByInput::BLOCKHEIGHT_DESC,
+        limit: Some(SEARCH_RESULT_LIMIT),
+        query: blocks_query::BlockQueryInput {
+            block_height: Some(height as i64),
+            ..Default::default()
+        },
+    };
+    query_graphql::<BlocksQuery>(network, variables).await
+}
+
+async fn load_accounts_by_username(
+    network: &Network,
+    username: String,
+) -> Result<accounts_query::ResponseData, MyError> {
+    let variables = accounts_query::Variables {
+        limit: Some(SEARCH_RESULT_LIMIT),
+        sort_by: accounts_query::AccountSortByInput::BALANCE_DESC,
+        query: Some(accounts_query::AccountQueryInput {
+            username: Some(username),
+            token: Some(network.mina_token_address.clone()),
+            ..Default::default()
+        }),
+    };
+    query_graphql::<AccountsQuery>(network, variables).await
+}
+
+/// Results for `value`, grouped by `SearchResult::group`. Lookups that fail
+/// count as having found nothing.
+pub async fn search(network: &Network, value: &str) -> Vec<SearchResult> {
+    let results = match SearchTerm::parse(value) {
+        SearchTerm::Empty | SearchTerm::Invalid(_) => vec![],
+        SearchTerm::PublicKey(public_key) => vec![SearchResult::Account {
+            public_key,
+            username: None,
+        }],
+        SearchTerm::StateHash(state_hash) => vec![SearchResult::Block {
+            state_hash,
+            height: None,
+            canonical: None,
+        }],
+        SearchTerm::TxnHash(hash) => vec![SearchResult::Transaction(hash)],
+        SearchTerm::TokenId(id) => vec![SearchResult::Token(id)],
+        SearchTerm::LedgerHash(ledger_hash) => load_epoch_data(network, Some(ledger_hash))
+            .await
+            .map(|data| {
+                data.stakes
+                    .into_iter()
+                    .flatten()
+                    .filter_map(|stake| stake.epoch)
+                    .map(|epoch| SearchResult::Epoch(epoch as u64))
+                    .collect()
+            })
+            .unwrap_or_else(log_search_error),
+        SearchTerm::Number(number) => {
+            let (blocks, ledger) = futures::join!(
+                load_blocks_at_height(network, number),
+                load_ledger_hash_data(network, Some(number))
             );
-            let client = reqwest::Client::new();
-            let response = client
-                .post(GRAPHQL_ENDPOINT)
-                .body(query_body)
-                .send()
-                .await
-                .map_err(|e| MyError::NetworkError(e.to_string()))?;
-
-            if response.status().is_success() {
-                let summary = response
-                    .json::<EpochConversionResponse>()
-                    .await
-                    .map_err(|e| MyError::ParseError(e.to_string()))?;
-                Ok(summary)
-            } else {
-                Err(MyError::NetworkError("Failed to fetch data".into()))
-            }
+            let blocks = blocks
+                .map(|data| {
+                    data.blocks
+                        .into_iter()
+                        .flatten()
+                        .filter_map(|block| {
+                            block.state_hash.map(|state_hash| SearchResult::Block {
+                                state_hash,
+                                height: block.block_height,
+                                canonical: block.canonical,
+                            })
+                        })
+                        .collect()
+                })
+                .unwrap_or_else(log_search_error);
+            let epochs = ledger
+                .map(|data| {
+                    (!data.stakes.is_empty())
+                        .then_some(SearchResult::Epoch(number))
+                        .into_iter()
+                        .collect()
+                })
+                .unwrap_or_else(log_search_error);
+            [blocks, epochs].concat()
+        }
+        SearchTerm::Text(username) => load_accounts_by_username(network, username)
+            .await
+            .map(|data| {
+                data.accounts
+                    .into_iter()
+                    .flatten()
+                    .filter_map(|account| {
+                        account.public_key.map(|public_key| SearchResult::Account {
+                            public_key,
+                            username: account.username,
+                        })
+                    })
+                    .collect()
+            })
+            .unwrap_or_else(log_search_error),
+    };
+    group_results(results)
+}
+
+fn log_search_error(err: MyError) -> Vec<SearchResult> {
+    logging::log!("Search lookup failed: {}", err);
+    vec![]
+}
+
+/// Orders results so each group is contiguous, keeping the groups in the order
+/// they first appear.
+fn group_results(results: Vec<SearchResult>) -> Vec<SearchResult> {
+    let mut groups: Vec<&'static str> = vec![];
+    for result in &results {
+        if !groups.contains(&result.group()) {
+            groups.push(result.group());
         }
     }
+    groups
+        .into_iter()
+        .flat_map(|group| results.iter().filter(move |r| r.group() == group).cloned())
+        .collect()
 }
 
 #[component]
 pub fn GlobalSearchBar() -> impl IntoView {
     let input_element: NodeRef<html::Input> = create_node_ref();
     let (value, set_value) = create_signal("".to_string());
-    let (ledger_hash_sig, set_lh) = create_signal(None);
-    let (epoch_sig, set_e) = create_signal(None);
-    let ledger_resource = create_resource(
-        move || epoch_sig.get(),
-        move |_| async move { load_ledger_hash_data(epoch_sig.get_untracked()).await },
+    // The term results are shown for; follows `value` after a pause in typing.
+    let (term, set_term) = create_signal("".to_string());
+    let (is_open, set_open) = create_signal(false);
+    let (selected, set_selected) = create_signal(None::<usize>);
+    // Set on submit: go to the first result as soon as there is one.
+    let (go_to_first, set_go_to_first) = create_signal(false);
+    let network = use_network();
+    let (recent, set_recent, _) = use_local_storage::<Vec<String>, JsonSerdeCodec>(
+        use_network_storage_key(RECENT_SEARCHES_STORAGE_KEY),
     );
-    let epoch_resource = create_resource(
-        move || ledger_hash_sig.get(),
-        move |_| async move { load_epoch_data(ledger_hash_sig.get_untracked()).await },
+    let resource = create_local_resource(
+        move || term.get(),
+        move |term| async move { search(&network.get_value(), &term).await },
     );
+    let results = move || {
+        resource
+            .get()
+            .filter(|_| !resource.loading().get())
+            .unwrap_or_default()
+    };
 
     let navigate = leptos_router::use_navigate();
-    let navigate_clone = navigate.clone();
-    let navigate_clone_2 = navigate.clone();
-
-    create_effect(move |_| {
-        epoch_resource.get().and_then(|res| res.ok()).map(|resp| {
-            if let Some(stake) = resp.data.stakes.first() {
-                navigate_clone(
-                    &format!("/staking-ledgers?epoch={}", stake.epoch.unwrap_or_default()),
-                    Default::default(),
-                );
-                set_lh.set(None);
-                set_value.set("".to_string());
-            }
-        })
+    let go_to = Callback::new(move |result: SearchResult| {
+        navigate(&network.with_value(|n| result.href(n)), Default::default());
+        set_value.set("".to_string());
+        set_term.set("".to_string());
+        set_open.set(false);
+        set_selected.set(None);
+        set_go_to_first.set(false);
     });
 
-    create_effect(move |_| {
-        ledger_resource.get().and_then(|res| res.ok()).map(|resp| {
-            if !resp.data.stakes.is_empty() {
-                if let Some(epoch) = epoch_sig.get() {
-                    navigate_clone_2(
-                        &format!("/staking-ledgers?epoch={}", epoch),
-                        Default::default(),
-                    )
-                }
-            } else if let Some(epoch) = epoch_sig.get() {
-                navigate_clone_2(&format!("/blocks?q-height={}", epoch), Default::default())
-            }
+    let update_term = use_debounce_fn_with_options(
+        move || {
+            set_term.set(value.get_untracked().trim().to_string());
+            set_selected.set(None);
+        },
+        DEFAULT_USER_INPUT_DEBOUNCE_INTERNVAL,
+        DebounceOptions::default(),
+    );
 
-            set_e.set(None);
-            set_value.set("".to_string());
-        })
+    create_effect(move |_| {
+        if !go_to_first.get() || resource.loading().get() {
+            return;
+        }
+        if let Some(first) = resource
+            .get()
+            .and_then(|results| results.into_iter().next())
+        {
+            go_to.call(first);
+        } else {
+            set_go_to_first.set(false);
+        }
     });
 
     let on_submit = move |ev: leptos::ev::SubmitEvent| {
         ev.prevent_default();
-
         let trimmed_value = value.get().trim().to_string();
-        match trimmed_value {
-            val if val.starts_with("B62q") => {
-                navigate(
-                    &format!("/addresses/accounts/{}/{}", MINA_TOKEN_ADDRESS, val),
-                    Default::default(),
-                );
-                set_value.set("".to_string());
-            }
-            val if val.starts_with("3N") => {
-                navigate(&format!("/blocks/{}", val), Default::default());
-                set_value.set("".to_string());
-            }
-            val if val.starts_with("5J") | val.starts_with("Ckp") => {
-                navigate(&format!("/commands/{}", val), Default::default());
-                set_value.set("".to_string());
+        let chosen = (term.get_untracked() == trimmed_value)
+            .then(|| {
+                selected
+                    .get_untracked()
+                    .and_then(|i| results().get(i).cloned())
+            })
+            .flatten();
+        set_recent.update(|searches| push_recent_search(searches, &trimmed_value));
+        match chosen {
+            Some(result) => go_to.call(result),
+            None => {
+                set_term.set(trimmed_value);
+                set_open.set(true);
+                set_go_to_first.set(true);
             }
-            val if val.starts_with('j') => {
-                set_lh.set(Some(val));
+        }
+    };
+
+    let on_keydown = move |ev: leptos::ev::KeyboardEvent| {
+        let count = results().len();
+        match ev.key().as_str() {
+            "ArrowDown" if count > 0 => {
+                ev.prevent_default();
+                set_open.set(true);
+                set_selected.update(|s| *s = Some(s.map_or(0, |i| (i + 1) % count)));
             }
-            val if val.starts_with('w') || val.starts_with('x') || val.starts_with('y') => {
-                navigate(
-                    &format!("/tokens?{}={}", QUERY_PARAM_ID, val),
-                    Default::default(),
-                );
-                set_value.set("".to_string());
+            "ArrowUp" if count > 0 => {
+                ev.prevent_default();
+                set_selected
+                    .update(|s| *s = Some(s.map_or(count - 1, |i| (i + count - 1) % count)));
             }
-            val if val.chars().all(char::is_numeric) => {
-                if let Ok(epoch) = val.parse::<u64>() {
-                    set_e.set(Some(epoch));
-                }
+            "Escape" => {
+                set_open.set(false);
+                set_selected.set(None);
             }
             _ => {}
         }
@@ -179,12 +402,18 @@ pub fn GlobalSearchBar() -> impl IntoView {
                     <input
                         id="searchbar"
                         type="text"
+                        autocomplete="off"
                         on:input=move |ev| {
                             set_value.set(event_target_value(&ev));
+                            set_open.set(true);
+                            update_term();
                         }
+                        on:keydown=on_keydown
+                        on:focus=move |_| set_open.set(true)
+                        on:blur=move |_| set_open.set(false)
 
                         prop:value=value
-                        placeholder=GLOBAL_SEARCH_PLACEHOLDER_TEXT
+                        placeholder=t(GLOBAL_SEARCH_PLACEHOLDER_TEXT)
                         class="h-14 flex justify-start items-center text-base text-white pl-14 placeholder:text-slate-400 placeholder:font-medium placeholder:text-base focus:outline-none box-border w-full rounded-2xl bg-[#383B42]"
                         node_ref=input_element
                     />
@@ -192,7 +421,211 @@ pub fn GlobalSearchBar() -> impl IntoView {
                 <span class="text-white absolute top-0 left-0 translate-x-3/4 translate-y-3/4">
                     <SearchIcon width=22 />
                 </span>
+                <Show when=move || {
+                    is_open.get()
+                        && (!term.get().is_empty()
+                            || value.get().is_empty() && recent.with(|recent| !recent.is_empty()))
+                }>
+                    <div
+                        id="search-results"
+                        class="absolute top-full left-0 right-0 z-30 mt-1 max-h-96 overflow-y-auto rounded-xl bg-white shadow-lg text-sm"
+                        // keep focus in the input so clicking a result doesn't close the list first
+                        on:mousedown=|ev| ev.prevent_default()
+                    >
+                        {move || {
+                            if term.get().is_empty() {
+                                return view! {
+                                    <RecentSearches
+                                        recent
+                                        on_select=Callback::new(move |search: String| {
+                                            set_value.set(search.clone());
+                                            set_recent
+                                                .update(|searches| push_recent_search(searches, &search));
+                                            set_term.set(search);
+                                            set_go_to_first.set(true);
+                                        })
+                                        on_clear=Callback::new(move |_| set_recent.set(vec![]))
+                                    />
+                                }
+                                    .into_view();
+                            }
+                            if resource.loading().get() {
+                                return view! {
+                                    <div class="p-4 text-slate-500">{t("Searching...")}</div>
+                                }
+                                    .into_view();
+                            }
+                            let results = results();
+                            if results.is_empty() {
+                                return view! {
+                                    <div id="search-no-results" class="p-4 text-slate-500">
+                                        {search_error(&term.get())
+                                            .unwrap_or_else(|| {
+                                                t("No results for \"{term}\"")
+                                                    .replace("{term}", &term.get())
+                                            })}
+                                    </div>
+                                }
+                                    .into_view();
+                            }
+                            results
+                                .iter()
+                                .enumerate()
+                                .map(|(index, result)| {
+                                    let is_first_of_group = index == 0
+                                        || results[index - 1].group() != result.group();
+                                    let result_clone = result.clone();
+                                    view! {
+                                        {is_first_of_group
+                                            .then(|| {
+                                                view! {
+                                                    <div class="px-4 pt-3 pb-1 text-xs font-semibold uppercase text-slate-400">
+                                                        {t(result.group())}
+                                                    </div>
+                                                }
+                                            })}
+                                        <button
+                                            type="button"
+                                            class="block w-full truncate px-4 py-2 text-left font-mono hover:bg-slate-100"
+                                            class=("bg-slate-100", move || selected.get() == Some(index))
+                                            on:click=move |_| go_to.call(result_clone.clone())
+                                        >
+                                            {result.label()}
+                                        </button>
+                                    }
+                                })
+                                .collect_view()
+                        }}
+                    </div>
+                </Show>
             </div>
         </PreSectionContainer>
     }
 }
+
+#[component]
+fn RecentSearches(
+    recent: Signal<Vec<String>>,
+    on_select: Callback<String>,
+    on_clear: Callback<()>,
+) -> impl IntoView {
+    view! {
+        <div id="recent-searches">
+            <div class="flex justify-between items-center px-4 pt-3 pb-1 text-xs font-semibold uppercase text-slate-400">
+                {t("Recent searches")}
+                <button
+                    type="button"
+                    class="uppercase hover:text-slate-600"
+                    on:click=move |_| on_clear.call(())
+                >
+                    {t("Clear")}
+                </button>
+            </div>
+            {move || {
+                recent
+                    .get()
+                    .into_iter()
+                    .map(|search| {
+                        let selected = search.clone();
+                        view! {
+                            <button
+                                type="button"
+                                class="block w-full truncate px-4 py-2 text-left font-mono hover:bg-slate-100"
+                                on:click=move |_| on_select.call(selected.clone())
+                            >
+                                {search}
+                            </button>
+                        }
+                    })
+                    .collect_view()
+            }}
+        </div>
+    }
+}
+
+#[cfg(test)]
+mod search_tests {
+    use super::*;
+
+    const PUBLIC_KEY: &str = "B62qrPN5Y5yq8kGE3FbVKbGTdTAJNdtNtB5sNVpxyRwWGcDEhpMzc8g";
+    const TOKEN_ID: &str = "wSHV2S4qX9jFsLjQo8r1BsMLH2ZRKsZx6EJd1sbozGPieEC4Jf";
+    const STATE_HASH: &str = "3NKeMoncuHab5ScarV5ViyF16cJPT4taWNSaTLS64Dp67wuXigPZ";
+    const TXN_HASH: &str = "CkpYeG32dVJUjs6iq3oroXWitXar1eBtV3GVFyH5agw7HPp9bG4yQ";
+    const LEDGER_HASH: &str = "jxsAidvKvEQJMC7Z2wkLrFGzCqUxpFMRhAj4K5o49eiFLhKSyXL";
+
+    #[test]
+    fn test_parse_search_term() {
+        assert_eq!(SearchTerm::parse("  "), SearchTerm::Empty);
+        assert_eq!(
+            SearchTerm::parse(PUBLIC_KEY),
+            SearchTerm::PublicKey(PUBLIC_KEY.to_string())
+        );
+        assert_eq!(
+            SearchTerm::parse(STATE_HASH),
+            SearchTerm::StateHash(STATE_HASH.to_string())
+        );
+        assert_eq!(
+            SearchTerm::parse(TXN_HASH),
+            SearchTerm::TxnHash(TXN_HASH.to_string())
+        );
+        assert_eq!(
+            SearchTerm::parse(LEDGER_HASH),
+            SearchTerm::LedgerHash(LEDGER_HASH.to_string())
+        );
+        assert_eq!(
+            SearchTerm::parse(TOKEN_ID),
+            SearchTerm::TokenId(TOKEN_ID.to_string())
+        );
+        assert_eq!(SearchTerm::parse(" 42 "), SearchTerm::Number(42));
+        assert_eq!(
+            SearchTerm::parse("Granola"),
+            SearchTerm::Text("Granola".to_string())
+        );
+        assert_eq!(
+            SearchTerm::parse("whale"),
+            SearchTerm::Text("whale".to_string())
+        );
+    }
+
+    #[test]
+    fn test_search_error() {
+        assert_eq!(
+            search_error("B62qrPN5Y5"),
+            Some("Invalid public key: invalid checksum".to_string())
+        );
+        assert_eq!(
+            search_error(&STATE_HASH.replace("Monc", "Mond")),
+            Some("Invalid state hash: invalid checksum".to_string())
+        );
+        assert_eq!(search_error(PUBLIC_KEY), None);
+        assert_eq!(search_error("Granola"), None);
+    }
+
+    #[test]
+    fn test_group_results() {
+        let block = SearchResult::Block {
+            state_hash: "3NK".to_string(),
+            height: Some(42),
+            canonical: Some(true),
+        };
+        let results = group_results(vec![block.clone(), SearchResult::Epoch(42), block.clone()]);
+        assert_eq!(results, vec![block.clone(), block, SearchResult::Epoch(42)]);
+    }
+
+    #[test]
+    fn test_href() {
+        let mainnet = Network::mainnet(GRAPHQL_ENDPOINT, REST_ENDPOINT);
+        assert_eq!(
+            SearchResult::Epoch(42).href(&mainnet),
+            "/staking-ledgers?epoch=42".to_string()
+        );
+        assert_eq!(
+            SearchResult::Account {
+                public_key: PUBLIC_KEY.to_string(),
+                username: Some("Granola".to_string()),
+            }
+            .href(&mainnet),
+            format!("/addresses/accounts/{}/{}", TOKEN_ID, PUBLIC_KEY)
+        );
+    }
+}
@@ -0,0 +1,504 @@
+//! The keyboard layer: global shortcuts, the command palette and the `?` help
+//! overlay. Shortcuts are ignored while typing into a form field.
+use super::{
+    address_book::use_address_book,
+    constants::RECENT_ITEMS_STORAGE_KEY,
+    i18n::t,
+    network::{use_mina_token_address, use_network_storage_key},
+};
+use crate::watchlist::functions::use_watchlist;
+use codee::string::JsonSerdeCodec;
+use leptos::{html::Input, *};
+use leptos_router::{use_location, use_navigate};
+use leptos_use::storage::use_local_storage;
+use serde::{Deserialize, Serialize};
+use wasm_bindgen::JsCast;
+use web_sys::{Element, HtmlElement, KeyboardEvent};
+
+const MAX_RECENT_ITEMS: usize = 10;
+const MAX_PALETTE_RESULTS: usize = 20;
+const SELECTED_ROW_CLASSES: [&str; 3] = ["outline", "outline-2", "outline-granola-orange"];
+
+/// Pages offered by the command palette, in the order of the routes in `Root`.
+/// `{token}` stands for the MINA token address, see `palette_href`.
+const PALETTE_ROUTES: [(&str, &str); 16] = [
+    ("Blocks", "/blocks"),
+    ("User Commands", "/commands/user"),
+    ("Pending Commands", "/commands/pending"),
+    ("Internal Commands", "/commands/internal"),
+    ("Accounts", "/addresses/accounts/{token}"),
+    ("Tokens", "/tokens"),
+    ("Watchlist", "/watchlist"),
+    ("Address Book", "/address-book"),
+    ("SNARKs", "/snarks"),
+    ("Staking Ledgers", "/staking-ledgers"),
+    ("Send", "/broadcast/transaction"),
+    ("Analytics", "/analytics/blocks"),
+    ("User Commands Analytics", "/analytics/commands/user"),
+    ("SNARK Analytics", "/analytics/snarks"),
+    ("Staker Leaderboard", "/analytics/staker-leaderboard"),
+    ("SNARKer Leaderboard", "/analytics/snarker-leaderboard"),
+];
+
+/// Keys and what they do, as listed by the help overlay.
+pub const SHORTCUTS: [(&str, &str); 8] = [
+    ("/", "Focus the search bar"),
+    ("Ctrl K", "Open the command palette"),
+    ("j", "Select the next table row"),
+    ("k", "Select the previous table row"),
+    ("Enter", "Open the selected row"),
+    ("[", "Previous block"),
+    ("]", "Next block"),
+    ("?", "Show keyboard shortcuts"),
+];
+
+/// A page the user has looked at, offered again by the command palette.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct RecentItem {
+    pub label: String,
+    pub href: String,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PaletteItem {
+    pub label: String,
+    pub group: &'static str,
+    pub href: String,
+}
+
+fn short_id(id: &str) -> String {
+    let len = id.chars().count();
+    if len > 12 {
+        let head = id.chars().take(6).collect::<String>();
+        let tail = id.chars().skip(len - 4).collect::<String>();
+        format!("{}…{}", head, tail)
+    } else {
+        id.to_string()
+    }
+}
+
+fn palette_href(href: &str, mina_token_address: &str) -> String {
+    href.replace("{token}", mina_token_address)
+}
+
+/// The recent item for a block, command or account page.
+pub fn recent_item_for_path(path: &str) -> Option<RecentItem> {
+    let segments = path.trim_matches('/').split('/').collect::<Vec<_>>();
+    let (label, href) = match segments.as_slice() {
+        ["blocks", id, ..] => (
+            format!("Block {}", short_id(id)),
+            format!("/blocks/{}/spotlight", id),
+        ),
+        ["commands", id] if !["user", "internal", "pending"].contains(id) => (
+            format!("Command {}", short_id(id)),
+            format!("/commands/{}", id),
+        ),
+        ["addresses", "accounts", token, id, ..] => (
+            format!("Account {}", short_id(id)),
+            format!("/addresses/accounts/{}/{}/spotlight", token, id),
+        ),
+        _ => return None,
+    };
+    Some(RecentItem { label, href })
+}
+
+/// Moves `item` to the front, keeping at most `MAX_RECENT_ITEMS`.
+pub fn push_recent(items: &mut Vec<RecentItem>, item: RecentItem) {
+    items.retain(|existing| existing.href != item.href);
+    items.insert(0, item);
+    items.truncate(MAX_RECENT_ITEMS);
+}
+
+/// Scores `text` for `query` when all of the query's characters appear in it
+/// in order. Consecutive characters and word starts score higher.
+pub fn fuzzy_score(query: &str, text: &str) -> Option<i32> {
+    let text = text.to_lowercase().chars().collect::<Vec<_>>();
+    let mut score = 0;
+    let mut position = 0;
+    let mut previous_match = None;
+    for query_char in query.to_lowercase().chars().filter(|c| !c.is_whitespace()) {
+        let index = position + text[position..].iter().position(|c| *c == query_char)?;
+        score += 1;
+        if previous_match == index.checked_sub(1) {
+            score += 5;
+        }
+        if index == 0 || !text[index - 1].is_alphanumeric() {
+            score += 3;
+        }
+        previous_match = Some(index);
+        position = index + 1;
+    }
+    Some(score)
+}
+
+/// The items matching `query`, best first. Ties keep their original order.
+pub fn filter_palette(items: &[PaletteItem], query: &str) -> Vec<PaletteItem> {
+    let mut matches = items
+        .iter()
+        .filter_map(|item| fuzzy_score(query, &item.label).map(|score| (score, item)))
+        .collect::<Vec<_>>();
+    matches.sort_by(|(a, _), (b, _)| b.cmp(a));
+    matches
+        .into_iter()
+        .take(MAX_PALETTE_RESULTS)
+        .map(|(_, item)| item.clone())
+        .collect()
+}
+
+/// Whether the key press was meant for a form field.
+pub fn is_typing(ev: &KeyboardEvent) -> bool {
+    ev.target()
+        .and_then(|target| target.dyn_into::<HtmlElement>().ok())
+        .is_some_and(|el| {
+            el.is_content_editable()
+                || ["INPUT", "TEXTAREA", "SELECT"].contains(&el.tag_name().as_str())
+        })
+}
+
+fn table_rows() -> Vec<Element> {
+    let Ok(rows) = document().query_selector_all("[data-table-row]") else {
+        return vec![];
+    };
+    (0..rows.length())
+        .filter_map(|i| rows.item(i))
+        .filter_map(|node| node.dyn_into::<Element>().ok())
+        .collect()
+}
+
+/// Moves the row selection by `step` and scrolls the row into view.
+fn select_row(selected: StoredValue<Option<usize>>, step: isize) {
+    let rows = table_rows();
+    if rows.is_empty() {
+        return;
+    }
+    let next = match selected.get_value() {
+        Some(i) => (i as isize + step).clamp(0, rows.len() as isize - 1) as usize,
+        None => 0,
+    };
+    for (i, row) in rows.iter().enumerate() {
+        let classes = row.class_list();
+        for class in SELECTED_ROW_CLASSES {
+            let _ = classes.toggle_with_force(class, i == next);
+        }
+    }
+    rows[next].scroll_into_view_with_bool(false);
+    selected.set_value(Some(next));
+}
+
+/// Follows the first link in the selected row.
+fn open_row(selected: StoredValue<Option<usize>>) {
+    let link = selected
+        .get_value()
+        .and_then(|i| table_rows().into_iter().nth(i))
+        .and_then(|row| row.query_selector("a[href]").ok().flatten())
+        .and_then(|link| link.dyn_into::<HtmlElement>().ok());
+    if let Some(link) = link {
+        link.click();
+    }
+}
+
+/// Installs the global shortcuts and renders the palette and help overlay.
+/// Must be inside the `Router`.
+#[component]
+pub fn KeyboardShortcuts() -> impl IntoView {
+    let (palette_open, set_palette_open) = create_signal(false);
+    let (help_open, set_help_open) = create_signal(false);
+    let selected_row = store_value(None::<usize>);
+    let (_, set_recent, _) = use_local_storage::<Vec<RecentItem>, JsonSerdeCodec>(
+        use_network_storage_key(RECENT_ITEMS_STORAGE_KEY),
+    );
+
+    let location = use_location();
+    create_effect(move |_| {
+        selected_row.set_value(None);
+        if let Some(item) = recent_item_for_path(&location.pathname.get()) {
+            set_recent.update(|items| push_recent(items, item));
+        }
+    });
+
+    let handle = window_event_listener(ev::keydown, move |ev| {
+        if ev.key() == "k" && (ev.ctrl_key() || ev.meta_key()) {
+            ev.prevent_default();
+            set_help_open.set(false);
+            set_palette_open.update(|open| *open = !*open);
+            return;
+        }
+        if ev.key() == "Escape" {
+            set_help_open.set(false);
+            set_palette_open.set(false);
+            return;
+        }
+        if palette_open.get_untracked() || is_typing(&ev) || ev.ctrl_key() || ev.meta_key() {
+            return;
+        }
+        match ev.key().as_str() {
+            "/" => {
+                if let Some(search) = document()
+                    .get_element_by_id("searchbar")
+                    .and_then(|el| el.dyn_into::<HtmlElement>().ok())
+                {
+                    ev.prevent_default();
+                    let _ = search.focus();
+                }
+            }
+            "?" => set_help_open.update(|open| *open = !*open),
+            "j" => select_row(selected_row, 1),
+            "k" => select_row(selected_row, -1),
+            // leave Enter to a focused link or button
+            "Enter" if document().active_element() == document().body().map(Into::into) => {
+                open_row(selected_row)
+            }
+            _ => {}
+        }
+    });
+    on_cleanup(move || handle.remove());
+
+    view! {
+        <Show when=move || palette_open.get()>
+            <CommandPalette on_close=Callback::new(move |_| set_palette_open.set(false)) />
+        </Show>
+        <Show when=move || help_open.get()>
+            <ShortcutHelp on_close=Callback::new(move |_| set_help_open.set(false)) />
+        </Show>
+    }
+}
+
+#[component]
+fn CommandPalette(on_close: Callback<()>) -> impl IntoView {
+    let input_element: NodeRef<Input> = create_node_ref();
+    let (query, set_query) = create_signal(String::new());
+    let (selected, set_selected) = create_signal(0usize);
+    let (watchlist, _) = use_watchlist();
+    let (recent, _, _) = use_local_storage::<Vec<RecentItem>, JsonSerdeCodec>(
+        use_network_storage_key(RECENT_ITEMS_STORAGE_KEY),
+    );
+    let book = use_address_book().book;
+    let navigate = use_navigate();
+    let mina_token_address = use_mina_token_address();
+
+    let items = move || {
+        let pages = PALETTE_ROUTES.iter().map(|(label, href)| PaletteItem {
+            label: t(label),
+            group: "Pages",
+            href: palette_href(href, &mina_token_address),
+        });
+        let recent = recent.get().into_iter().map(|item| PaletteItem {
+            label: item.label,
+            group: "Recent",
+            href: item.href,
+        });
+        let watched = watchlist.get().into_iter().map(|public_key| PaletteItem {
+            label: book.with(|book| {
+                book.get(&public_key)
+                    .map(|entry| format!("{} ({})", entry.label, short_id(&public_key)))
+                    .unwrap_or(public_key.clone())
+            }),
+            group: "Watchlist",
+            href: format!(
+                "/addresses/accounts/{}/{}/spotlight",
+                mina_token_address, public_key
+            ),
+        });
+        pages.chain(recent).chain(watched).collect::<Vec<_>>()
+    };
+    let results = Memo::new(move |_| filter_palette(&items(), &query.get()));
+
+    let go_to = move |item: PaletteItem| {
+        navigate(&item.href, Default::default());
+        on_close.call(());
+    };
+
+    create_effect(move |_| {
+        if let Some(input) = input_element.get() {
+            let _ = input.focus();
+        }
+    });
+
+    let on_keydown = {
+        let go_to = go_to.clone();
+        move |ev: ev::KeyboardEvent| {
+            let count = results.with(Vec::len);
+            match ev.key().as_str() {
+                "ArrowDown" if count > 0 => {
+                    ev.prevent_default();
+                    set_selected.update(|i| *i = (*i + 1) % count);
+                }
+                "ArrowUp" if count > 0 => {
+                    ev.prevent_default();
+                    set_selected.update(|i| *i = (*i + count - 1) % count);
+                }
+                "Enter" => {
+                    ev.prevent_default();
+                    if let Some(item) = results.with(|r| r.get(selected.get_untracked()).cloned()) {
+                        go_to(item);
+                    }
+                }
+                _ => {}
+            }
+        }
+    };
+
+    view! {
+        <div
+            class="fixed inset-0 z-50 bg-black/50 flex justify-center items-start pt-24"
+            on:click=move |_| on_close.call(())
+        >
+            <div
+                id="command-palette"
+                class="w-11/12 max-w-xl rounded-xl bg-white shadow-lg overflow-hidden"
+                on:click=|ev| ev.stop_propagation()
+            >
+                <input
+                    type="text"
+                    autocomplete="off"
+                    class="w-full h-12 px-4 text-base border-b border-slate-200 focus:outline-none"
+                    placeholder=t("Go to...")
+                    node_ref=input_element
+                    prop:value=query
+                    on:input=move |ev| {
+                        set_query.set(event_target_value(&ev));
+                        set_selected.set(0);
+                    }
+                    on:keydown=on_keydown
+                />
+                <ul class="max-h-96 overflow-y-auto text-sm">
+                    {move || {
+                        results
+                            .get()
+                            .into_iter()
+                            .enumerate()
+                            .map(|(i, item)| {
+                                let go_to = go_to.clone();
+                                let is_selected = move || selected.get() == i;
+                                let choice = item.clone();
+                                view! {
+                                    <li
+                                        class="px-4 py-2 flex justify-between cursor-pointer"
+                                        class:bg-slate-100=is_selected
+                                        on:mouseenter=move |_| set_selected.set(i)
+                                        on:click=move |_| go_to(choice.clone())
+                                    >
+                                        <span class="truncate">{item.label}</span>
+                                        <span class="text-slate-400 ml-4">{t(item.group)}</span>
+                                    </li>
+                                }
+                            })
+                            .collect_view()
+                    }}
+                </ul>
+            </div>
+        </div>
+    }
+}
+
+#[component]
+fn ShortcutHelp(on_close: Callback<()>) -> impl IntoView {
+    view! {
+        <div
+            class="fixed inset-0 z-50 bg-black/50 flex justify-center items-center"
+            on:click=move |_| on_close.call(())
+        >
+            <div
+                id="shortcut-help"
+                class="w-11/12 max-w-md rounded-xl bg-white shadow-lg p-6"
+                on:click=|ev| ev.stop_propagation()
+            >
+                <h2 class="text-lg font-semibold mb-4">{t("Keyboard Shortcuts")}</h2>
+                <dl class="grid grid-cols-[6rem_auto] gap-y-2 text-sm">
+                    {SHORTCUTS
+                        .iter()
+                        .map(|(keys, description)| {
+                            view! {
+                                <dt>
+                                    <kbd class="px-2 py-0.5 rounded border border-slate-300 bg-slate-50 font-mono">
+                                        {*keys}
+                                    </kbd>
+                                </dt>
+                                <dd>{t(description)}</dd>
+                            }
+                        })
+                        .collect_view()}
+                </dl>
+            </div>
+        </div>
+    }
+}
+
+#[cfg(test)]
+mod shortcuts_tests {
+    use super::*;
+
+    const STATE_HASH: &str = "3NKeMoncuHab5ScarV5ViyF16cJPT4taWNSaTLS64Dp67wuXigPZ";
+
+    #[test]
+    fn test_fuzzy_score() {
+        assert!(fuzzy_score("blk", "Blocks").is_some());
+        assert!(fuzzy_score("xyz", "Blocks").is_none());
+        assert_eq!(fuzzy_score("", "Blocks"), Some(0));
+        assert!(fuzzy_score("uc", "User Commands") > fuzzy_score("uc", "Staker Leaderboard"));
+        assert!(fuzzy_score("bl", "Blocks") > fuzzy_score("bl", "Analytics Blocks"));
+    }
+
+    #[test]
+    fn test_filter_palette() {
+        let item = |label: &str| PaletteItem {
+            label: label.to_string(),
+            group: "Pages",
+            href: "/".to_string(),
+        };
+        let items = vec![item("Staking Ledgers"), item("SNARKs"), item("Tokens")];
+        let labels = |query| {
+            filter_palette(&items, query)
+                .into_iter()
+                .map(|i| i.label)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(labels(""), vec!["Staking Ledgers", "SNARKs", "Tokens"]);
+        assert_eq!(labels("sn"), vec!["SNARKs", "Staking Ledgers"]);
+        assert_eq!(labels("tok"), vec!["Tokens"]);
+    }
+
+    #[test]
+    fn test_recent_item_for_path() {
+        assert_eq!(
+            recent_item_for_path(&format!("/blocks/{}/snark-jobs", STATE_HASH)),
+            Some(RecentItem {
+                label: "Block 3NKeMo…igPZ".to_string(),
+                href: format!("/blocks/{}/spotlight", STATE_HASH),
+            })
+        );
+        assert!(recent_item_for_path("/commands/user").is_none());
+        assert!(recent_item_for_path("/blocks").is_none());
+        assert!(recent_item_for_path("/commands/CkpZ1").is_some());
+        assert_eq!(
+            recent_item_for_path("/commands/ÄÖÜäöüßÄÖÜäöüß").map(|item| item.label),
+            Some("Command ÄÖÜäöü…äöüß".to_string())
+        );
+    }
+
+    #[test]
+    fn test_palette_href() {
+        const TOKEN: &str = "wSHV2S4qX9jFsLjQo8r1BsMLH2ZRKsZx6EJd1sbozGPieEC4Jf";
+        assert_eq!(
+            palette_href("/addresses/accounts/{token}", TOKEN),
+            format!("/addresses/accounts/{}", TOKEN)
+        );
+        assert_eq!(palette_href("/blocks", TOKEN), "/blocks");
+    }
+
+    #[test]
+    fn test_push_recent() {
+        let item = |n: usize| RecentItem {
+            label: n.to_string(),
+            href: format!("/commands/{}", n),
+        };
+        let mut items = (0..MAX_RECENT_ITEMS).map(item).collect::<Vec<_>>();
+        push_recent(&mut items, item(3));
+        assert_eq!(items[0], item(3));
+        assert_eq!(items.len(), MAX_RECENT_ITEMS);
+        push_recent(&mut items, item(99));
+        assert_eq!(items[0], item(99));
+        assert_eq!(items.len(), MAX_RECENT_ITEMS);
+        assert!(!items.contains(&item(MAX_RECENT_ITEMS - 1)));
+    }
+}
@@ -0,0 +1,283 @@
+//! OpenGraph and Twitter card tags for the spotlight pages. They are loaded
+//! with a blocking resource keyed on the route, so that the `ssr` build
+//! renders them into the page for crawlers and link unfurlers, which do not
+//! run the app.
+
+use super::{
+    amount::Amount,
+    constants::*,
+    models::{MyError, PublicKey, StateHash, TokenId, TxnHash},
+    network::{Network, use_network},
+};
+use crate::{
+    accounts::{functions as accounts, graphql::accounts_query::AccountsQueryAccounts},
+    blocks::{functions as blocks, graphql::blocks_query::BlocksQueryBlocks},
+    user_commands::{
+        functions as user_commands, graphql::transactions_query::TransactionsQueryTransactions,
+        table_trait::TransactionsTrait,
+    },
+};
+use leptos::*;
+use leptos_meta::Meta;
+use leptos_router::use_location;
+use serde::{Deserialize, Serialize};
+
+/// The origin pages are served from, provided by the server since it has no
+/// `window` to ask.
+#[derive(Clone, Debug)]
+pub struct SiteOrigin(pub String);
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Preview {
+    pub title: String,
+    pub description: String,
+}
+
+#[derive(Debug, PartialEq)]
+enum PreviewRoute {
+    Block(StateHash),
+    Command(TxnHash),
+    Account(TokenId, PublicKey),
+}
+
+/// The spotlight page a path leads to, if any.
+fn preview_route(path: &str) -> Option<PreviewRoute> {
+    let segments = path.trim_matches('/').split('/').collect::<Vec<_>>();
+    match segments.as_slice() {
+        ["blocks", id, ..] => StateHash::new(*id).ok().map(PreviewRoute::Block),
+        ["commands", id, ..] => TxnHash::new(*id).ok().map(PreviewRoute::Command),
+        ["addresses", "accounts", token_id, id, ..] => Some(PreviewRoute::Account(
+            TokenId::new(*token_id).ok()?,
+            PublicKey::new(*id).ok()?,
+        )),
+        _ => None,
+    }
+}
+
+fn block_preview(block: &BlocksQueryBlocks) -> Preview {
+    Preview {
+        title: format!(
+            "Block {} | {}",
+            block.block_height.unwrap_or_default(),
+            SITE_NAME
+        ),
+        description: format!(
+            "Produced by {} with {} user commands and {} SNARK jobs. Coinbase: {} MINA.",
+            blocks::get_creator_username(block)
+                .unwrap_or_else(|| blocks::get_creator_account(block)),
+            blocks::get_transaction_count(block).unwrap_or_default(),
+            blocks::get_snark_job_count(block).unwrap_or_default(),
+            blocks::get_coinbase_amount(block)
+                .unwrap_or_default()
+                .to_short_mina_string(),
+        ),
+    }
+}
+
+fn command_preview(transaction: &TransactionsQueryTransactions) -> Preview {
+    Preview {
+        title: format!(
+            "{} {} | {}",
+            transaction.get_kind(),
+            transaction.get_hash(),
+            SITE_NAME
+        ),
+        description: format!(
+            "{} of {} MINA from {} to {} in block {}. Fee: {} MINA.",
+            transaction.get_kind(),
+            transaction
+                .amount
+                .unwrap_or_default()
+                .to_short_mina_string(),
+            transaction.get_from().unwrap_or_default(),
+            transaction.get_receiver_public_key().unwrap_or_default(),
+            transaction.block_height.unwrap_or_default(),
+            transaction.fee.unwrap_or_default().to_short_mina_string(),
+        ),
+    }
+}
+
+fn account_preview(account: &AccountsQueryAccounts, public_key: &PublicKey) -> Preview {
+    Preview {
+        title: format!(
+            "Account {} | {}",
+            account
+                .username
+                .clone()
+                .unwrap_or_else(|| public_key.to_string()),
+            SITE_NAME
+        ),
+        description: format!(
+            "Balance: {} MINA. Nonce: {}.",
+            Amount::from_nanomina(account.balance.unwrap_or_default()).to_short_mina_string(),
+            account.nonce.unwrap_or_default(),
+        ),
+    }
+}
+
+async fn load_preview(network: &Network, path: &str) -> Result<Option<Preview>, MyError> {
+    let preview = match preview_route(path) {
+        Some(PreviewRoute::Block(state_hash)) => blocks::load_data(
+            network,
+            Some(1),
+            None,
+            Some(state_hash.to_string()),
+            None,
+            None,
+            None,
+            Default::default(),
+            None,
+        )
+        .await?
+        .blocks
+        .into_iter()
+        .flatten()
+        .next()
+        .map(|block| block_preview(&block)),
+        Some(PreviewRoute::Command(txn_hash)) => user_commands::load_data(
+            network,
+            Some(1),
+            None,
+            None,
+            Some(txn_hash.to_string()),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            Default::default(),
+            None,
+        )
+        .await?
+        .transactions
+        .into_iter()
+        .flatten()
+        .next()
+        .map(|transaction| command_preview(&transaction)),
+        Some(PreviewRoute::Account(token_id, public_key)) => accounts::load_data(
+            network,
+            Some(1),
+            Some(public_key.clone()),
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some(token_id.to_string()),
+        )
+        .await?
+        .accounts
+        .into_iter()
+        .flatten()
+        .next()
+        .map(|account| account_preview(&account, &public_key)),
+        None => None,
+    };
+    Ok(preview)
+}
+
+/// Social tags for the spotlight page at the current route.
+#[component]
+pub fn SocialPreview() -> impl IntoView {
+    let location = use_location();
+    let network = use_network();
+    let resource = create_blocking_resource(
+        move || location.pathname.get(),
+        move |path| async move {
+            load_preview(&network.get_value(), &path)
+                .await
+                .unwrap_or_else(|err| {
+                    logging::log!("No social preview for {}: {}", path, err);
+                    None
+                })
+        },
+    );
+
+    view! {
+        <Suspense>
+            {move || {
+                resource
+                    .get()
+                    .flatten()
+                    .map(|preview| {
+                        view! {
+                            <SocialMeta title=preview.title description=preview.description />
+                        }
+                    })
+            }}
+        </Suspense>
+    }
+}
+
+/// OpenGraph and Twitter card tags, so that links to an entity page unfurl
+/// with a summary of the entity in chat apps and social sites.
+#[component]
+pub fn SocialMeta(#[prop(into)] title: String, #[prop(into)] description: String) -> impl IntoView {
+    let location = use_location();
+    let origin = use_context::<SiteOrigin>()
+        .map(|origin| origin.0)
+        .unwrap_or_else(|| window().location().origin().unwrap_or_default());
+    let search = location.search.get_untracked();
+    let search = search.trim_start_matches('?');
+    let url = if search.is_empty() {
+        format!("{}{}", origin, location.pathname.get_untracked())
+    } else {
+        format!("{}{}?{}", origin, location.pathname.get_untracked(), search)
+    };
+    view! {
+        <Meta property="og:type" content="website" />
+        <Meta property="og:site_name" content=SITE_NAME />
+        <Meta property="og:url" content=url />
+        <Meta property="og:title" content=title.clone() />
+        <Meta property="og:description" content=description.clone() />
+        <Meta name="twitter:card" content="summary" />
+        <Meta name="twitter:title" content=title />
+        <Meta name="twitter:description" content=description />
+    }
+}
+
+#[cfg(test)]
+mod social_tests {
+    use super::*;
+
+    const STATE_HASH: &str = "3NK2tkzqqK5spR2sZ7tujjqPksL45M3UUrcA4WhCkeiPtnugyE2x";
+    const TXN_HASH: &str = "5JtVQKsT6jBdWKdscCzm4a1JzuUdBwQ3jzX3fyqkvgpraPizUzqw";
+    const TOKEN_ID: &str = "wSHV2S4qX9jFsLjQo8r1BsMLH2ZRKsZx6EJd1sbozGPieEC4Jf";
+    const PUBLIC_KEY: &str = "B62qrCz3ehCqi8Pn8y3vWC9zYEB9RKsidauv15DeZxhzkxL3bKeba5h";
+
+    #[test]
+    fn test_preview_route() {
+        assert_eq!(
+            preview_route(&format!("/blocks/{}/spotlight", STATE_HASH)),
+            Some(PreviewRoute::Block(StateHash::new(STATE_HASH).unwrap()))
+        );
+        assert_eq!(
+            preview_route(&format!("/commands/{}", TXN_HASH)),
+            Some(PreviewRoute::Command(TxnHash::new(TXN_HASH).unwrap()))
+        );
+        assert_eq!(
+            preview_route(&format!(
+                "/addresses/accounts/{}/{}/spotlight",
+                TOKEN_ID, PUBLIC_KEY
+            )),
+            Some(PreviewRoute::Account(
+                TokenId::new(TOKEN_ID).unwrap(),
+                PublicKey::new(PUBLIC_KEY).unwrap()
+            ))
+        );
+    }
+
+    #[test]
+    fn test_preview_route_ignores_other_pages() {
+        assert_eq!(preview_route("/blocks"), None);
+        assert_eq!(preview_route("/commands/user"), None);
+        assert_eq!(preview_route(&format!("/blocks/{}gg", STATE_HASH)), None);
+        assert_eq!(
+            preview_route(&format!("/addresses/accounts/{}", TOKEN_ID)),
+            None
+        );
+    }
+}
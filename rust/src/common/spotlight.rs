@@ -1,4 +1,4 @@
-use crate::common::{components::*, functions::*};
+use crate::common::{components::*, datetime::use_time_settings, functions::*, i18n::t};
 use heck::ToKebabCase;
 use leptos::*;
 
@@ -9,13 +9,27 @@ pub struct SpotlightEntry {
     pub copiable: bool,
 }
 
+/// Spotlight meta for a timestamp, in both the chosen and the other form.
+pub fn date_time_meta(date_time: String) -> MaybeSignal<Option<String>> {
+    let settings = use_time_settings().settings;
+    MaybeSignal::derive(move || {
+        settings.with(|settings| {
+            Some(format!(
+                "{} ({})",
+                settings.format(&date_time),
+                settings.format_alternate(&date_time)
+            ))
+        })
+    })
+}
+
 #[component]
 pub fn SpotlightSection(
     #[prop(into)] header: String,
     #[prop(default = None)] top_right: Option<Children>,
     spotlight_items: Vec<SpotlightEntry>,
     #[prop(into)] id: Option<String>,
-    #[prop(into)] meta: Option<String>,
+    #[prop(into)] meta: MaybeSignal<Option<String>>,
     children: Children,
 ) -> impl IntoView {
     view! {
@@ -24,7 +38,7 @@ pub fn SpotlightSection(
             class="@container md:rounded-lg bg-table-section px-4 py-1 md:py-4 mb-2"
         >
             <h1 class="md:rounded-lg h-16 pl-8 text-xl bg-table-section flex justify-between items-center">
-                <span>{header}</span>
+                <span>{t(&header)}</span>
                 <span>
                     {match top_right {
                         Some(frag) => frag(),
@@ -43,7 +57,7 @@ pub fn SpotlightSection(
 #[component]
 fn Spotlight(
     spotlight_items: Vec<SpotlightEntry>,
-    #[prop(into)] meta: Option<String>,
+    #[prop(into)] meta: MaybeSignal<Option<String>>,
     #[prop(into)] id: Option<String>,
     children: Children,
 ) -> impl IntoView {
@@ -73,7 +87,7 @@ fn Spotlight(
                 id="spotlight-meta"
                 class="@3xl:col-start-3 @3xl:row-start-2 text-slate-400 text-sm max-w-80"
             >
-                {match meta {
+                {move || match meta.get() {
                     Some(m) => view! { <span>{m}</span> }.into_view(),
                     None => data_placeholder().into_view(),
                 }}
@@ -124,7 +138,7 @@ fn SpotlightRow(entry: SpotlightEntry) -> impl IntoView {
             "{} {}",
             th_td_class_base,
             "w-36 md:w-40 min-w-36 md:min-w-40 font-normal text-slate-400 md:mr-8 mr-0",
-        )>{entry.label}:</th>
+        )>{t(&entry.label)}:</th>
         <td class=format!(
             "{} {}",
             th_td_class_base,
@@ -1,4 +1,4 @@
-use super::{components::*, functions::*, models::*};
+use super::{components::*, functions::*, i18n::*, models::*, presets::FilterPresets};
 use crate::{
     account_activity::models::Delegators,
     accounts::models::AccountsSort,
@@ -10,10 +10,12 @@ use crate::{
     icons::*,
     stakes::models::StakesSort,
 };
+use codee::string::JsonSerdeCodec;
 use heck::{ToKebabCase, ToTitleCase};
 use leptos::{html::*, *};
 use leptos_router::*;
-use leptos_use::{DebounceOptions, use_debounce_fn_with_options};
+use leptos_use::{DebounceOptions, storage::use_local_storage, use_debounce_fn_with_options};
+use serde::{Deserialize, Serialize};
 
 pub trait TableData {
     #[allow(dead_code)]
@@ -21,6 +23,11 @@ pub trait TableData {
         Vec::new()
     }
     fn get_rows(&self) -> Vec<Vec<HtmlElement<AnyElement>>>;
+    /// Plain values for the same columns as `get_rows`, used for exports.
+    /// Tables that return nothing here are not exportable.
+    fn get_raw_rows(&self) -> Vec<Vec<String>> {
+        Vec::new()
+    }
     #[allow(dead_code)]
     fn get_exact_search_columns(&self) -> Vec<String> {
         Vec::new()
@@ -41,8 +48,15 @@ pub enum ColumnSearchType {
     None,
     Text,
     Select,
+    /// From and to dates, set as the column's `-gte` and `-lte` params.
+    DateRange,
+    /// Minimum and maximum numbers, set like `DateRange`.
+    NumberRange,
 }
 
+/// Checks a column's text search input, e.g. `StateHash::validate`.
+pub type Validator = fn(&str) -> Result<(), String>;
+
 #[derive(Clone)]
 pub struct TableColumn<T> {
     pub column: String,
@@ -54,6 +68,7 @@ pub struct TableColumn<T> {
     pub html_input_type: String,
     pub alignment: Option<ColumnTextAlignment>,
     pub tooltip: Option<String>,
+    pub validate: Option<Validator>,
 }
 
 impl<T> Default for TableColumn<T> {
@@ -68,10 +83,79 @@ impl<T> Default for TableColumn<T> {
             html_input_type: "text".to_string(),
             alignment: None,
             tooltip: None,
+            validate: None,
         }
     }
 }
 
+/// A table's column order and hidden columns, saved per table by column name.
+/// Columns the layout doesn't know about are shown after the known ones.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct ColumnLayout {
+    pub order: Vec<String>,
+    pub hidden: Vec<String>,
+}
+
+impl ColumnLayout {
+    /// Indices of all `columns`, hidden or not, in display order.
+    pub fn ordered_columns(&self, columns: &[String]) -> Vec<usize> {
+        let mut indices = self
+            .order
+            .iter()
+            .filter_map(|name| columns.iter().position(|column| column == name))
+            .collect::<Vec<_>>();
+        indices.dedup();
+        (0..columns.len()).for_each(|index| {
+            if !indices.contains(&index) {
+                indices.push(index);
+            }
+        });
+        indices
+    }
+
+    /// Indices of the visible `columns`, in display order.
+    pub fn active_columns(&self, columns: &[String]) -> Vec<usize> {
+        self.ordered_columns(columns)
+            .into_iter()
+            .filter(|index| !self.hidden.contains(&columns[*index]))
+            .collect()
+    }
+
+    /// The values of a row in `columns` order that are in visible columns,
+    /// in display order.
+    pub fn active_values<T: Clone>(&self, columns: &[String], row: &[T]) -> Vec<T> {
+        self.active_columns(columns)
+            .into_iter()
+            .filter_map(|index| row.get(index).cloned())
+            .collect()
+    }
+
+    /// Shows or hides `column`, always leaving at least one column visible.
+    pub fn toggle(&mut self, columns: &[String], column: &str) {
+        if let Some(position) = self.hidden.iter().position(|hidden| hidden == column) {
+            self.hidden.remove(position);
+        } else if self.active_columns(columns).len() > 1 {
+            self.hidden.push(column.to_string());
+        }
+    }
+
+    /// Moves `column` by `step` places in the display order.
+    pub fn move_column(&mut self, columns: &[String], column: &str, step: isize) {
+        let mut order = self
+            .ordered_columns(columns)
+            .into_iter()
+            .map(|index| columns[index].clone())
+            .collect::<Vec<_>>();
+        let Some(from) = order.iter().position(|name| name == column) else {
+            return;
+        };
+        let to = (from as isize + step).clamp(0, order.len() as isize - 1) as usize;
+        let name = order.remove(from);
+        order.insert(to, name);
+        self.order = order;
+    }
+}
+
 const INPUT_CLASS: &str = " block w-full mt-1 h-7 text-base text-sm font-normal font-mono rounded ";
 const CELL_PADDING_CLASS: &str = " first:pl-8 pl-4 last:pr-4 ";
 
@@ -172,22 +256,84 @@ pub fn TableSectionTemplate<T, S>(
     is_loading: Signal<bool>,
     #[prop(optional)] metadata: Option<Signal<Option<TableMetadata>>>,
     #[prop(into)] section_heading: MaybeSignal<String>,
+    /// Keys the saved column layout and filter presets. Unlike the heading
+    /// it must not change with the data shown, and no two tables share it.
+    #[prop(into)]
+    table_id: String,
     #[prop(optional, into)] additional_info: ViewFn,
     #[prop(optional, into)] controls: ViewFn,
     #[prop(optional, into)] footer: ViewFn,
     #[prop(default = false)] half_width: bool,
+    #[prop(optional)] error: Option<Signal<Option<MyError>>>,
+    #[prop(optional)] on_retry: Option<Callback<()>>,
 ) -> impl IntoView
 where
     T: TableData + Clone + 'static,
     S: CycleSort + SortDirection + ToString + Clone + 'static,
 {
-    let table_cols_length = table_columns.len();
+    let get_error = move || error.and_then(|error| error.get());
+    let export_headers = table_columns
+        .iter()
+        .map(|c| c.column.to_string())
+        .collect::<Vec<_>>();
+    let export_name = section_heading.get_untracked().to_kebab_case();
+
+    let (layout, set_layout, _) = use_local_storage::<ColumnLayout, JsonSerdeCodec>(format!(
+        "{}-{}",
+        TABLE_LAYOUT_STORAGE_KEY, table_id
+    ));
+    let column_names = store_value(export_headers.clone());
+    let active_export_headers = Signal::derive(move || {
+        column_names
+            .with_value(|columns| layout.with(|layout| layout.active_values(columns, columns)))
+    });
+    let export_rows = Signal::derive(move || {
+        let rows = data_sig
+            .get()
+            .map(|data| data.get_raw_rows())
+            .unwrap_or_default();
+        column_names.with_value(|columns| {
+            layout.with(|layout| {
+                rows.iter()
+                    .map(|row| layout.active_values(columns, row))
+                    .collect()
+            })
+        })
+    });
+    let active = Memo::new(move |_| {
+        column_names.with_value(|columns| layout.with(|layout| layout.active_columns(columns)))
+    });
+    let table_columns = store_value(table_columns);
+    let active_table_columns = move || {
+        table_columns.with_value(|columns| {
+            active
+                .get()
+                .into_iter()
+                .map(|index| columns[index].clone())
+                .collect::<Vec<_>>()
+        })
+    };
 
     view! {
         <TableSection
             metadata=metadata.unwrap_or_default()
             section_heading=section_heading.clone()
-            controls
+            controls=move || {
+                view! {
+                    {controls.run()}
+                    <FilterPresets table_id=table_id.clone() />
+                    <ColumnChooser
+                        columns=export_headers.clone()
+                        layout
+                        set_layout
+                    />
+                    <TableExport
+                        headers=active_export_headers
+                        rows=export_rows
+                        filename=export_name.clone()
+                    />
+                }
+            }
             additional_info
         >
             <div
@@ -196,24 +342,35 @@ where
                 class=("mx-auto", move || half_width)
             >
                 <Table id=section_heading.get().as_str().to_lowercase().to_kebab_case()>
-                    <ColGroup columns=table_columns.clone() />
-                    <TableHeader columns=table_columns.clone() />
-
+                    {move || {
+                        view! {
+                            <ColGroup columns=active_table_columns() />
+                            <TableHeader columns=active_table_columns() />
+                        }
+                    }}
                     {move || {
                         if is_loading.get() {
                             view! {
                                 <TableRows
-                                    columns=table_columns.clone()
+                                    columns=active_table_columns()
                                     data=vec![
-                                        vec![LoadingPlaceholder; table_cols_length];
+                                        vec![LoadingPlaceholder; active.with(Vec::len)];
                                         (TABLE_ROW_LIMIT / 2) as usize
                                     ]
                                 />
                             }
+                        } else if get_error().is_some() {
+                            ().into_view()
                         } else {
                             match data_sig.get() {
                                 Some(data) => {
-                                    view! { <TableRows columns=table_columns.clone() data=data /> }
+                                    view! {
+                                        <TableRows
+                                            columns=active_table_columns()
+                                            data=data
+                                            column_order=active.get()
+                                        />
+                                    }
                                 }
                                 None => ().into_view(),
                             }
@@ -222,9 +379,11 @@ where
 
                 </Table>
                 {move || {
-                    if !is_loading.get()
-                        && data_sig.get().map(|v| v.get_rows().len()).unwrap_or_default() == 0
-                    {
+                    if is_loading.get() {
+                        ().into_view()
+                    } else if let Some(err) = get_error() {
+                        view! { <ErrorView err on_retry /> }
+                    } else if data_sig.get().map(|v| v.get_rows().len()).unwrap_or_default() == 0 {
                         view! { <EmptyTable message="No data for this view" /> }
                     } else {
                         ().into_view()
@@ -236,6 +395,143 @@ where
     }
 }
 
+#[component]
+pub fn TableExport(
+    headers: Signal<Vec<String>>,
+    rows: Signal<Vec<Vec<String>>>,
+    filename: String,
+) -> impl IntoView {
+    let csv_filename = filename.clone();
+
+    view! {
+        <Show when=move || !rows.get().is_empty()>
+            <div class="export-table flex items-center ml-2">
+                <Button
+                    style_variant=ButtonStyleVariant::Tertiary
+                    text="CSV"
+                    class_str="mr-1"
+                    on_click={
+                        let filename = csv_filename.clone();
+                        move |_| {
+                            download_file(
+                                &format!("{}.csv", filename),
+                                "text/csv",
+                                &to_csv(&headers.get_untracked(), &rows.get_untracked()),
+                            )
+                        }
+                    }
+                />
+                <Button
+                    style_variant=ButtonStyleVariant::Tertiary
+                    text="JSON"
+                    on_click={
+                        let filename = filename.clone();
+                        move |_| {
+                            download_file(
+                                &format!("{}.json", filename),
+                                "application/json",
+                                &to_json_records(&headers.get_untracked(), &rows.get_untracked()),
+                            )
+                        }
+                    }
+                />
+            </div>
+        </Show>
+    }
+}
+
+/// Lets the user hide, show and reorder the columns of a table.
+#[component]
+pub fn ColumnChooser(
+    columns: Vec<String>,
+    layout: Signal<ColumnLayout>,
+    set_layout: WriteSignal<ColumnLayout>,
+) -> impl IntoView {
+    let (open, set_open) = create_signal(false);
+    let columns = store_value(columns);
+    let update = move |f: &dyn Fn(&mut ColumnLayout, &[String])| {
+        columns.with_value(|columns| set_layout.update(|layout| f(layout, columns)))
+    };
+
+    view! {
+        <div class="column-chooser relative flex items-center ml-2">
+            <Button
+                style_variant=ButtonStyleVariant::Tertiary
+                text=t("Columns")
+                on_click=move |_| set_open.update(|open| *open = !*open)
+            />
+            <Show when=move || open.get()>
+                <div class="fixed inset-0 z-10" on:click=move |_| set_open.set(false) />
+                <div class="absolute right-0 top-10 z-20 w-64 rounded-md bg-white shadow-lg p-3 flex flex-col gap-2">
+                    {move || {
+                        let (ordered, hidden) = columns
+                            .with_value(|columns| {
+                                layout
+                                    .with(|layout| {
+                                        (
+                                            layout
+                                                .ordered_columns(columns)
+                                                .into_iter()
+                                                .map(|index| columns[index].clone())
+                                                .collect::<Vec<_>>(),
+                                            layout.hidden.clone(),
+                                        )
+                                    })
+                            });
+                        ordered
+                            .into_iter()
+                            .map(|column| {
+                                let visible = !hidden.contains(&column);
+                                let (up, down, toggle) = (
+                                    column.clone(),
+                                    column.clone(),
+                                    column.clone(),
+                                );
+                                view! {
+                                    <div class="flex justify-between items-center">
+                                        <Checkbox
+                                            label=t(&column)
+                                            value=visible
+                                            handle_change=move |_| {
+                                                update(&|layout, columns| layout.toggle(columns, &toggle))
+                                            }
+                                        />
+                                        <span class="flex">
+                                            <button
+                                                title=t("Move up")
+                                                class=ICON_CLASS
+                                                on:click=move |_| {
+                                                    update(&|layout, columns| layout.move_column(columns, &up, -1))
+                                                }
+                                            >
+                                                <UpArrow width=12 />
+                                            </button>
+                                            <button
+                                                title=t("Move down")
+                                                class=ICON_CLASS
+                                                on:click=move |_| {
+                                                    update(&|layout, columns| layout.move_column(columns, &down, 1))
+                                                }
+                                            >
+                                                <DownArrow width=12 />
+                                            </button>
+                                        </span>
+                                    </div>
+                                }
+                            })
+                            .collect_view()
+                    }}
+                    <Button
+                        style_variant=ButtonStyleVariant::Tertiary
+                        text=t("Reset")
+                        on_click=move |_| set_layout.set(ColumnLayout::default())
+                    />
+                </div>
+            </Show>
+        </div>
+    }
+}
+
 #[component]
 pub fn ColGroup<T>(columns: Vec<TableColumn<T>>) -> impl IntoView
 where
@@ -300,6 +596,8 @@ where
     }
 
     let search_type = column.search_type.clone();
+    let validate = column.validate;
+    let (error, set_error) = create_signal(None::<String>);
 
     let update_value = use_debounce_fn_with_options(
         move || {
@@ -312,8 +610,18 @@ where
                     .get()
                     .expect("<select/> should be mounted")
                     .value(),
-                ColumnSearchType::None => panic!("Unexpected scenario"),
+                ColumnSearchType::None
+                | ColumnSearchType::DateRange
+                | ColumnSearchType::NumberRange => panic!("Unexpected scenario"),
             };
+            if let Some(Err(err)) = validate
+                .filter(|_| !v.is_empty())
+                .map(|validate| validate(&v))
+            {
+                set_error.set(Some(err));
+                return;
+            }
+            set_error.set(None);
             if v.is_empty() {
                 set_value.set(None);
             } else {
@@ -349,14 +657,14 @@ where
                                 class="flex justify-start items-center cursor-pointer"
                                 title=title.to_string()
                             >
-                                {column.column.to_string()}
+                                {t(&column.column)}
                                 <span class="w-2" />
                                 {convert_to_tooltip(title).into_view()}
                             </span>
                         }
                             .into_view()
                     })
-                    .unwrap_or(view! { {column.column.to_string()} }.into_view())}
+                    .unwrap_or(view! { {t(&column.column)} }.into_view())}
                 {match &column.sort_direction {
                     Some(direction) => {
                         if direction.is_active() {
@@ -401,8 +709,20 @@ where
                                 }
                                 node_ref=input_element
                                 class=INPUT_CLASS.to_string() + &input_class + " p-2 pl-1"
+                                class=("border-red-500", move || error.with(Option::is_some))
                                 id=id_copy
                             />
+                            {move || {
+                                error
+                                    .get()
+                                    .map(|err| {
+                                        view! {
+                                            <span class="basis-full text-xs font-normal normal-case text-red-500 column-search-error">
+                                                {err}
+                                            </span>
+                                        }
+                                    })
+                            }}
                         }
                             .into_view()
                     }
@@ -439,6 +759,17 @@ where
                         }
                             .into_view()
                     }
+                    ColumnSearchType::DateRange | ColumnSearchType::NumberRange => {
+                        view! {
+                            <RangeSearch
+                                id=id_copy
+                                search_type=column.search_type
+                                validate
+                                input_class
+                            />
+                        }
+                            .into_view()
+                    }
                     _ => view! { <div class=INPUT_CLASS></div> }.into_view(),
                 }}
             </span>
@@ -446,8 +777,75 @@ where
     }
 }
 
+/// The from and to inputs of a range column.
 #[component]
-pub fn TableRows<T, S>(data: T, columns: Vec<TableColumn<S>>) -> impl IntoView
+fn RangeSearch(
+    id: String,
+    search_type: ColumnSearchType,
+    validate: Option<Validator>,
+    input_class: String,
+) -> impl IntoView {
+    let is_date = search_type == ColumnSearchType::DateRange;
+    let (error, set_error) = create_signal(None::<String>);
+    let bound = move |param: String, label: &str| {
+        let (value, set_value) = create_query_signal::<String>(param.clone());
+        view! {
+            <input
+                data-test=format!("input-{}", param)
+                id=param
+                type=if is_date { "date" } else { "number" }
+                min=(!is_date).then_some("0")
+                step=(!is_date).then_some("any")
+                placeholder=t(label)
+                title=t(label)
+                value=value
+                on:change=move |ev| {
+                    let v = event_target_value(&ev);
+                    if let Some(Err(err)) = validate
+                        .filter(|_| !v.is_empty())
+                        .map(|validate| validate(&v))
+                    {
+                        set_error.set(Some(err));
+                        return;
+                    }
+                    set_error.set(None);
+                    set_value.set(Some(v).filter(|v| !v.is_empty()));
+                }
+                on:click=move |e| {
+                    e.stop_propagation();
+                }
+                class=INPUT_CLASS.to_string() + &input_class + " p-1 min-w-0"
+                class=("border-red-500", move || error.with(Option::is_some))
+            />
+        }
+    };
+
+    view! {
+        <span class="flex gap-1 w-full">
+            {bound(format!("{}-gte", id), "From")} {bound(format!("{}-lte", id), "To")}
+        </span>
+        {move || {
+            error
+                .get()
+                .map(|err| {
+                    view! {
+                        <span class="basis-full text-xs font-normal normal-case text-red-500 column-search-error">
+                            {err}
+                        </span>
+                    }
+                })
+        }}
+    }
+}
+
+/// `columns` are the rendered columns. `column_order` picks and orders the
+/// matching cells from each row, which otherwise are rendered as they are.
+#[component]
+pub fn TableRows<T, S>(
+    data: T,
+    columns: Vec<TableColumn<S>>,
+    #[prop(optional)] column_order: Option<Vec<usize>>,
+) -> impl IntoView
 where
     T: TableData,
     S: Clone + 'static,
@@ -455,10 +853,15 @@ where
     data.get_rows()
         .into_iter()
         .map(|row| {
+            let mut cells = row.into_iter().map(Some).collect::<Vec<_>>();
+            let order = column_order
+                .clone()
+                .unwrap_or_else(|| (0..cells.len()).collect());
             view! {
-                <tr class="h-12 bg-table-row-fill border-b border-slate-200">
-                    {row
+                <tr data-table-row class="h-12 bg-table-row-fill border-b border-slate-200">
+                    {order
                         .into_iter()
+                        .filter_map(|index| cells.get_mut(index).and_then(Option::take))
                         .enumerate()
                         .map(|(index, cell)| {
                             view! {
@@ -566,6 +969,12 @@ pub fn Metadata(meta: TableMetadata, section_heading: String) -> impl IntoView {
     let total = meta.total_records.map_or("?".to_string(), |records| {
         format_number(records.to_string())
     });
+    let total = tn_as(
+        "{count} records",
+        meta.total_records.unwrap_or(meta.displayed_records),
+        &total,
+    );
+    let of = format!(" {} ", t("of"));
 
     view! {
         <div
@@ -579,7 +988,7 @@ pub fn Metadata(meta: TableMetadata, section_heading: String) -> impl IntoView {
                     .as_ref()
                     .map(|hint| convert_to_tooltip(hint.clone()))
                     .into_view()}
-                <span>" of "</span>
+                <span>{of.clone()}</span>
                 {if let Some(available_records) = meta.available_records {
                     let available = format_number(available_records.to_string());
                     view! {
@@ -589,7 +998,7 @@ pub fn Metadata(meta: TableMetadata, section_heading: String) -> impl IntoView {
                             .as_ref()
                             .map(|hint| convert_to_tooltip(hint.clone()))
                             .into_view()}
-                        <span>" of "</span>
+                        <span>{of.clone()}</span>
                     }
                         .into_view()
                 } else {
@@ -654,3 +1063,70 @@ impl TableData for DeprecatedLoadingPlaceholder {
             .collect()
     }
 }
+
+#[cfg(test)]
+mod column_layout_tests {
+    use super::ColumnLayout;
+
+    fn columns() -> Vec<String> {
+        ["Hash", "From", "To", "Amount", "Status"]
+            .map(String::from)
+            .to_vec()
+    }
+
+    #[test]
+    fn test_default_layout_shows_everything() {
+        assert_eq!(
+            ColumnLayout::default().active_columns(&columns()),
+            vec![0, 1, 2, 3, 4]
+        );
+    }
+
+    #[test]
+    fn test_saved_order_and_unknown_columns() {
+        let layout = ColumnLayout {
+            order: ["Status", "Gone", "Hash"].map(String::from).to_vec(),
+            hidden: ["To", "Gone"].map(String::from).to_vec(),
+        };
+        assert_eq!(layout.ordered_columns(&columns()), vec![4, 0, 1, 2, 3]);
+        assert_eq!(layout.active_columns(&columns()), vec![4, 0, 1, 3]);
+    }
+
+    #[test]
+    fn test_active_values() {
+        let layout = ColumnLayout {
+            order: ["Status", "Hash"].map(String::from).to_vec(),
+            hidden: ["To"].map(String::from).to_vec(),
+        };
+        assert_eq!(
+            layout.active_values(&columns(), &columns()),
+            vec!["Status", "Hash", "From", "Amount"]
+        );
+        let row = ["CkpA", "B62qa", "B62qb", "1", "Applied"].map(String::from);
+        assert_eq!(
+            layout.active_values(&columns(), &row),
+            vec!["Applied", "CkpA", "B62qa", "1"]
+        );
+    }
+
+    #[test]
+    fn test_toggle_keeps_one_column() {
+        let columns = columns()[..2].to_vec();
+        let mut layout = ColumnLayout::default();
+        layout.toggle(&columns, "Hash");
+        layout.toggle(&columns, "From");
+        assert_eq!(layout.active_columns(&columns), vec![1]);
+        layout.toggle(&columns, "Hash");
+        assert_eq!(layout.active_columns(&columns), vec![0, 1]);
+    }
+
+    #[test]
+    fn test_move_column() {
+        let mut layout = ColumnLayout::default();
+        layout.move_column(&columns(), "Amount", -2);
+        assert_eq!(layout.active_columns(&columns()), vec![0, 3, 1, 2, 4]);
+        layout.move_column(&columns(), "Hash", -1);
+        layout.move_column(&columns(), "Status", 1);
+        assert_eq!(layout.active_columns(&columns()), vec![0, 3, 1, 2, 4]);
+    }
+}
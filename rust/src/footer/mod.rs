@@ -1,8 +1,11 @@
-use crate::{common::constants::*, icons::*, summary::models::*};
+use crate::{
+    common::{constants::*, network::*},
+    icons::*,
+    summary::models::*,
+};
 use codee::string::JsonSerdeCodec;
 use leptos::*;
 use leptos_use::storage::use_local_storage;
-use serde::{Deserialize, Serialize};
 
 enum Icon {
     Docs,
@@ -15,23 +18,14 @@ pub struct Link<'a> {
     icon: Icon,
 }
 
-#[derive(Deserialize, Serialize, Debug, Clone)]
-struct VersionData {
-    pub version: String,
-}
-
-#[derive(Deserialize, Serialize, Debug, Clone)]
-struct APIVersionResponse {
-    pub data: VersionData,
-}
-
 const FOOTER_LINK_BASE_CLASS: &str = "ml-4 flex items-center text-white text-sm ";
 const HIDE_ON_MOBILE: &str = "hidden sm:block ";
 
 #[component]
 pub fn Footer() -> impl IntoView {
-    let (summary_sig, _, _) =
-        use_local_storage::<BlockchainSummary, JsonSerdeCodec>(BLOCKCHAIN_SUMMARY_STORAGE_KEY);
+    let (summary_sig, _, _) = use_local_storage::<BlockchainSummary, JsonSerdeCodec>(
+        use_network_storage_key(BLOCKCHAIN_SUMMARY_STORAGE_KEY),
+    );
 
     let links = vec![
         Link {
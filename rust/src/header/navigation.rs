@@ -1,4 +1,14 @@
-use crate::common::{components::*, constants::MINA_TOKEN_ADDRESS, models::*};
+use crate::common::{
+    components::*,
+    config::use_app_config,
+    constants::LOCALE_STORAGE_KEY,
+    datetime::*,
+    functions::is_valid_time_zone,
+    i18n::{locales, t, use_locale},
+    models::*,
+    network::{search_with_network, use_mina_token_address, use_network},
+    preferences::set_preference_cookie,
+};
 use leptos::*;
 
 #[component]
@@ -44,7 +54,7 @@ pub fn Header() -> impl IntoView {
             ..Default::default()
         },
         NavEntry {
-            href: format!("/addresses/accounts/{}", MINA_TOKEN_ADDRESS),
+            href: format!("/addresses/accounts/{}", use_mina_token_address()),
             text: "Accounts".to_string(),
             icon: NavIcon::Addresses,
             match_type: Some(NavMatchType::Prefix),
@@ -83,6 +93,18 @@ pub fn Header() -> impl IntoView {
                     icon: NavIcon::SNARKs,
                     ..Default::default()
                 },
+                NavEntry {
+                    href: "/watchlist".to_string(),
+                    text: "Watchlist".to_string(),
+                    icon: NavIcon::Watchlist,
+                    ..Default::default()
+                },
+                NavEntry {
+                    href: "/address-book".to_string(),
+                    text: "Address Book".to_string(),
+                    icon: NavIcon::AddressBook,
+                    ..Default::default()
+                },
                 NavEntry {
                     href: "/analytics".to_string(),
                     text: "Analytics".to_string(),
@@ -168,6 +190,9 @@ pub fn Header() -> impl IntoView {
 
                 </ul>
             </nav>
+            <NetworkSelect />
+            <LocaleSelect />
+            <TimeSettingsSelect />
             <label
                 on:click=toggle
                 for="nav-toggle"
@@ -179,3 +204,184 @@ pub fn Header() -> impl IntoView {
         </header>
     }
 }
+
+#[component]
+fn NetworkSelect() -> impl IntoView {
+    let networks = use_app_config().with_value(|config| config.networks());
+    let selected = use_network().with_value(|network| network.id.clone());
+
+    // Reload rather than swapping endpoints in place, so no resource or
+    // cached signal from the previous network survives the switch.
+    let on_change = move |ev| {
+        let location = window().location();
+        let pathname = location.pathname().unwrap_or_default();
+        let search = location.search().unwrap_or_default();
+        let _ = location.set_href(&format!(
+            "{}{}",
+            pathname,
+            search_with_network(&search, &event_target_value(&ev))
+        ));
+    };
+
+    (networks.len() > 1)
+        .then(|| {
+            view! {
+                <select
+                    id="network-select"
+                    data-test="network-select"
+                    class="md:ml-4 bg-main-background text-white text-sm border border-slate-500 focus:border-granola-orange focus:outline-none rounded-md p-1"
+                    on:change=on_change
+                >
+                    {networks
+                        .into_iter()
+                        .map(|network| {
+                            let is_selected = network.id == selected;
+                            view! {
+                                <option value=network.id selected=is_selected>
+                                    {network.name}
+                                </option>
+                            }
+                        })
+                        .collect::<Vec<_>>()}
+                </select>
+            }
+        })
+        .into_view()
+}
+
+#[component]
+fn LocaleSelect() -> impl IntoView {
+    let selected = use_locale().0;
+
+    // Messages are translated as they render, so reload to redraw the page.
+    let on_change = move |ev| {
+        let locale = event_target_value(&ev);
+        if let Ok(Some(storage)) = window().local_storage() {
+            let _ = storage.set_item(LOCALE_STORAGE_KEY, &locale);
+        }
+        set_preference_cookie(LOCALE_STORAGE_KEY, &locale);
+        let _ = window().location().reload();
+    };
+
+    view! {
+        <select
+            id="locale-select"
+            data-test="locale-select"
+            aria-label=t("Language")
+            class="md:ml-4 bg-main-background text-white text-sm border border-slate-500 focus:border-granola-orange focus:outline-none rounded-md p-1"
+            on:change=on_change
+        >
+            {locales()
+                .into_iter()
+                .map(|(code, name)| {
+                    view! {
+                        <option value=code selected=code == selected>
+                            {name}
+                        </option>
+                    }
+                })
+                .collect::<Vec<_>>()}
+        </select>
+    }
+}
+
+#[component]
+fn TimeSettingsSelect() -> impl IntoView {
+    let TimeSettingsContext {
+        settings,
+        set_settings,
+    } = use_time_settings();
+    let saved_zone = create_memo(move |_| settings.with(|s| s.time_zone.clone()));
+    let custom_zone = move || match saved_zone.get() {
+        TimeZoneSetting::Custom(zone) => zone,
+        _ => String::new(),
+    };
+    // A custom zone is only saved once it is valid, so track the choice apart
+    // from the settings while the name is being typed. It follows the saved
+    // zone, which server-rendered pages only load once hydrated.
+    let (zone_choice, set_zone_choice) = create_signal("local");
+    create_effect(move |_| {
+        set_zone_choice.set(match saved_zone.get() {
+            TimeZoneSetting::Local => "local",
+            TimeZoneSetting::Utc => "utc",
+            TimeZoneSetting::Custom(_) => "custom",
+        })
+    });
+    let (invalid_zone, set_invalid_zone) = create_signal(false);
+
+    let on_zone_change = move |ev| {
+        let choice = match event_target_value(&ev).as_str() {
+            "utc" => "utc",
+            "custom" => "custom",
+            _ => "local",
+        };
+        set_zone_choice.set(choice);
+        match choice {
+            "local" => set_settings.update(|s| s.time_zone = TimeZoneSetting::Local),
+            "utc" => set_settings.update(|s| s.time_zone = TimeZoneSetting::Utc),
+            _ => (),
+        }
+    };
+    let on_custom_zone_change = move |ev| {
+        let zone = event_target_value(&ev).trim().to_string();
+        let is_valid = is_valid_time_zone(&zone);
+        set_invalid_zone.set(!is_valid);
+        if is_valid {
+            set_settings.update(|s| s.time_zone = TimeZoneSetting::Custom(zone));
+        }
+    };
+    let on_display_change = move |ev| {
+        let display = if event_target_value(&ev) == "relative" {
+            TimeDisplay::Relative
+        } else {
+            TimeDisplay::Absolute
+        };
+        set_settings.update(|s| s.display = display);
+    };
+
+    let select_class = "md:ml-4 bg-main-background text-white text-sm border border-slate-500 focus:border-granola-orange focus:outline-none rounded-md p-1";
+    view! {
+        <select
+            id="time-zone-select"
+            data-test="time-zone-select"
+            aria-label=t("Time zone")
+            class=select_class
+            prop:value=zone_choice
+            on:change=on_zone_change
+        >
+            <option value="local">{t("Local time")}</option>
+            <option value="utc">{t("UTC")}</option>
+            <option value="custom">{t("Custom zone")}</option>
+        </select>
+        <Show when=move || zone_choice.get() == "custom">
+            <input
+                id="time-zone-input"
+                data-test="time-zone-input"
+                type="text"
+                placeholder=t("e.g. Europe/Berlin")
+                value=custom_zone
+                class=select_class
+                class:border-red-500=invalid_zone
+                title=move || invalid_zone.get().then(|| t("Unknown time zone"))
+                on:change=on_custom_zone_change
+            />
+        </Show>
+        <select
+            id="time-display-select"
+            data-test="time-display-select"
+            aria-label=t("Time display")
+            class=select_class
+            prop:value=move || {
+                settings
+                    .with(|s| match s.display {
+                        TimeDisplay::Absolute => "absolute",
+                        TimeDisplay::Relative => "relative",
+                    })
+            }
+            on:change=on_display_change
+        >
+            <option value="absolute">{t("Absolute")}</option>
+            <option value="relative">{t("Relative")}</option>
+        </select>
+    }
+}
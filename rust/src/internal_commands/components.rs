@@ -1,5 +1,5 @@
 use crate::{
-    common::{components::*, constants::*, models::*, table::*},
+    common::{components::*, constants::*, models::*, network::*, table::*},
     internal_commands::functions::load_data,
     summary::models::BlockchainSummary,
 };
@@ -11,14 +11,16 @@ use leptos_use::storage::use_local_storage;
 
 #[component]
 pub fn InternalCommandsTab() -> impl IntoView {
-    let (summary_sig, _, _) =
-        use_local_storage::<BlockchainSummary, JsonSerdeCodec>(BLOCKCHAIN_SUMMARY_STORAGE_KEY);
+    let (summary_sig, _, _) = use_local_storage::<BlockchainSummary, JsonSerdeCodec>(
+        use_network_storage_key(BLOCKCHAIN_SUMMARY_STORAGE_KEY),
+    );
     let (data_sig, set_data) = create_signal(None);
     let (recipient, _) = create_query_signal::<String>(QUERY_PARAM_RECEIPIENT);
     let (height_sig, _) = create_query_signal::<u64>(QUERY_PARAM_HEIGHT);
     let (row_limit_sig, _) = create_query_signal::<u64>("row-limit");
     let (state_hash_sig, _) = create_query_signal::<String>(QUERY_PARAM_STATE_HASH);
     let (canonical_sig, _) = create_query_signal::<bool>("canonical");
+    let network = use_network();
     let resource = create_resource(
         move || {
             (
@@ -29,8 +31,9 @@ pub fn InternalCommandsTab() -> impl IntoView {
                 row_limit_sig.get(),
             )
         },
-        |(opt_recipient, height, state_hash, canonical, row_limit)| async move {
+        move |(opt_recipient, height, state_hash, canonical, row_limit)| async move {
             load_data(
+                &network.get_value(),
                 row_limit.map(|l| l as i64),
                 opt_recipient,
                 height,
@@ -58,12 +61,14 @@ pub fn InternalCommandsTab() -> impl IntoView {
         },
         TableColumn {
             column: "State Hash".to_string(),
+            validate: Some(StateHash::validate),
             search_type: ColumnSearchType::Text,
             width: Some(String::from(TABLE_COL_HASH_WIDTH)),
             ..Default::default()
         },
         TableColumn {
             column: "Recipient".to_string(),
+            validate: Some(PublicKey::validate),
             search_type: ColumnSearchType::Text,
             width: Some(String::from(TABLE_COL_HASH_WIDTH)),
             ..Default::default()
@@ -89,6 +94,7 @@ pub fn InternalCommandsTab() -> impl IntoView {
         <Title text="Transactions | Internal Commands" />
         <PageContainer>
             <TableSectionTemplate
+                table_id="internal-commands"
                 table_columns
                 data_sig
                 metadata=Signal::derive(move || {
@@ -125,12 +131,27 @@ pub fn InternalCommandsTab() -> impl IntoView {
                 })
 
                 is_loading=resource.loading()
+                error=Signal::derive(move || resource.get().and_then(|res| res.err()))
+                on_retry=Callback::new(move |_| resource.refetch())
                 section_heading="Internal Commands"
                 footer=move || {
                     view! {
-                        <NextBlockPage
-                            data=data_sig.get().unwrap_or(vec![])
-                            row_limit=row_limit_sig.get()
+                        <NextPage
+                            data_sig
+                            set_data_sig=set_data
+                            row_limit=row_limit_sig
+                            fetch=move |height, limit| async move {
+                                load_data(
+                                        &network.get_value(),
+                                        Some(limit as i64),
+                                        recipient.get_untracked(),
+                                        height,
+                                        state_hash_sig.get_untracked(),
+                                        canonical_sig.get_untracked(),
+                                    )
+                                    .await
+                                    .map(|data| data.internal_commands)
+                            }
                         />
                     }
                 }
@@ -1,12 +1,12 @@
 use crate::{
-    common::{constants::*, models::*},
+    common::{client::query_graphql, models::*, network::Network},
     internal_commands::graphql::{
         InternalCommandsQuery, internal_commands_query, internal_commands_query::BlockQueryInput,
     },
 };
-use graphql_client::reqwest::post_graphql;
 
 pub async fn load_data(
+    network: &Network,
     mut limit: Option<i64>,
     recipient: Option<String>,
     block_height: Option<u64>,
@@ -32,17 +32,5 @@ pub async fn load_data(
         },
     };
 
-    let client = reqwest::Client::new();
-
-    let response = post_graphql::<InternalCommandsQuery, _>(&client, GRAPHQL_ENDPOINT, variables)
-        .await
-        .map_err(|e| MyError::NetworkError(e.to_string()))?;
-
-    if let Some(errors) = response.errors {
-        return Err(MyError::GraphQLError(errors));
-    }
-
-    response
-        .data
-        .ok_or(MyError::GraphQLEmpty("No data available".to_string()))
+    query_graphql::<InternalCommandsQuery>(network, variables).await
 }
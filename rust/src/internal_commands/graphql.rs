@@ -1,3 +1,4 @@
+use crate::common::amount::Amount;
 use crate::internal_commands::graphql::internal_commands_query::{
     BlockQueryInput, InternalCommandQueryInput,
 };
@@ -9,7 +10,7 @@ type Long = i32;
 
 #[derive(GraphQLQuery)]
 #[graphql(
-    schema_path = "./graphql/schemas/mina-explorer.graphql",
+    schema_path = "./graphql/schemas/mina-explorer.typed.graphql",
     query_path = "./graphql/queries/internal_commands.graphql",
     response_derives = "Serialize,PartialEq,Debug,Clone,Default",
     skip_serializing_none
@@ -36,6 +37,7 @@ impl Default for InternalCommandQueryInput {
 impl Default for BlockQueryInput {
     fn default() -> Self {
         BlockQueryInput {
+            unique_block_producers_last_n_blocks: None,
             creator_nin: None,
             state_hash_lte: None,
             canonical_ne: None,
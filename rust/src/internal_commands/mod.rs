@@ -1,4 +1,4 @@
 pub mod components;
 mod functions;
-mod graphql;
+pub mod graphql;
 mod table_traits;
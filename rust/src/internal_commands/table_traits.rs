@@ -1,9 +1,7 @@
 use super::graphql::internal_commands_query::InternalCommandsQueryInternalCommands;
 use crate::common::{
-    constants::LHS_MAX_SPACE_FEES,
-    functions::*,
-    models::{ColorVariant, HasBlockHeight},
-    table::*,
+    amount::Amount, constants::LHS_MAX_SPACE_FEES, functions::*, models::ColorVariant,
+    pagination::Paginated, table::*,
 };
 use leptos::*;
 
@@ -23,15 +21,32 @@ impl TableData for Vec<Option<InternalCommandsQueryInternalCommands>> {
                     ),
                     decorate_with_mina_tag(internal_command.get_fee()),
                     convert_to_pill(internal_command.get_type(), ColorVariant::Grey),
-                    convert_to_title(
-                        convert_to_local_timezone_formatted(&internal_command.get_block_datetime()),
-                        internal_command.get_block_datetime(),
-                    ),
+                    convert_to_date_time(internal_command.get_block_datetime()),
                 ],
                 None => vec![],
             })
             .collect::<Vec<_>>()
     }
+
+    fn get_raw_rows(&self) -> Vec<Vec<String>> {
+        self.iter()
+            .flatten()
+            .map(|internal_command| {
+                vec![
+                    opt_to_string(internal_command.block_height),
+                    internal_command.get_state_hash(),
+                    internal_command.get_receipient(),
+                    opt_to_string(
+                        internal_command
+                            .fee
+                            .map(|f| Amount::from_nanomina(f).to_mina_string()),
+                    ),
+                    internal_command.get_type(),
+                    internal_command.get_block_datetime(),
+                ]
+            })
+            .collect()
+    }
 }
 
 pub trait InternalCommandTrait {
@@ -65,8 +80,8 @@ impl InternalCommandTrait for InternalCommandsQueryInternalCommands {
     }
     fn get_fee(&self) -> String {
         self.fee
-            .map(|i| nanomina_to_mina(i as u64))
-            .map(|number| format_number_for_html(&number, LHS_MAX_SPACE_FEES))
+            .map(Amount::from_nanomina)
+            .map(|fee| format_number_for_html(&fee.format(), LHS_MAX_SPACE_FEES))
             .unwrap_or_default()
     }
     fn get_type(&self) -> String {
@@ -79,8 +94,20 @@ impl InternalCommandTrait for InternalCommandsQueryInternalCommands {
     }
 }
 
-impl HasBlockHeight for InternalCommandsQueryInternalCommands {
-    fn block_height(&self) -> Option<i64> {
-        self.block_height
+impl Paginated for InternalCommandsQueryInternalCommands {
+    type Cursor = u64;
+
+    fn cursor(&self) -> Option<u64> {
+        self.block_height.map(|h| h as u64)
+    }
+
+    fn key(&self) -> Option<String> {
+        Some(format!(
+            "{}-{}-{}-{}",
+            self.get_state_hash(),
+            self.get_receipient(),
+            self.get_type(),
+            opt_to_string(self.fee)
+        ))
     }
 }
@@ -1,6 +1,7 @@
-use leptos::*;
 mod account_activity;
 mod accounts;
+mod address_book;
+mod advanced_filter;
 mod analytics;
 mod blocks;
 mod broadcast;
@@ -10,18 +11,24 @@ mod header;
 mod icons;
 mod internal_commands;
 mod root;
+#[cfg(feature = "ssr")]
+mod server;
 mod snarks;
 mod stakes;
 mod summary;
 mod token_holders;
 mod tokens;
 mod user_commands;
+mod watchlist;
 
-use leptos_meta::provide_meta_context;
-use root::Root;
-
+#[cfg(not(feature = "ssr"))]
 fn main() {
     console_error_panic_hook::set_once();
-    provide_meta_context();
-    leptos::mount_to_body(|| view! { <Root /> })
+    leptos::mount_to_body(root::App)
+}
+
+#[cfg(feature = "ssr")]
+#[tokio::main]
+async fn main() {
+    server::serve().await
 }
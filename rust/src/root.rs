@@ -5,6 +5,7 @@ use crate::{
         AccountUserCommandsPage,
     },
     accounts::page::AccountsPage,
+    address_book::page::AddressBookPage,
     analytics::{
         page::{
             AnalyticsTabbedPage, BlocksAnalyticsPage, SnarkerLeaderboardPage, SnarksAnalyticsPage,
@@ -20,7 +21,18 @@ use crate::{
         BroadcastDelegationPage, BroadcastFromLedgerPage, BroadcastTransactionPage,
         DelegationTabbedPage,
     },
-    common::{constants::*, search::*},
+    common::{
+        components::{
+            AddressBookProvider, AppConfigProvider, LiveUpdatesProvider, LocaleProvider,
+            TimeSettingsProvider, ValidRouteParam,
+        },
+        config::config_script,
+        models::*,
+        network::*,
+        search::*,
+        shortcuts::KeyboardShortcuts,
+        social::SocialPreview,
+    },
     footer::Footer,
     header::navigation::Header,
     internal_commands::components::InternalCommandsTab,
@@ -32,35 +44,94 @@ use crate::{
     user_commands::page::{
         CommandSpotlightPage, CommandsTabbedPage, PendingCommandsPage, UserCommandsPage,
     },
+    watchlist::{components::WatchlistNotifier, page::WatchlistPage},
 };
 use leptos::*;
+use leptos_meta::provide_meta_context;
 use leptos_router::*;
+
+/// Only the social preview is rendered on the server. Everything else needs
+/// the browser, so it is rendered once the page has been hydrated.
+#[component]
+pub fn App() -> impl IntoView {
+    provide_meta_context();
+    view! {
+        <Router>
+            <AppConfigProvider>
+                <SocialPreview />
+                <LocaleProvider>
+                    <TimeSettingsProvider>
+                        <AddressBookProvider>
+                            <LiveUpdatesProvider>
+                                <Root />
+                            </LiveUpdatesProvider>
+                        </AddressBookProvider>
+                    </TimeSettingsProvider>
+                </LocaleProvider>
+            </AppConfigProvider>
+        </Router>
+    }
+}
+
 #[component]
-pub fn Root() -> impl IntoView {
+fn Root() -> impl IntoView {
+    let network = use_network();
     view! {
-        <script>
-            {format!(
-                r#"const config = {{ graphql_endpoint: "{}", rest_endpoint: "{}" }}"#,
-                GRAPHQL_ENDPOINT,
-                REST_ENDPOINT,
-            )}
-        </script>
+        <script inner_html=network.with_value(config_script)></script>
         <script src="/scripts/analytics/charts-common.js" defer=true></script>
         <SummaryLocalStorage />
         // TODO: loading 1000 blocks is too expensive for now
         // <BlocksLocalStorage/>
-        <Router>
-            <Header />
-            <GlobalSearchBar />
-            <main>
-                <Routes>
-                    // redirect any non-existent URL back to the blocks page
+        <Header />
+        <WatchlistNotifier />
+        <KeyboardShortcuts />
+        <GlobalSearchBar />
+        <main>
+            <Routes>
+                // redirect any non-existent URL back to the blocks page
+                <Route
+                    path="/*"
+                    view=move || {
+                        view! {
+                            <Redirect
+                                path="/blocks"
+                                options=NavigateOptions {
+                                    replace: true,
+                                    ..Default::default()
+                                }
+                            />
+                        }
+                    }
+                />
+                // <Route path="/addresses/zkapps/:id" view=ZkAppSpotlight />
+                <Route
+                    path="/addresses/accounts/:token_id"
+                    view=move || {
+                        view! {
+                            <ValidRouteParam param="token_id" validate=TokenId::validate>
+                                <AccountsPage />
+                            </ValidRouteParam>
+                        }
+                    }
+                />
+                <Route
+                    path="/addresses/accounts/:token_id/:id"
+                    view=move || {
+                        view! {
+                            <ValidRouteParam param="token_id" validate=TokenId::validate>
+                                <ValidRouteParam validate=PublicKey::validate>
+                                    <AccountSpotlightTabbedPage />
+                                </ValidRouteParam>
+                            </ValidRouteParam>
+                        }
+                    }
+                >
                     <Route
-                        path="/*"
+                        path="*"
                         view=move || {
                             view! {
                                 <Redirect
-                                    path="/blocks"
+                                    path="commands/user"
                                     options=NavigateOptions {
                                         replace: true,
                                         ..Default::default()
@@ -69,127 +140,138 @@ pub fn Root() -> impl IntoView {
                             }
                         }
                     />
-                    // <Route path="/addresses/zkapps/:id" view=ZkAppSpotlight />
-                    <Route path="/addresses/accounts/:token_id" view=AccountsPage />
-                    <Route path="/addresses/accounts/:token_id/:id" view=AccountSpotlightTabbedPage>
+                    <Route path="/commands/user" view=AccountUserCommandsPage />
+                    <Route path="/commands/internal" view=AccountInternalCommandsPage />
+                    <Route path="/snark-jobs" view=AccountSnarkWorkPage />
+                    <Route path="/block-production" view=AccountBlockProductionPage />
+                    <Route path="/delegations" view=AccountDelegationsPage />
+                    <Route path="/tokens" view=AccountTokensPage>
                         <Route
-                            path="*"
+                            path="/:token_id"
                             view=move || {
                                 view! {
-                                    <Redirect
-                                        path="commands/user"
-                                        options=NavigateOptions {
-                                            replace: true,
-                                            ..Default::default()
-                                        }
-                                    />
+                                    <ValidRouteParam param="token_id" validate=TokenId::validate>
+                                        <TokenHoldersMoreDetailsSubpage />
+                                    </ValidRouteParam>
                                 }
                             }
                         />
-                        <Route path="/commands/user" view=AccountUserCommandsPage />
-                        <Route path="/commands/internal" view=AccountInternalCommandsPage />
-                        <Route path="/snark-jobs" view=AccountSnarkWorkPage />
-                        <Route path="/block-production" view=AccountBlockProductionPage />
-                        <Route path="/delegations" view=AccountDelegationsPage />
-                        <Route path="/tokens" view=AccountTokensPage>
-                            <Route path="/:token_id" view=TokenHoldersMoreDetailsSubpage />
-                            <Route path="" view=move || ().into_view() />
-                        </Route>
+                        <Route path="" view=move || ().into_view() />
                     </Route>
-                    <Route path="/tokens" view=TokensPage />
+                </Route>
+                <Route path="/tokens" view=TokensPage />
+                <Route path="/watchlist" view=WatchlistPage />
+                <Route path="/address-book" view=AddressBookPage />
 
-                    <Route path="/blocks" view=SummaryPage />
-                    <Route path="/blocks/:id" view=BlockTabbedPage>
-                        <Route
-                            path="/*"
-                            view=move || {
-                                view! {
-                                    <Redirect
-                                        path="spotlight"
-                                        options=NavigateOptions {
-                                            replace: true,
-                                            ..Default::default()
-                                        }
-                                    />
-                                }
+                <Route path="/blocks" view=SummaryPage />
+                <Route
+                    path="/blocks/:id"
+                    view=move || {
+                        view! {
+                            <ValidRouteParam validate=StateHash::validate>
+                                <BlockTabbedPage />
+                            </ValidRouteParam>
+                        }
+                    }
+                >
+                    <Route
+                        path="/*"
+                        view=move || {
+                            view! {
+                                <Redirect
+                                    path="spotlight"
+                                    options=NavigateOptions {
+                                        replace: true,
+                                        ..Default::default()
+                                    }
+                                />
                             }
-                        />
-                        <Route path="/spotlight" view=BlockSpotlightTab />
-                        <Route path="/snark-jobs" view=BlockSnarkJobsTab />
-                        <Route path="/commands/user" view=BlockUserCommandsTab />
-                        <Route path="/commands/internal" view=BlockInternalCommandsTab />
-                        <Route path="/analytics" view=BlockAnalyticsTab />
-                    </Route>
+                        }
+                    />
+                    <Route path="/spotlight" view=BlockSpotlightTab />
+                    <Route path="/snark-jobs" view=BlockSnarkJobsTab />
+                    <Route path="/commands/user" view=BlockUserCommandsTab />
+                    <Route path="/commands/internal" view=BlockInternalCommandsTab />
+                    <Route path="/analytics" view=BlockAnalyticsTab />
+                </Route>
 
-                    <Route path="/commands" view=CommandsTabbedPage>
-                        <Route
-                            path="*"
-                            view=move || {
-                                view! {
-                                    <Redirect
-                                        path="user"
-                                        options=NavigateOptions {
-                                            replace: true,
-                                            ..Default::default()
-                                        }
-                                    />
-                                }
+                <Route path="/commands" view=CommandsTabbedPage>
+                    <Route
+                        path="*"
+                        view=move || {
+                            view! {
+                                <Redirect
+                                    path="user"
+                                    options=NavigateOptions {
+                                        replace: true,
+                                        ..Default::default()
+                                    }
+                                />
                             }
-                        />
-                        <Route path="/user" view=UserCommandsPage />
-                        <Route path="/pending" view=PendingCommandsPage />
-                        <Route path="/internal" view=InternalCommandsTab />
-                    // <Route path="/zk-app" view=ZkAppTransactionsPage />
-                    </Route>
-                    <Route path="/commands/:id" view=CommandSpotlightPage />
-                    // <Route path="/commands/zk-app/:id" view=ZkAppTransactionSpotlightPage />
-                    <Route path="/snarks" view=SnarksPage />
+                        }
+                    />
+                    <Route path="/user" view=UserCommandsPage />
+                    <Route path="/pending" view=PendingCommandsPage />
+                    <Route path="/internal" view=InternalCommandsTab />
+                // <Route path="/zk-app" view=ZkAppTransactionsPage />
+                </Route>
+                <Route
+                    path="/commands/:id"
+                    view=move || {
+                        view! {
+                            <ValidRouteParam validate=TxnHash::validate>
+                                <CommandSpotlightPage />
+                            </ValidRouteParam>
+                        }
+                    }
+                />
+                // <Route path="/commands/zk-app/:id" view=ZkAppTransactionSpotlightPage />
+                <Route path="/snarks" view=SnarksPage />
 
-                    <Route path="/staking-ledgers" view=StakesPage />
+                <Route path="/staking-ledgers" view=StakesPage />
 
-                    <Route path="/broadcast" view=DelegationTabbedPage>
-                        <Route
-                            path="/*"
-                            view=move || {
-                                view! {
-                                    <Redirect
-                                        path="transaction"
-                                        options=NavigateOptions {
-                                            replace: true,
-                                            ..Default::default()
-                                        }
-                                    />
-                                }
+                <Route path="/broadcast" view=DelegationTabbedPage>
+                    <Route
+                        path="/*"
+                        view=move || {
+                            view! {
+                                <Redirect
+                                    path="transaction"
+                                    options=NavigateOptions {
+                                        replace: true,
+                                        ..Default::default()
+                                    }
+                                />
                             }
-                        />
-                        <Route path="/transaction" view=BroadcastTransactionPage />
-                        <Route path="/delegation" view=BroadcastDelegationPage />
-                        <Route path="/ledger" view=BroadcastFromLedgerPage />
-                    </Route>
-                    <Route path="/analytics" view=AnalyticsTabbedPage>
-                        <Route
-                            path="*"
-                            view=move || {
-                                view! {
-                                    <Redirect
-                                        path="blocks"
-                                        options=NavigateOptions {
-                                            replace: true,
-                                            ..Default::default()
-                                        }
-                                    />
-                                }
+                        }
+                    />
+                    <Route path="/transaction" view=BroadcastTransactionPage />
+                    <Route path="/delegation" view=BroadcastDelegationPage />
+                    <Route path="/ledger" view=BroadcastFromLedgerPage />
+                </Route>
+                <Route path="/analytics" view=AnalyticsTabbedPage>
+                    <Route
+                        path="*"
+                        view=move || {
+                            view! {
+                                <Redirect
+                                    path="blocks"
+                                    options=NavigateOptions {
+                                        replace: true,
+                                        ..Default::default()
+                                    }
+                                />
                             }
-                        />
-                        <Route path="/commands/user" view=UserCommandsAnalyticsPage />
-                        <Route path="/blocks" view=BlocksAnalyticsPage />
-                        <Route path="/snarks" view=SnarksAnalyticsPage />
-                        <Route path="/staker-leaderboard" view=StakerLeaderboardPage />
-                        <Route path="/snarker-leaderboard" view=SnarkerLeaderboardPage />
-                    </Route>
-                </Routes>
-            </main>
-            <Footer />
-        </Router>
+                        }
+                    />
+                    <Route path="/commands/user" view=UserCommandsAnalyticsPage />
+                    <Route path="/blocks" view=BlocksAnalyticsPage />
+                    <Route path="/snarks" view=SnarksAnalyticsPage />
+                    <Route path="/staker-leaderboard" view=StakerLeaderboardPage />
+                    <Route path="/snarker-leaderboard" view=SnarkerLeaderboardPage />
+                </Route>
+            </Routes>
+        </main>
+        <Footer />
     }
 }
@@ -0,0 +1,182 @@
+//! The `ssr` build: an axum server for the Trunk output of the `hydrate` build.
+//! Pages are rendered into its `index.html`, which the `hydrate` build then
+//! takes over, so that crawlers and link unfurlers, which do not run the app,
+//! see each page's content and social tags. Every other file is served as is.
+
+use crate::{
+    common::{preferences::RequestPreferences, social::SiteOrigin},
+    root::App,
+};
+use axum::{
+    Router,
+    extract::State,
+    http::{HeaderMap, Uri, header},
+    response::{Html, IntoResponse, Redirect, Response},
+    routing::get,
+};
+use futures::StreamExt;
+use leptos::{leptos_dom::ssr_in_order::*, *};
+use leptos_meta::{MetaContext, generate_head_metadata_separated, provide_meta_context};
+use leptos_router::{RouterIntegrationContext, ServerIntegration, provide_server_redirect};
+use std::{cell::RefCell, path::PathBuf, rc::Rc, sync::Arc};
+use tokio_util::task::LocalPoolHandle;
+use tower_http::services::ServeDir;
+
+const DEFAULT_SITE_ROOT: &str = "dist";
+const DEFAULT_SITE_ADDR: &str = "127.0.0.1:3000";
+
+/// The directory Trunk built the site into, from `SITE_ROOT`.
+pub fn site_root() -> PathBuf {
+    std::env::var("SITE_ROOT")
+        .unwrap_or_else(|_| DEFAULT_SITE_ROOT.to_string())
+        .into()
+}
+
+#[derive(Clone)]
+struct AppState {
+    index_html: Arc<str>,
+    // Rendering is !Send, so each page renders on one of these threads
+    pool: LocalPoolHandle,
+}
+
+pub async fn serve() {
+    let site_root = site_root();
+    let index_html = std::fs::read_to_string(site_root.join("index.html"))
+        .unwrap_or_else(|err| panic!("No index.html in {}: {}", site_root.display(), err));
+    let threads = std::thread::available_parallelism().map_or(1, |n| n.get());
+    let state = AppState {
+        index_html: index_html.into(),
+        pool: LocalPoolHandle::new(threads),
+    };
+    let files = ServeDir::new(&site_root)
+        .append_index_html_on_directories(false)
+        .fallback(get(render).with_state(state.clone()));
+    let app = Router::new()
+        .route("/", get(render))
+        .fallback_service(files)
+        .with_state(state);
+
+    let addr = std::env::var("SITE_ADDR").unwrap_or_else(|_| DEFAULT_SITE_ADDR.to_string());
+    let listener = tokio::net::TcpListener::bind(&addr)
+        .await
+        .unwrap_or_else(|err| panic!("Cannot listen on {}: {}", addr, err));
+    logging::log!("Serving {} on http://{}", site_root.display(), addr);
+    axum::serve(listener, app).await.expect("server to run");
+}
+
+/// A rendered page, or where its route redirects to.
+enum Page {
+    Html(String),
+    Redirect(String),
+}
+
+async fn render(State(state): State<AppState>, headers: HeaderMap, uri: Uri) -> Response {
+    let scheme = headers
+        .get("x-forwarded-proto")
+        .and_then(|value| value.to_str().ok())
+        .unwrap_or("http");
+    let host = headers
+        .get(header::HOST)
+        .and_then(|value| value.to_str().ok())
+        .unwrap_or("localhost");
+    let origin = format!("{}://{}", scheme, host);
+    let preferences = RequestPreferences::from_headers(
+        headers
+            .get(header::COOKIE)
+            .and_then(|value| value.to_str().ok()),
+        headers
+            .get(header::ACCEPT_LANGUAGE)
+            .and_then(|value| value.to_str().ok()),
+    );
+    let url = format!(
+        "{}{}",
+        origin,
+        uri.path_and_query().map_or("/", |path| path.as_str())
+    );
+    let page = state
+        .pool
+        .spawn_pinned(move || async move {
+            render_page(&state.index_html, origin, url, preferences).await
+        })
+        .await
+        .expect("page to render");
+    match page {
+        Page::Html(html) => Html(html).into_response(),
+        Page::Redirect(path) => Redirect::temporary(&path).into_response(),
+    }
+}
+
+/// Renders the app at `url` into the `<head>` and `<body>` of `index_html`,
+/// and sets the attributes of its `<html>` element. Routes that redirect are
+/// answered with the redirect instead.
+async fn render_page(
+    index_html: &str,
+    origin: String,
+    url: String,
+    preferences: RequestPreferences,
+) -> Page {
+    let head = Rc::new(RefCell::new(String::new()));
+    let redirect = Rc::new(RefCell::new(None));
+    let html_attributes = Rc::new(RefCell::new(String::new()));
+    let (stream, runtime) = render_to_stream_in_order_with_prefix_undisposed_with_context(
+        || view! { <App /> }.into_view(),
+        {
+            let head = Rc::clone(&head);
+            let html_attributes = Rc::clone(&html_attributes);
+            // Called once the blocking resources, and so the social tags, are ready
+            move || {
+                *head.borrow_mut() = generate_head_metadata_separated().0;
+                *html_attributes.borrow_mut() = use_context::<MetaContext>()
+                    .and_then(|meta| meta.html.as_string())
+                    .unwrap_or_default();
+                "".into()
+            }
+        },
+        {
+            let redirect = Rc::clone(&redirect);
+            move || {
+                provide_meta_context();
+                provide_server_redirect(move |path| {
+                    redirect
+                        .borrow_mut()
+                        .get_or_insert_with(|| path.to_string());
+                });
+                provide_context(SiteOrigin(origin));
+                provide_context(preferences);
+                provide_context(RouterIntegrationContext::new(ServerIntegration {
+                    path: url,
+                }));
+            }
+        },
+    );
+    let body = stream.collect::<String>().await;
+    runtime.dispose();
+    if let Some(path) = redirect.take() {
+        return Page::Redirect(path);
+    }
+
+    let head = head.take();
+    let html_attributes = html_attributes.take();
+    Page::Html(
+        with_html_attributes(index_html, &html_attributes)
+            .replacen("</head>", &format!("{}</head>", head), 1)
+            .replacen("</body>", &format!("{}</body>", body), 1),
+    )
+}
+
+/// `index_html` with the attributes of its `<html>` element replaced, unless
+/// there are none to set.
+fn with_html_attributes(index_html: &str, attributes: &str) -> String {
+    let tag = index_html
+        .find("<html")
+        .and_then(|start| Some(start..start + index_html[start..].find('>')? + 1));
+    match tag {
+        Some(tag) if !attributes.is_empty() => format!(
+            "{}<html{}>{}",
+            &index_html[..tag.start],
+            attributes,
+            &index_html[tag.end..]
+        ),
+        _ => index_html.to_string(),
+    }
+}
@@ -1,19 +1,26 @@
 use super::graphql::{snarks_query::SnarksQuerySnarks, *};
-use crate::common::{constants::*, functions::*, models::*};
-use graphql_client::reqwest::post_graphql;
+use crate::common::{
+    client::query_graphql, constants::*, functions::*, models::*, network::Network,
+};
 
 pub async fn load_data(
+    network: &Network,
     limit: Option<i64>,
     prover: Option<String>,
     block_state_hash: Option<String>,
     block_height: Option<u64>,
     canonical: Option<bool>,
+    ranges: RangeFilters,
 ) -> Result<snarks_query::ResponseData, MyError> {
     let variables = snarks_query::Variables {
         sort_by: snarks_query::SnarkSortByInput::BLOCKHEIGHT_DESC,
         limit,
         query: snarks_query::SnarkQueryInput {
             block_height_lte: block_height.map(|x| x as i64),
+            date_time_gte: ranges.date_time.gte,
+            date_time_lte: ranges.date_time.lte,
+            fee_gte: ranges.fee.gte,
+            fee_lte: ranges.fee.lte,
             prover,
             canonical: if canonical.is_none() {
                 Some(true)
@@ -32,19 +39,7 @@ pub async fn load_data(
         },
     };
 
-    let client = reqwest::Client::new();
-
-    let response = post_graphql::<SnarksQuery, _>(&client, GRAPHQL_ENDPOINT, variables)
-        .await
-        .map_err(|e| MyError::NetworkError(e.to_string()))?;
-
-    if let Some(errors) = response.errors {
-        return Err(MyError::GraphQLError(errors));
-    }
-
-    response
-        .data
-        .ok_or(MyError::GraphQLEmpty("No data available".to_string()))
+    query_graphql::<SnarksQuery>(network, variables).await
 }
 
 pub fn get_block_height(snark: &SnarksQuerySnarks) -> String {
@@ -81,8 +76,6 @@ pub fn get_block_state_hash(snark: &SnarksQuerySnarks) -> String {
 pub fn get_fee(snark: &SnarksQuerySnarks) -> String {
     snark
         .fee
-        .map(|f| f.round() as u64)
-        .map(nanomina_to_mina)
-        .map(|number| format_number_for_html(&number, LHS_MAX_SPACE_FEES))
+        .map(|fee| format_number_for_html(&fee.format(), LHS_MAX_SPACE_FEES))
         .unwrap_or_default()
 }
@@ -1,6 +1,16 @@
 use super::functions::*;
 use crate::{
-    common::{components::*, constants::*, models::*, table::*},
+    common::{
+        amount::Amount,
+        components::*,
+        constants::*,
+        datetime::{use_time_settings, validate_date},
+        functions::*,
+        live::*,
+        models::*,
+        network::*,
+        table::*,
+    },
     snarks::graphql::snarks_query,
     summary::models::BlockchainSummary,
 };
@@ -8,10 +18,7 @@ use codee::string::JsonSerdeCodec;
 use leptos::*;
 use leptos_meta::Title;
 use leptos_router::{create_query_signal, use_query_map};
-use leptos_use::{
-    UseIntervalReturn, storage::use_local_storage, use_document_visibility, use_interval,
-};
-use web_sys::VisibilityState;
+use leptos_use::storage::use_local_storage;
 
 #[component]
 pub fn SnarksPage() -> impl IntoView {
@@ -25,36 +32,42 @@ pub fn SnarksPage() -> impl IntoView {
 
 #[component]
 fn SnarksPageContents() -> impl IntoView {
-    let (summary_sig, _, _) =
-        use_local_storage::<BlockchainSummary, JsonSerdeCodec>(BLOCKCHAIN_SUMMARY_STORAGE_KEY);
-    let visibility = use_document_visibility();
+    let (summary_sig, _, _) = use_local_storage::<BlockchainSummary, JsonSerdeCodec>(
+        use_network_storage_key(BLOCKCHAIN_SUMMARY_STORAGE_KEY),
+    );
+    let visible = use_page_visible();
     let (data_sig, set_data) = create_signal(None);
     let query_params_map = use_query_map();
     let (canonical_qp, _) = create_query_signal::<bool>("canonical");
     let (block_height_sig, _) = create_query_signal::<u64>(QUERY_PARAM_HEIGHT);
     let (row_limit_sig, _) = create_query_signal::<i64>("row-limit");
-    let UseIntervalReturn { counter, .. } = use_interval(LIVE_RELOAD_INTERVAL);
+    let live = use_live_updates();
+    let time_settings = use_time_settings().settings;
 
+    let network = use_network();
     let resource = create_resource(
         move || {
             (
-                counter.get(),
+                live.refresh_key(),
                 query_params_map.get(),
+                time_settings.with(|s| s.time_zone.clone()),
                 canonical_qp.get(),
                 block_height_sig.get(),
                 row_limit_sig.get(),
             )
         },
-        move |(_, value, canonical, block_height, mut row_limit)| async move {
-            if visibility.get_untracked() == VisibilityState::Visible {
+        move |(_, value, time_zone, canonical, block_height, mut row_limit)| async move {
+            if visible.get_untracked() {
                 let prover = value.get(QUERY_PARAM_PROVER);
                 let block_state_hash = value.get(QUERY_PARAM_STATE_HASH);
                 load_data(
+                    &network.get_value(),
                     Some(*row_limit.get_or_insert(25i64)),
                     prover.cloned(),
                     block_state_hash.cloned(),
                     block_height,
                     canonical,
+                    get_range_filters(&value, &time_zone),
                 )
                 .await
             } else {
@@ -77,23 +90,29 @@ fn SnarksPageContents() -> impl IntoView {
         },
         TableColumn {
             column: "State Hash".to_string(),
+            validate: Some(StateHash::validate),
             search_type: ColumnSearchType::Text,
             width: Some(String::from(TABLE_COL_HASH_WIDTH)),
             ..Default::default()
         },
         TableColumn {
             column: "Date".to_string(),
+            search_type: ColumnSearchType::DateRange,
+            validate: Some(validate_date),
             width: Some(String::from(TABLE_COL_DATE_WIDTH)),
             ..Default::default()
         },
         TableColumn {
             column: "Prover".to_string(),
+            validate: Some(PublicKey::validate),
             search_type: ColumnSearchType::Text,
             width: Some(String::from(TABLE_COL_HASH_WIDTH)),
             ..Default::default()
         },
         TableColumn {
             column: "Fee".to_string(),
+            search_type: ColumnSearchType::NumberRange,
+            validate: Some(Amount::validate_mina),
             width: Some(String::from(TABLE_COL_NUMERIC_WIDTH)),
             ..Default::default()
         },
@@ -109,6 +128,7 @@ fn SnarksPageContents() -> impl IntoView {
 
     view! {
         <TableSectionTemplate
+            table_id="snarks"
             table_columns
             data_sig
             metadata=Signal::derive(move || {
@@ -117,7 +137,7 @@ fn SnarksPageContents() -> impl IntoView {
                 let block_state_hash = qp_map.get(QUERY_PARAM_STATE_HASH);
                 let mut available_records = None;
                 if prover.is_none() && block_state_hash.is_none()
-                    && block_height_sig.get().is_none()
+                    && block_height_sig.get().is_none() && !has_range_filters(&qp_map)
                 {
                     available_records = canonical_qp
                         .get()
@@ -143,12 +163,32 @@ fn SnarksPageContents() -> impl IntoView {
             })
 
             is_loading=resource.loading()
+            error=Signal::derive(move || resource.get().and_then(|res| res.err()))
+            on_retry=Callback::new(move |_| resource.refetch())
             section_heading="SNARKs"
             footer=move || {
                 view! {
-                    <NextBlockPage
-                        data=data_sig.get().unwrap_or(vec![])
-                        row_limit=row_limit_sig.get().map(|l| l as u64)
+                    <NextPage
+                        data_sig
+                        set_data_sig=set_data
+                        row_limit=Signal::derive(move || row_limit_sig.get().map(|l| l as u64))
+                        fetch=move |height, limit| async move {
+                            let q_map = query_params_map.get_untracked();
+                            load_data(
+                                    &network.get_value(),
+                                    Some(limit as i64),
+                                    q_map.get(QUERY_PARAM_PROVER).cloned(),
+                                    q_map.get(QUERY_PARAM_STATE_HASH).cloned(),
+                                    height,
+                                    canonical_qp.get_untracked(),
+                                    get_range_filters(
+                                        &q_map,
+                                        &time_settings.with_untracked(|s| s.time_zone.clone()),
+                                    ),
+                                )
+                                .await
+                                .map(|data| data.snarks)
+                        }
                     />
                 }
             }
@@ -1,5 +1,5 @@
 use super::{functions::*, graphql::snarks_query};
-use crate::common::{functions::*, models::HasBlockHeight, table::*};
+use crate::common::{functions::*, pagination::Paginated, table::*};
 use leptos::*;
 use snarks_query::SnarksQuerySnarks;
 
@@ -13,10 +13,7 @@ impl TableData for Vec<Option<SnarksQuerySnarks>> {
                         get_block_state_hash(snark),
                         format!("/blocks/{}/snark-jobs", get_block_state_hash(snark)),
                     ),
-                    convert_to_title(
-                        convert_to_local_timezone_formatted(&get_date_time(snark)),
-                        get_date_time(snark),
-                    ),
+                    convert_to_date_time(get_date_time(snark)),
                     convert_to_linkable_address(get_prover_username(snark), get_prover(snark)),
                     convert_to_span(get_fee(snark)),
                 ],
@@ -24,10 +21,36 @@ impl TableData for Vec<Option<SnarksQuerySnarks>> {
             })
             .collect::<Vec<_>>()
     }
+
+    fn get_raw_rows(&self) -> Vec<Vec<String>> {
+        self.iter()
+            .flatten()
+            .map(|snark| {
+                vec![
+                    opt_to_string(snark.block_height),
+                    get_block_state_hash(snark),
+                    get_date_time(snark),
+                    get_prover(snark),
+                    opt_to_string(snark.fee.map(|fee| fee.to_mina_string())),
+                ]
+            })
+            .collect()
+    }
 }
 
-impl HasBlockHeight for SnarksQuerySnarks {
-    fn block_height(&self) -> Option<i64> {
-        self.block_height
+impl Paginated for SnarksQuerySnarks {
+    type Cursor = u64;
+
+    fn cursor(&self) -> Option<u64> {
+        self.block_height.map(|h| h as u64)
+    }
+
+    fn key(&self) -> Option<String> {
+        Some(format!(
+            "{}-{}-{}",
+            get_block_state_hash(self),
+            get_prover(self),
+            opt_to_string(self.fee)
+        ))
     }
 }
@@ -1,7 +1,7 @@
 use super::{functions::*, models::*};
 use crate::{
-    common::{components::*, constants::*, functions::*, models::*, table::*},
-    stakes::graphql::staking_ledgers_query::{StakesSortByInput, StakingLedgersQueryStakes},
+    common::{components::*, constants::*, functions::*, models::*, network::*, table::*},
+    stakes::graphql::staking_ledgers_query::StakesSortByInput,
 };
 use leptos::*;
 use leptos_router::*;
@@ -58,6 +58,7 @@ pub fn StakesPageContents(
             },
             TableColumn {
                 column: "Delegate".to_string(),
+                validate: Some(PublicKey::validate),
                 search_type: ColumnSearchType::Text,
                 width: Some(String::from(TABLE_COL_HASH_WIDTH)),
                 ..Default::default()
@@ -66,6 +67,7 @@ pub fn StakesPageContents(
     }
 
     let (metadata_sig, set_metadata) = create_signal(None);
+    let epoch_slots = use_network().with_value(|n| n.epoch_slots as u64);
     let header_epoch = selected_epoch.unwrap_or(current_epoch);
     let next_epoch = header_epoch + 1;
     let prev_epoch = header_epoch.saturating_sub(1); // prevents underflow
@@ -93,10 +95,7 @@ pub fn StakesPageContents(
     let public_key_memo = Memo::new(move |_| {
         account_sig
             .get()
-            .and_then(|account| match PublicKey::new(account) {
-                Ok(public_key) => Some(public_key),
-                Err(_) => None,
-            })
+            .and_then(|account| PublicKey::new(account).ok())
     });
     let username_memo = Memo::new(move |_| {
         account_sig
@@ -111,6 +110,7 @@ pub fn StakesPageContents(
             })
     });
 
+    let network = use_network();
     let resource = create_resource(
         move || {
             (
@@ -146,6 +146,7 @@ pub fn StakesPageContents(
             }
 
             load_data(
+                &network.get_value(),
                 Some(*row_limit.get_or_insert(25i64)),
                 Some(epoch_opt.unwrap_or(current_epoch)),
                 public_key,
@@ -192,36 +193,53 @@ pub fn StakesPageContents(
         }));
     });
 
+    let (network_id, current_chain_id, previous_chain) =
+        use_network().with_value(|n| (n.id.clone(), n.chain_id.clone(), n.previous_chain.clone()));
+
     {
         move || {
             let chain_id_clone = chain_id.to_string();
+            let network_id = network_id.clone();
+            let current_chain_id = current_chain_id.clone();
+            let previous_chain = previous_chain.clone();
             let s_dir = sort_dir
                 .get()
                 .and_then(|s| StakesSort::try_from(s).ok())
                 .unwrap_or(StakesSort::StakeDesc);
+            // Pages follow a stake upper bound, which only works sorting down.
+            let is_pageable = matches!(s_dir, StakesSort::StakeDesc);
             let table_columns = create_table_columns(AnySort::Stakes(s_dir));
             view! {
                 <TableSectionTemplate
+                    table_id="staking-ledger"
                     table_columns
                     data_sig
                     metadata=metadata_sig.into()
                     section_heading=section_heading_sig.get()
                     is_loading=resource.loading()
+                    error=Signal::derive(move || resource.get().and_then(|res| res.err()))
+                    on_retry=Callback::new(move |_| resource.refetch())
                     controls=move || {
                         view! {
                             <div class="hidden md:flex justify-center items-center space-x-4">
                                 <RowLimit />
-                                <UrlParamSelectMenu
-                                    id="fork_selection"
-                                    query_str_key="post-fork"
-                                    labels=UrlParamSelectOptions {
-                                        is_boolean_option: true,
-                                        cases: vec![
-                                            format!("mainnet {}", MAINNET_2_CHAIN_ID),
-                                            format!("mainnet {}", MAINNET_1_CHAIN_ID),
-                                        ],
-                                    }
-                                />
+                                {previous_chain
+                                    .clone()
+                                    .map(|previous| {
+                                        view! {
+                                            <UrlParamSelectMenu
+                                                id="fork_selection"
+                                                query_str_key="post-fork"
+                                                labels=UrlParamSelectOptions {
+                                                    is_boolean_option: true,
+                                                    cases: vec![
+                                                        format!("{} {}", network_id, current_chain_id),
+                                                        format!("{} {}", network_id, previous.chain_id),
+                                                    ],
+                                                }
+                                            />
+                                        }
+                                    })}
                                 <EpochButton
                                     disabled=prev_epoch_sig
                                         .get()
@@ -235,8 +253,12 @@ pub fn StakesPageContents(
                                     disabled=next_epoch_opt.is_none()
                                         || next_epoch_opt
                                             .is_some_and(|ne| {
-                                                ne == LAST_EPOCH_OF_MAINNET_CHAIN
-                                                    && chain_id_clone == MAINNET_1_CHAIN_ID
+                                                previous_chain
+                                                    .as_ref()
+                                                    .is_some_and(|previous| {
+                                                        ne > previous.last_epoch
+                                                            && chain_id_clone == previous.chain_id
+                                                    })
                                             })
                                     text="Next"
                                     style_variant=ButtonStyleVariant::Primary
@@ -246,12 +268,34 @@ pub fn StakesPageContents(
                         }
                     }
                     footer=move || {
-                        view! {
-                            <NextStakePage
-                                data=data_sig.get().unwrap_or(vec![])
-                                row_limit=row_limit_sig.get()
-                            />
-                        }
+                        is_pageable
+                            .then(|| {
+                                view! {
+                                    <NextPage
+                                        data_sig
+                                        set_data_sig=set_data
+                                        row_limit=Signal::derive(move || {
+                                            row_limit_sig.get().map(|rl| rl as u64)
+                                        })
+                                        fetch=move |stake, limit| async move {
+                                            load_data(
+                                                    &network.get_value(),
+                                                    Some(limit as i64),
+                                                    Some(selected_epoch.unwrap_or(current_epoch)),
+                                                    public_key_memo.get_untracked(),
+                                                    query_params_map
+                                                        .with_untracked(|q| q.get(QUERY_PARAM_DELEGATE).cloned()),
+                                                    stake,
+                                                    StakesSortByInput::STAKE_DESC,
+                                                    genesis_state_hash_sig.get_untracked(),
+                                                    username_memo.get_untracked(),
+                                                )
+                                                .await
+                                                .map(|data| data.stakes)
+                                        }
+                                    />
+                                }
+                            })
                     }
                     additional_info=move || {
                         view! {
@@ -275,7 +319,7 @@ pub fn StakesPageContents(
                                         if current_epoch == next_epoch - 1 {
                                             slot_in_epoch
                                         } else {
-                                            EPOCH_SLOTS as u64
+                                            epoch_slots
                                         }
                                     }
                                     None => 0_u64,
@@ -284,10 +328,10 @@ pub fn StakesPageContents(
                                     <div class="text-sm text-slate-500 staking-ledger-percent-complete">
                                         {format!(
                                             "{:.0}% complete ({}/{} slots filled)",
-                                            (resolved_slot_in_epoch as f64 / EPOCH_SLOTS as f64)
+                                            (resolved_slot_in_epoch as f64 / epoch_slots as f64)
                                                 * 100.0,
                                             format_number(resolved_slot_in_epoch.to_string()),
-                                            format_number(EPOCH_SLOTS.to_string()),
+                                            format_number(epoch_slots.to_string()),
                                         )}
 
                                     </div>
@@ -302,43 +346,6 @@ pub fn StakesPageContents(
     }
 }
 
-#[component]
-pub fn NextStakePage(
-    data: Vec<Option<StakingLedgersQueryStakes>>,
-    row_limit: Option<i64>,
-) -> impl IntoView {
-    let (_, set_stake) = create_query_signal_with_options::<String>(
-        QUERY_PARAM_STAKE,
-        NavigateOptions {
-            scroll: false,
-            ..Default::default()
-        },
-    );
-    let mut last_stake = None;
-    if let Some(Some(last_row)) = data.last() {
-        last_stake = last_row
-            .delegation_totals
-            .as_ref()
-            .and_then(|delegation_totals| delegation_totals.total_delegated_nanomina)
-            .map(|stake| nanomina_to_mina(stake as u64))
-            .as_ref()
-            .and_then(|stake| normalize_number_format(stake).ok())
-    }
-    let last_stake_clone = last_stake.clone();
-    view! {
-        <div class="w-full flex justify-center items-center p-4">
-            <Button
-                style_variant=ButtonStyleVariant::Tertiary
-                text="Load Next"
-                on_click=move |_| { set_stake.set(last_stake.clone()) }
-                class_str="ml-2"
-                disabled=data.len() as i64 != row_limit.unwrap_or(TABLE_ROW_LIMIT as i64)
-                    || *"0.0" == last_stake_clone.unwrap_or("0.0".to_string())
-            />
-        </div>
-    }
-}
-
 #[component]
 pub fn EpochButton(
     #[prop(into)] text: String,
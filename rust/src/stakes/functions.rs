@@ -1,8 +1,9 @@
 use super::graphql::{
     StakingLedgersQuery, staking_ledgers_query, staking_ledgers_query::StakingLedgersQueryStakes,
 };
-use crate::common::{constants::*, functions::*, models::*};
-use graphql_client::reqwest::post_graphql;
+use crate::common::{
+    amount::Amount, client::query_graphql, functions::*, models::*, network::Network,
+};
 
 pub fn get_public_key(stake: &StakingLedgersQueryStakes) -> String {
     stake
@@ -16,8 +17,8 @@ pub fn get_stake(stake: &StakingLedgersQueryStakes) -> String {
         .delegation_totals
         .as_ref()
         .and_then(|delegation_totals| delegation_totals.total_delegated_nanomina)
-        .map(|stake| nanomina_to_mina(stake as u64))
-        .map(|number| format_number_for_html(&number, 11))
+        .map(Amount::from_nanomina)
+        .map(|stake| format_number_for_html(&stake.format(), 11))
         .unwrap_or("0".to_string())
 }
 
@@ -71,9 +72,8 @@ pub fn get_username(stake: &StakingLedgersQueryStakes) -> Option<String> {
 pub fn get_balance(stake: &StakingLedgersQueryStakes) -> String {
     stake
         .balance
-        .map(|number| format!("{:.1}", number))
-        .map(format_mina)
-        .map(|number| format_number_for_html(&number, 11))
+        .and_then(|mina| Amount::from_mina(&mina.to_string()).ok())
+        .map(|balance| format_number_for_html(&balance.format(), 11))
         .unwrap_or_default()
 }
 
@@ -87,6 +87,7 @@ pub fn get_delegators_count(stake: &StakingLedgersQueryStakes) -> String {
 
 #[allow(clippy::too_many_arguments)]
 pub async fn load_data(
+    network: &Network,
     limit: Option<i64>,
     epoch: Option<u64>,
     public_key: Option<PublicKey>,
@@ -97,7 +98,7 @@ pub async fn load_data(
     username: Option<String>,
 ) -> Result<staking_ledgers_query::ResponseData, MyError> {
     if stake.is_some() && normalize_number_format(stake.as_deref().unwrap()).is_err() {
-        return Err(MyError::ParseError(
+        return Err(MyError::InvalidInput(
             "Unable to normalize stake input".to_string(),
         ));
     }
@@ -117,17 +118,5 @@ pub async fn load_data(
         },
     };
 
-    let client = reqwest::Client::new();
-
-    let response = post_graphql::<StakingLedgersQuery, _>(&client, GRAPHQL_ENDPOINT, variables)
-        .await
-        .map_err(|e| MyError::NetworkError(e.to_string()))?;
-
-    if let Some(errors) = response.errors {
-        return Err(MyError::GraphQLError(errors));
-    }
-
-    response
-        .data
-        .ok_or(MyError::GraphQLEmpty("No data available".to_string()))
+    query_graphql::<StakingLedgersQuery>(network, variables).await
 }
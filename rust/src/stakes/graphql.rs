@@ -7,7 +7,7 @@ use graphql_client::GraphQLQuery;
 
 #[derive(GraphQLQuery)]
 #[graphql(
-    schema_path = "./graphql/schemas/mina-explorer.graphql",
+    schema_path = "./graphql/schemas/mina-explorer.typed.graphql",
     query_path = "./graphql/queries/staking_ledgers.graphql",
     response_derives = "Serialize,PartialEq,Debug,Clone,Default",
     skip_serializing_none
@@ -129,3 +129,44 @@ impl Default for StakesQueryInput {
         }
     }
 }
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "./graphql/schemas/mina-explorer.typed.graphql",
+    query_path = "./graphql/queries/staking_epochs.graphql",
+    response_derives = "Serialize,PartialEq,Debug,Clone,Default"
+)]
+pub struct LedgerHashQuery;
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "./graphql/schemas/mina-explorer.typed.graphql",
+    query_path = "./graphql/queries/staking_epochs.graphql",
+    response_derives = "Serialize,PartialEq,Debug,Clone,Default"
+)]
+pub struct EpochQuery;
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "./graphql/schemas/mina-explorer.typed.graphql",
+    query_path = "./graphql/queries/staking_epochs.graphql",
+    response_derives = "Serialize,PartialEq,Debug,Clone,Default"
+)]
+pub struct EpochSummaryQuery;
+
+#[cfg(test)]
+mod epoch_query_tests {
+    use super::*;
+
+    #[test]
+    fn test_ledger_hash_is_escaped() {
+        let ledger_hash = r#"jx"} }, "limit": 0"#.to_string();
+        let body = EpochQuery::build_query(epoch_query::Variables {
+            ledger_hash: ledger_hash.clone(),
+        });
+        let json: serde_json::Value =
+            serde_json::from_str(&serde_json::to_string(&body).unwrap()).unwrap();
+        assert_eq!(json["variables"]["ledger_hash"], ledger_hash);
+        assert_eq!(json["operationName"], "EpochQuery");
+    }
+}
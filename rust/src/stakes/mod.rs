@@ -1,6 +1,6 @@
 pub mod components;
 mod functions;
-mod graphql;
+pub mod graphql;
 pub mod models;
 pub mod page;
 mod table_trait;
@@ -1,6 +1,9 @@
-use super::components::*;
+use super::{
+    components::*,
+    graphql::{EpochSummaryQuery, epoch_summary_query},
+};
 use crate::{
-    common::{components::*, constants::*, models::MyError},
+    common::{client::query_graphql, components::*, constants::*, models::MyError, network::*},
     summary::models::BlockchainSummary,
 };
 use codee::string::JsonSerdeCodec;
@@ -8,49 +11,18 @@ use leptos::*;
 use leptos_meta::Title;
 use leptos_router::*;
 use leptos_use::storage::use_local_storage;
-use serde::{Deserialize, Serialize};
-
-#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Default)]
-struct EpochSummary {
-    pub epoch: u64,
-    pub epoch_num_accounts: u64,
-}
-
-#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Default)]
-struct StakesData {
-    pub stakes: Vec<EpochSummary>,
-}
-
-#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Default)]
-struct EpochSummaryResponse {
-    pub data: StakesData,
-}
 
-async fn load_epoch_summary(epoch: Option<u64>) -> Result<EpochSummaryResponse, MyError> {
+async fn load_epoch_summary(
+    network: &Network,
+    epoch: Option<u64>,
+) -> Result<epoch_summary_query::ResponseData, MyError> {
     match epoch {
-        None => Err(MyError::ParseError("epoch not supplied".to_string())),
+        None => Err(MyError::InvalidInput("epoch not supplied".to_string())),
         Some(epoch) => {
-            let query_body = format!(
-                r#"{{"query":"query StakingLedgersQuery($limit: Int = 1, $query: StakesQueryInput!) {{stakes(limit: $limit, query: $query) {{ epoch epoch_num_accounts  }}}}", "variables":{{"query": {{"epoch":{}}}, "limit":{}}},"operationName":"StakingLedgersQuery"}}"#,
-                epoch, 1
-            );
-            let client = reqwest::Client::new();
-            let response = client
-                .post(GRAPHQL_ENDPOINT)
-                .body(query_body)
-                .send()
-                .await
-                .map_err(|e| MyError::NetworkError(e.to_string()))?;
-
-            if response.status().is_success() {
-                let summary = response
-                    .json::<EpochSummaryResponse>()
-                    .await
-                    .map_err(|e| MyError::ParseError(e.to_string()))?;
-                Ok(summary)
-            } else {
-                Err(MyError::NetworkError("Failed to fetch data".into()))
-            }
+            let variables = epoch_summary_query::Variables {
+                epoch: epoch as i64,
+            };
+            query_graphql::<EpochSummaryQuery>(network, variables).await
         }
     }
 }
@@ -59,20 +31,18 @@ async fn load_epoch_summary(epoch: Option<u64>) -> Result<EpochSummaryResponse,
 pub fn StakesPage() -> impl IntoView {
     let epoch_sig = create_query_signal::<u64>("epoch");
     let (post_fork_sig, _) = create_query_signal::<bool>("post-fork");
-    let (summary_sig, _, _) =
-        use_local_storage::<BlockchainSummary, JsonSerdeCodec>(BLOCKCHAIN_SUMMARY_STORAGE_KEY);
+    let (summary_sig, _, _) = use_local_storage::<BlockchainSummary, JsonSerdeCodec>(
+        use_network_storage_key(BLOCKCHAIN_SUMMARY_STORAGE_KEY),
+    );
 
-    let get_chain_id = move || {
-        if post_fork_sig.get().unwrap_or(true) {
-            MAINNET_2_CHAIN_ID
-        } else {
-            MAINNET_1_CHAIN_ID
-        }
-    };
+    let network = use_network();
+
+    let get_chain_id =
+        move || network.with_value(|n| n.chain_id(post_fork_sig.get().unwrap_or(true)));
     let get_current_chain_info = move || {
         summary_sig.get().chain.and_then(|c| {
             let chain_id = get_chain_id();
-            c.clone().get(chain_id).cloned()
+            c.clone().get(&chain_id).cloned()
         })
     };
 
@@ -102,7 +72,7 @@ pub fn StakesPage() -> impl IntoView {
 
     let resource = create_resource(
         move || epoch_sig.0.get(),
-        |epoch| async move { load_epoch_summary(epoch).await },
+        move |epoch| async move { load_epoch_summary(&network.get_value(), epoch).await },
     );
 
     view! {
@@ -132,18 +102,13 @@ pub fn StakesPage() -> impl IntoView {
                         epoch_num_accounts=resource
                             .get()
                             .and_then(|res| res.ok())
-                            .and_then(|s| s.data.stakes.first().cloned())
-                            .map(|s| s.epoch_num_accounts)
+                            .and_then(|s| s.stakes.into_iter().flatten().next())
+                            .and_then(|s| s.epoch_num_accounts)
+                            .map(|n| n as u64)
                         genesis_state_hash=post_fork_sig
                             .get()
-                            .map(|post_fork| {
-                                if post_fork {
-                                    HARDFORK_STATE_HASH.to_string()
-                                } else {
-                                    MAINNET_STATE_HASH.to_string()
-                                }
-                            })
-                        chain_id=get_chain_id().to_string()
+                            .map(|post_fork| network.with_value(|n| n.genesis_state_hash(post_fork)))
+                        chain_id=get_chain_id()
                     />
                 }
             }}
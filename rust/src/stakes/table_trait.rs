@@ -1,5 +1,5 @@
 use super::{functions::*, graphql::staking_ledgers_query};
-use crate::common::{functions::*, models::*, table::*};
+use crate::common::{amount::Amount, functions::*, models::*, pagination::Paginated, table::*};
 use leptos::*;
 use staking_ledgers_query::StakingLedgersQueryStakes;
 
@@ -20,4 +20,47 @@ impl TableData for Vec<Option<StakingLedgersQueryStakes>> {
             })
             .collect::<Vec<_>>()
     }
+
+    fn get_raw_rows(&self) -> Vec<Vec<String>> {
+        self.iter()
+            .flatten()
+            .map(|stake| {
+                let totals = stake.delegation_totals.as_ref();
+                vec![
+                    get_public_key(stake),
+                    opt_to_string(
+                        stake
+                            .balance
+                            .and_then(|mina| Amount::from_mina(&mina.to_string()).ok())
+                            .map(|balance| balance.to_mina_string()),
+                    ),
+                    opt_to_string(
+                        totals
+                            .and_then(|t| t.total_delegated_nanomina)
+                            .map(|n| Amount::from_nanomina(n).to_mina_string()),
+                    ),
+                    opt_to_string(totals.and_then(|t| t.total_stake_percentage.as_ref())),
+                    get_slot_win_likelihood(stake),
+                    get_delegators_count(stake),
+                    get_delegate(stake),
+                ]
+            })
+            .collect()
+    }
+}
+
+impl Paginated for StakingLedgersQueryStakes {
+    /// The stake in MINA, as the `stake` query param takes it.
+    type Cursor = String;
+
+    fn cursor(&self) -> Option<String> {
+        self.delegation_totals
+            .as_ref()
+            .and_then(|delegation_totals| delegation_totals.total_delegated_nanomina)
+            .map(|stake| Amount::from_nanomina(stake).to_mina_string())
+    }
+
+    fn key(&self) -> Option<String> {
+        Some(get_public_key(self))
+    }
 }
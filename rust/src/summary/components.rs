@@ -1,9 +1,5 @@
 use crate::{
-    common::{
-        components::*,
-        constants::{HARDFORK_STATE_HASH, MAINNET_2_CHAIN_ID},
-        functions::*,
-    },
+    common::{components::*, functions::*, network::use_network},
     summary::models::*,
 };
 use leptos::*;
@@ -13,6 +9,7 @@ pub fn SummaryGrid(
     summary: Option<BlockchainSummary>,
     stat: Option<BlockchainStat>,
 ) -> impl IntoView {
+    let network = use_network();
     view! {
         <section class="grid grid-cols-1 sm:grid-cols-2 xl:grid-cols-6 auto-rows-min gap-4 p-4 pt-0">
             <h2 class="h-0 w-0 overflow-hidden absolute">"Summary"</h2>
@@ -23,7 +20,7 @@ pub fn SummaryGrid(
                     summary
                         .as_ref()
                         .and_then(|s| s.chain.as_ref())
-                        .and_then(|c| c.get(MAINNET_2_CHAIN_ID))
+                        .and_then(|c| network.with_value(|n| c.get(&n.chain_id).cloned()))
                         .map(|c| c.latest_epoch.to_string())
                         .unwrap_or_default(),
                 )
@@ -52,7 +49,8 @@ pub fn SummaryGrid(
                 value=summary
                     .as_ref()
                     .map(|s| {
-                        format_mina(s.tot_currency().to_string())
+                        s.tot_currency()
+                            .format()
                             .split('.')
                             .collect::<Vec<_>>()[0]
                             .to_string()
@@ -65,7 +63,8 @@ pub fn SummaryGrid(
                 value=summary
                     .as_ref()
                     .map(|s| {
-                        format_mina(s.circ_supply().to_string())
+                        s.circ_supply()
+                            .format()
                             .split('.')
                             .collect::<Vec<_>>()[0]
                             .to_string()
@@ -107,7 +106,7 @@ pub fn SummaryGrid(
                     .map(|s| {
                         s.genesis_state_hash
                             .clone()
-                            .unwrap_or(HARDFORK_STATE_HASH.to_string())
+                            .unwrap_or(network.with_value(|n| n.genesis_state_hash.to_string()))
                             .chars()
                             .take(7)
                             .collect()
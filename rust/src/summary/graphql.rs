@@ -0,0 +1,9 @@
+use graphql_client::GraphQLQuery;
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "./graphql/schemas/mina-explorer.typed.graphql",
+    query_path = "./graphql/queries/block_producers_stat.graphql",
+    response_derives = "Serialize,PartialEq,Debug,Clone,Default"
+)]
+pub struct BlockProducersStatQuery;
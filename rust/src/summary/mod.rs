@@ -1,4 +1,5 @@
 mod components;
 mod functions;
+pub mod graphql;
 pub mod models;
 pub mod page;
@@ -1,3 +1,5 @@
+use super::graphql::block_producers_stat_query::BlockProducersStatQueryBlocks;
+use crate::common::amount::Amount;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
@@ -76,44 +78,51 @@ impl BlockchainSummary {
     }
 }
 
-#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Default)]
-pub struct BlockchainStatData {
-    pub blocks: Vec<BlockchainStat>,
-}
-
-#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Default)]
-pub struct BlockchainStatResponse {
-    pub data: BlockchainStatData,
-}
-
 #[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Default)]
 pub struct BlockchainStat {
     pub num_unique_block_producers_last_n_blocks: u64,
 }
 
+impl From<BlockProducersStatQueryBlocks> for BlockchainStat {
+    fn from(block: BlockProducersStatQueryBlocks) -> Self {
+        BlockchainStat {
+            num_unique_block_producers_last_n_blocks: block
+                .num_unique_block_producers_last_n_blocks
+                .unwrap_or_default() as u64,
+        }
+    }
+}
+
 impl BlockchainSummary {
-    pub fn circ_supply(&self) -> f64 {
-        self.circulating_supply.trim().parse().map_or(0.0, |r| r)
+    pub fn circ_supply(&self) -> Amount {
+        Amount::from_mina(&self.circulating_supply).unwrap_or_default()
     }
-    pub fn tot_currency(&self) -> f64 {
-        self.total_currency.trim().parse().map_or(0.0, |r| r)
+    pub fn tot_currency(&self) -> Amount {
+        Amount::from_mina(&self.total_currency).unwrap_or_default()
     }
 }
 
 #[cfg(test)]
-mod float_tests {
+mod supply_tests {
 
     use super::*;
 
     #[test]
-    fn test_parsing_floats() {
+    fn test_parsing_supply() {
         let bs = BlockchainSummary {
             circulating_supply: "2345345.4312431243".to_owned(),
             total_currency: "1105297372.840039233".to_owned(),
             ..Default::default()
         };
-        assert_eq!(bs.circ_supply(), 2345345.4312431243);
-        assert_eq!(bs.tot_currency(), 1_105_297_372.840_039_3)
+        assert_eq!(
+            bs.circ_supply(),
+            Amount::from_nanomina(2_345_345_431_243_124i64)
+        );
+        assert_eq!(
+            bs.tot_currency(),
+            Amount::from_nanomina(1_105_297_372_840_039_233i64)
+        );
+        assert_eq!(BlockchainSummary::default().tot_currency(), Amount::ZERO);
     }
 }
 
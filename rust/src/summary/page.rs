@@ -4,63 +4,74 @@ use super::{
     models::{BlockchainSummary, ChainSummary},
 };
 use crate::{
-    blocks::components::BlocksSection,
-    common::{components::*, constants::*},
-    summary::models::{BlockchainStat, BlockchainStatData, BlockchainStatResponse},
+    advanced_filter::{components::AdvancedFilterSection, functions::FilterTarget},
+    blocks::{components::BlocksSection, graphql::blocks_query::BlockQueryInput},
+    common::{components::*, constants::*, live::*, network::*},
+    summary::models::BlockchainStat,
 };
 use codee::string::JsonSerdeCodec;
 use leptos::*;
 use leptos_meta::Title;
-use leptos_use::{UseIntervalReturn, storage::*, use_document_visibility, use_interval};
+use leptos_use::storage::*;
 use std::collections::HashMap;
-use web_sys::VisibilityState;
 
 #[component]
 pub fn SummaryPage() -> impl IntoView {
-    let (summary_sig, _, _) =
-        use_local_storage::<BlockchainSummary, JsonSerdeCodec>(BLOCKCHAIN_SUMMARY_STORAGE_KEY);
-    let (stat_sig, _, _) = use_local_storage::<BlockchainStat, JsonSerdeCodec>("blockchain-stat");
+    let (summary_sig, _, _) = use_local_storage::<BlockchainSummary, JsonSerdeCodec>(
+        use_network_storage_key(BLOCKCHAIN_SUMMARY_STORAGE_KEY),
+    );
+    let (stat_sig, _, _) = use_local_storage::<BlockchainStat, JsonSerdeCodec>(
+        use_network_storage_key("blockchain-stat"),
+    );
 
     view! {
         <Title text="Blocks | Search for blocks on Mina Blockchain" />
         <PageContainer>
             {move || {
                 view! { <SummaryGrid summary=Some(summary_sig.get()) stat=Some(stat_sig.get()) /> }
-            }} <BlocksSection />
+            }} <AdvancedFilterSection fields=BlockQueryInput::FIELDS /> <BlocksSection />
         </PageContainer>
     }
 }
 
 #[component]
 pub fn SummaryLocalStorage() -> impl IntoView {
-    let (summary_sig, set_summary, _) =
-        use_local_storage::<BlockchainSummary, JsonSerdeCodec>(BLOCKCHAIN_SUMMARY_STORAGE_KEY);
-    let (_, set_stat, _) = use_local_storage::<BlockchainStat, JsonSerdeCodec>("blockchain-stat");
-    let visibility = use_document_visibility();
-    let UseIntervalReturn { counter, .. } = use_interval(LIVE_RELOAD_INTERVAL);
+    let (summary_sig, set_summary, _) = use_local_storage::<BlockchainSummary, JsonSerdeCodec>(
+        use_network_storage_key(BLOCKCHAIN_SUMMARY_STORAGE_KEY),
+    );
+    let (_, set_stat, _) = use_local_storage::<BlockchainStat, JsonSerdeCodec>(
+        use_network_storage_key("blockchain-stat"),
+    );
+    let visible = use_page_visible();
+    let live = use_live_updates();
+    let network = use_network();
+    let last_poll = store_value(None);
 
     let resource = create_resource(
-        move || counter.get(),
-        move |_| async move {
-            if visibility.get_untracked() == VisibilityState::Visible {
-                load_data().await
-            } else {
+        move || live.refresh_key(),
+        move |(height, poll)| async move {
+            let polled = last_poll.get_value() != Some(poll);
+            last_poll.set_value(Some(poll));
+            if !visible.get_untracked() {
                 logging::log!("Document not visible. Data polling skipped for summary endpoint.");
-                Ok(summary_sig.get_untracked())
+                return Ok(summary_sig.get_untracked());
+            }
+            // Skip the refetch triggered by announcing a tip this summary reported.
+            if !polled && height == Some(summary_sig.get_untracked().blockchain_length) {
+                return Ok(summary_sig.get_untracked());
             }
+            load_data(&network.get_value()).await
         },
     );
 
     let unique_blocks_producers_resource = create_resource(
-        move || (counter.get()),
+        move || live.latest_height(),
         move |_| async move {
-            if visibility.get_untracked() == VisibilityState::Visible {
-                load_block_producers_stat(10000).await
+            if visible.get_untracked() {
+                load_block_producers_stat(&network.get_value(), 10000).await
             } else {
                 logging::log!("Document not visible. Data polling skipped for summary endpoint.");
-                Ok(BlockchainStatResponse {
-                    data: BlockchainStatData { blocks: vec![] },
-                })
+                Ok(None)
             }
         },
     );
@@ -70,7 +81,7 @@ pub fn SummaryLocalStorage() -> impl IntoView {
         get_data().map(|data: BlockchainSummary| {
             let mut chain = HashMap::new();
             chain.insert(
-                MAINNET_2_CHAIN_ID.to_string(),
+                network.with_value(|n| n.chain_id.to_string()),
                 ChainSummary {
                     #[allow(deprecated)]
                     latest_epoch: data.epoch,
@@ -78,13 +89,15 @@ pub fn SummaryLocalStorage() -> impl IntoView {
                     latest_slot: data.slot,
                 },
             );
-            chain.insert(
-                MAINNET_1_CHAIN_ID.to_string(),
-                ChainSummary {
-                    latest_epoch: 79,
-                    latest_slot: EPOCH_SLOTS as u64,
-                },
-            );
+            if let Some(previous) = network.with_value(|n| n.previous_chain.clone()) {
+                chain.insert(
+                    previous.chain_id,
+                    ChainSummary {
+                        latest_epoch: previous.last_epoch,
+                        latest_slot: network.with_value(|n| n.epoch_slots as u64),
+                    },
+                );
+            }
             BlockchainSummary {
                 chain: Some(chain),
                 ..data
@@ -94,15 +107,19 @@ pub fn SummaryLocalStorage() -> impl IntoView {
 
     create_effect(move |_| {
         if let Some(blockchain_summary) = augment_data() {
+            let event = NewBlockEvent {
+                blockchain_length: blockchain_summary.blockchain_length,
+                state_hash: None,
+            };
             set_summary.set(blockchain_summary);
+            live.announce(event);
         }
         if let Some(blockchain_stat) = unique_blocks_producers_resource
             .get()
             .and_then(|res| res.ok())
+            .flatten()
         {
-            if let Some(block) = blockchain_stat.data.blocks.first() {
-                set_stat.set(block.clone());
-            }
+            set_stat.set(blockchain_stat);
         }
     });
 
@@ -1,5 +1,5 @@
 mod components;
 mod functions;
-mod graphql;
+pub mod graphql;
 mod models;
 pub mod page;
@@ -1,10 +1,12 @@
 use super::{components::TokenHoldersMoreDetails, functions::load_data};
+use crate::common::network::use_network;
 use leptos::*;
 use leptos_router::use_params_map;
 
 #[component]
 pub fn TokenHoldersMoreDetailsSubpage() -> impl IntoView {
     let memo_params_map = use_params_map();
+    let network = use_network();
 
     let resource = create_resource(
         move || {
@@ -22,7 +24,12 @@ pub fn TokenHoldersMoreDetailsSubpage() -> impl IntoView {
             )
         },
         move |(account, token_id)| async move {
-            load_data(account.to_string(), token_id.to_string()).await
+            load_data(
+                &network.get_value(),
+                account.to_string(),
+                token_id.to_string(),
+            )
+            .await
         },
     );
 
@@ -2,10 +2,10 @@ use super::graphql::{
     TokensQuery,
     tokens_query::{self, TokensSortByInput},
 };
-use crate::common::{constants::GRAPHQL_ENDPOINT, models::MyError};
-use graphql_client::reqwest::post_graphql;
+use crate::common::{client::query_graphql, models::MyError, network::Network};
 
 pub async fn load_data(
+    network: &Network,
     limit: Option<i64>,
     name: Option<String>,
     token: Option<String>,
@@ -34,17 +34,5 @@ pub async fn load_data(
         sort_by,
     };
 
-    let client = reqwest::Client::new();
-
-    let response = post_graphql::<TokensQuery, _>(&client, GRAPHQL_ENDPOINT, variables)
-        .await
-        .map_err(|e| MyError::NetworkError(e.to_string()))?;
-
-    if let Some(errors) = response.errors {
-        return Err(MyError::GraphQLError(errors));
-    }
-
-    response
-        .data
-        .ok_or(MyError::GraphQLEmpty("No data available".to_string()))
+    query_graphql::<TokensQuery>(network, variables).await
 }
@@ -1,4 +1,4 @@
 mod functions;
-mod graphql;
+pub mod graphql;
 pub mod page;
 mod table_trait;
@@ -6,7 +6,8 @@ use crate::{
             QUERY_PARAM_ID, QUERY_PARAM_TOKEN_SYMBOL, TABLE_COL_HASH_WIDTH,
             TABLE_COL_LARGE_BALANCE, TABLE_COL_NUMERIC_WIDTH, TABLE_COL_USERNAME_WIDTH,
         },
-        models::{TableMetadata, UrlParamSelectOptions},
+        models::{TableMetadata, TokenId, UrlParamSelectOptions},
+        network::*,
         table::*,
     },
     tokens::graphql::tokens_query,
@@ -23,10 +24,12 @@ pub fn TokensPage() -> impl IntoView {
     let (name_sig, _) = create_query_signal::<String>(QUERY_PARAM_TOKEN_SYMBOL);
     let (q_token_id_sig, _) = create_query_signal::<String>(QUERY_PARAM_ID);
 
+    let network = use_network();
     let resource = create_resource(
         move || (name_sig.get(), q_token_id_sig.get(), row_limit_sig.get()),
         move |(name_opt, q_token_id_opt, row_limit)| async move {
             load_data(
+                &network.get_value(),
                 row_limit.or(Some(50)),
                 name_opt,
                 q_token_id_opt,
@@ -64,6 +67,7 @@ pub fn TokensPage() -> impl IntoView {
         },
         TableColumn {
             column: "ID".to_string(),
+            validate: Some(TokenId::validate),
             width: Some(String::from(TABLE_COL_HASH_WIDTH)),
             alignment: Some(ColumnTextAlignment::Left),
             search_type: ColumnSearchType::Text,
@@ -102,9 +106,12 @@ pub fn TokensPage() -> impl IntoView {
         <Title text="Tokens | Search For Tokens" />
         <PageContainer>
             <TableSectionTemplate
+                table_id="tokens"
                 table_columns
                 data_sig
                 is_loading=resource.loading()
+                error=Signal::derive(move || resource.get().and_then(|res| res.err()))
+                on_retry=Callback::new(move |_| resource.refetch())
                 controls=move || {
                     view! {
                         // Avoiding RowLimit component so we can set default
@@ -1,9 +1,13 @@
 use super::graphql::tokens_query::TokensQueryTokens;
-use crate::common::{constants::*, functions::*, models::*, table::TableData};
+use crate::common::{
+    amount::Amount, constants::*, functions::*, models::*, network::use_mina_token_address,
+    table::TableData,
+};
 use leptos::{HtmlElement, html};
 
 impl TableData for Vec<Option<TokensQueryTokens>> {
     fn get_rows(&self) -> Vec<Vec<HtmlElement<html::AnyElement>>> {
+        let mina_token_address = use_mina_token_address();
         self.iter()
             .map(|token| {
                 if let Some(token) = token {
@@ -32,7 +36,7 @@ impl TableData for Vec<Option<TokensQueryTokens>> {
                                     QUERY_PARAM_TOKEN,
                                     token.get_token(),
                                     QUERY_PARAM_TYPE,
-                                    if token.get_token() == MINA_TOKEN_ADDRESS {
+                                    if token.get_token() == mina_token_address {
                                         "true"
                                     } else {
                                         "false"
@@ -49,6 +53,23 @@ impl TableData for Vec<Option<TokensQueryTokens>> {
             })
             .collect::<Vec<_>>()
     }
+
+    fn get_raw_rows(&self) -> Vec<Vec<String>> {
+        self.iter()
+            .flatten()
+            .map(|token| {
+                vec![
+                    token.get_symbol(),
+                    Amount::from_nanomina(token.supply).to_mina_string(),
+                    token.get_token(),
+                    token.get_owner().unwrap_or_default(),
+                    token.num_holders.to_string(),
+                    token.total_num_txns.to_string(),
+                    token.get_percent_unlocked().unwrap_or_else(|e| e),
+                ]
+            })
+            .collect()
+    }
 }
 
 pub trait TokensTrait {
@@ -84,7 +105,7 @@ impl TokensTrait for TokensQueryTokens {
             .to_string()
     }
     fn get_supply(&self) -> String {
-        format_number_for_html(&nanomina_str_to_mina(&self.supply.to_string()), 14)
+        format_number_for_html(&Amount::from_nanomina(self.supply).format(), 14)
     }
     fn get_number_of_holders(&self) -> String {
         format_number(self.num_holders.to_string())
@@ -1,19 +1,29 @@
 use super::functions::*;
 use crate::{
-    common::{components::*, constants::*, models::*, table::*},
+    advanced_filter::functions::get_advanced_filter,
+    common::{
+        amount::Amount,
+        components::*,
+        constants::*,
+        datetime::{use_time_settings, validate_date},
+        functions::{format_number, get_range_filters, has_range_filters},
+        i18n::t,
+        live::*,
+        memo::validate_memo,
+        models::*,
+        network::*,
+        table::*,
+    },
     summary::models::BlockchainSummary,
     user_commands::{
         graphql::transactions_query::{self, TransactionsQueryTransactionsZkappAccountsUpdated},
-        models::PendingTxn,
+        models::{ConfirmationStatus, Inclusion, PendingTxn, merge_pending_txns},
     },
 };
 use codee::string::JsonSerdeCodec;
 use leptos::*;
 use leptos_router::*;
-use leptos_use::{
-    UseIntervalReturn, storage::use_local_storage, use_document_visibility, use_interval,
-};
-use web_sys::VisibilityState;
+use leptos_use::storage::use_local_storage;
 
 const BACKSCAN_LIMIT: u64 = 2000;
 
@@ -54,6 +64,7 @@ pub fn AccountsUpdatedSection(
 
     view! {
         <TableSectionTemplate
+            table_id="command-accounts-updated"
             table_columns
             data_sig=account_updates_sig
             metadata=metadata.into()
@@ -66,9 +77,10 @@ pub fn AccountsUpdatedSection(
 
 #[component]
 pub fn TransactionsSection() -> impl IntoView {
-    let (summary_sig, _, _) =
-        use_local_storage::<BlockchainSummary, JsonSerdeCodec>(BLOCKCHAIN_SUMMARY_STORAGE_KEY);
-    let visibility = use_document_visibility();
+    let (summary_sig, _, _) = use_local_storage::<BlockchainSummary, JsonSerdeCodec>(
+        use_network_storage_key(BLOCKCHAIN_SUMMARY_STORAGE_KEY),
+    );
+    let visible = use_page_visible();
     let (data_sig, set_data) = create_signal(None);
     let (txn_type_qp, _) = create_query_signal::<String>(QUERY_PARAM_TXN_TYPE);
     let (row_limit_sig, _) = create_query_signal::<u64>(QUERY_PARAM_ROW_LIMIT);
@@ -78,13 +90,58 @@ pub fn TransactionsSection() -> impl IntoView {
     let (q_token_sig, _) = create_query_signal::<String>(QUERY_PARAM_TOKEN);
     let (q_type_sig, _) = create_query_signal::<TransactionKind>(QUERY_PARAM_TYPE);
     let (token_sig, set_token) = create_signal(None);
-    let UseIntervalReturn { counter, .. } = use_interval(LIVE_RELOAD_INTERVAL);
+    let live = use_live_updates();
+    let time_settings = use_time_settings().settings;
 
+    let network = use_network();
+    // Shared by the resource and "Load Next", which passes its own height and
+    // limit. The other params are read as they are when the page is requested.
+    let load_page = move |block_height: Option<u64>, row_limit: Option<u64>| {
+        let url_query_map = query_params_map.get_untracked();
+        let time_zone = time_settings.with_untracked(|s| s.time_zone.clone());
+        let canonical = match txn_type_qp.get_untracked().as_deref() {
+            Some("Non-Canonical") => Some(false),
+            _ => Some(true),
+        };
+        let is_txn_applied = match txn_applied_sig.get_untracked() {
+            None => None, // by default, assume all txn
+            Some(txn) if txn == STATUS_SEARCH_OPTION_APPLIED => Some(true),
+            Some(txn) if txn == STATUS_SEARCH_OPTION_FAILED => Some(false),
+            _ => None,
+        };
+        let q_type = q_type_sig.get_untracked();
+        let token = q_token_sig.get_untracked();
+        async move {
+            load_data(
+                &network.get_value(),
+                row_limit,
+                url_query_map.get(QUERY_PARAM_FROM).cloned(),
+                url_query_map.get(QUERY_PARAM_TO).cloned(),
+                url_query_map.get(QUERY_PARAM_TXN_HASH).cloned(),
+                block_height,
+                if is_txn_applied.is_some_and(|t| !t) {
+                    Some(BACKSCAN_LIMIT)
+                } else {
+                    None
+                },
+                None,
+                canonical,
+                is_txn_applied,
+                q_type,
+                token,
+                url_query_map.get(QUERY_PARAM_MEMO).cloned(),
+                get_range_filters(&url_query_map, &time_zone),
+                get_advanced_filter(&url_query_map),
+            )
+            .await
+        }
+    };
     let resource = create_resource(
         move || {
             (
-                counter.get(),
+                live.refresh_key(),
                 query_params_map.get(),
+                time_settings.with(|s| s.time_zone.clone()),
                 txn_type_qp.get(),
                 block_height_sig.get(),
                 row_limit_sig.get(),
@@ -93,8 +150,8 @@ pub fn TransactionsSection() -> impl IntoView {
                 q_token_sig.get(),
             )
         },
-        move |(_, url_query_map, txn_type, block_height, row_limit, txn_applied, q_type, token)| async move {
-            if visibility.get_untracked() != VisibilityState::Visible {
+        move |(_, _, _, _, block_height, row_limit, _, _, _)| async move {
+            if !visible.get_untracked() {
                 logging::log!("Document not visible. Data polling skipped for user commands.");
                 return Ok(transactions_query::ResponseData {
                     transactions: data_sig.get().unwrap_or_default(),
@@ -102,38 +159,7 @@ pub fn TransactionsSection() -> impl IntoView {
                     tokens: vec![],
                 });
             }
-
-            let (canonical, load_fn) = match txn_type.as_deref() {
-                Some("Canonical") => (Some(true), load_data),
-                Some("Non-Canonical") => (Some(false), load_data),
-                _ => (Some(true), load_data),
-            };
-
-            let is_txn_applied = match txn_applied {
-                None => None, // by default, assume all txn
-                Some(txn) if txn == STATUS_SEARCH_OPTION_APPLIED => Some(true),
-                Some(txn) if txn == STATUS_SEARCH_OPTION_FAILED => Some(false),
-                _ => None,
-            };
-
-            load_fn(
-                row_limit,
-                url_query_map.get(QUERY_PARAM_FROM).cloned(),
-                url_query_map.get(QUERY_PARAM_TO).cloned(),
-                url_query_map.get(QUERY_PARAM_TXN_HASH).cloned(),
-                block_height,
-                if is_txn_applied.is_some_and(|t| !t) {
-                    Some(BACKSCAN_LIMIT)
-                } else {
-                    None
-                },
-                None,
-                canonical,
-                is_txn_applied,
-                q_type,
-                token,
-            )
-            .await
+            load_page(block_height, row_limit).await
         },
     );
 
@@ -148,12 +174,15 @@ pub fn TransactionsSection() -> impl IntoView {
         },
         TableColumn {
             column: "Txn Hash".to_string(),
+            validate: Some(TxnHash::validate),
             search_type: ColumnSearchType::Text,
             width: Some(String::from(TABLE_COL_HASH_WIDTH)),
             ..Default::default()
         },
         TableColumn {
             column: "Date".to_string(),
+            search_type: ColumnSearchType::DateRange,
+            validate: Some(validate_date),
             width: Some(String::from(TABLE_COL_DATE_WIDTH)),
             ..Default::default()
         },
@@ -182,12 +211,14 @@ pub fn TransactionsSection() -> impl IntoView {
         },
         TableColumn {
             column: "From".to_string(),
+            validate: Some(PublicKey::validate),
             search_type: ColumnSearchType::Text,
             width: Some(String::from(TABLE_COL_HASH_WIDTH)),
             ..Default::default()
         },
         TableColumn {
             column: "To".to_string(),
+            validate: Some(PublicKey::validate),
             search_type: ColumnSearchType::Text,
             width: Some(String::from(TABLE_COL_HASH_WIDTH)),
             ..Default::default()
@@ -200,14 +231,25 @@ pub fn TransactionsSection() -> impl IntoView {
         },
         TableColumn {
             column: "Fee".to_string(),
+            search_type: ColumnSearchType::NumberRange,
+            validate: Some(Amount::validate_mina),
             width: Some(String::from(TABLE_COL_NUMERIC_WIDTH)),
             ..Default::default()
         },
         TableColumn {
             column: "Amount".to_string(),
+            search_type: ColumnSearchType::NumberRange,
+            validate: Some(Amount::validate_mina),
             width: Some(String::from(TABLE_COL_LARGE_BALANCE)),
             ..Default::default()
         },
+        TableColumn {
+            column: "Memo".to_string(),
+            search_type: ColumnSearchType::Text,
+            validate: Some(validate_memo),
+            width: Some(String::from(TABLE_COL_HASH_WIDTH)),
+            ..Default::default()
+        },
     ];
     let get_data = move || resource.get().and_then(|res| res.ok());
 
@@ -220,6 +262,7 @@ pub fn TransactionsSection() -> impl IntoView {
 
     view! {
         <TableSectionTemplate
+            table_id="user-commands"
             table_columns
             data_sig
             metadata=Signal::derive(move || {
@@ -230,7 +273,10 @@ pub fn TransactionsSection() -> impl IntoView {
                         .is_some_and(|q_type| { *q_type != TransactionKind::Zkapp.to_string() })
                     || url_query_map.get(QUERY_PARAM_FROM).is_some()
                     || url_query_map.get(QUERY_PARAM_TO).is_some()
-                    || url_query_map.get(QUERY_PARAM_TXN_HASH).is_some();
+                    || url_query_map.get(QUERY_PARAM_TXN_HASH).is_some()
+                    || url_query_map.get(QUERY_PARAM_MEMO).is_some()
+                    || has_range_filters(&url_query_map)
+                    || url_query_map.get(QUERY_PARAM_FILTER).is_some();
                 let indexes_available = !indexes_not_available;
                 let is_zk_app = q_type_sig.get().is_some_and(|p| p == TransactionKind::Zkapp);
                 let txn_all = txn_applied_sig.get().is_none();
@@ -336,11 +382,13 @@ pub fn TransactionsSection() -> impl IntoView {
             })
 
             is_loading=resource.loading()
+            error=Signal::derive(move || resource.get().and_then(|res| res.err()))
+            on_retry=Callback::new(move |_| resource.refetch())
             section_heading=MaybeSignal::derive(move || {
                 token_sig
                     .get_untracked()
                     .and_then(|token| {
-                        (token.token != MINA_TOKEN_ADDRESS)
+                        (network.with_value(|n| token.token != n.mina_token_address))
                             .then(|| {
                                 token.symbol.map(|symbol| symbol.to_string()).unwrap_or_default()
                             })
@@ -350,9 +398,13 @@ pub fn TransactionsSection() -> impl IntoView {
             })
             footer=move || {
                 view! {
-                    <NextBlockPage
-                        data=data_sig.get().unwrap_or(vec![])
-                        row_limit=row_limit_sig.get()
+                    <NextPage
+                        data_sig
+                        set_data_sig=set_data
+                        row_limit=row_limit_sig
+                        fetch=move |height, limit| async move {
+                            load_page(height, Some(limit)).await.map(|data| data.transactions)
+                        }
                     />
                 }
             }
@@ -377,24 +429,20 @@ pub fn TransactionsSection() -> impl IntoView {
 
 #[component]
 pub fn PendingTransactionsSection() -> impl IntoView {
-    let visibility = use_document_visibility();
+    let visible = use_page_visible();
     let (data_sig, set_data) = create_signal::<Option<Vec<Option<PendingTxn>>>>(None);
     let query_params_map = use_query_map();
-    let UseIntervalReturn { counter, .. } = use_interval(LIVE_RELOAD_INTERVAL);
+    let live = use_live_updates();
 
     let resource = create_resource(
-        move || counter.get(),
+        move || live.refresh_key(),
         move |_| async move {
-            if visibility.get_untracked() != VisibilityState::Visible {
+            if !visible.get_untracked() {
                 logging::log!("Document not visible. Data polling skipped for user commands.");
-                return Ok(transactions_query::ResponseData {
-                    transactions: vec![],
-                    other_transactions: vec![],
-                    tokens: vec![],
-                });
+                return Ok(None);
             }
 
-            load_pending_txn().await
+            load_pending_txn().await.map(Some)
         },
     );
 
@@ -439,21 +487,25 @@ pub fn PendingTransactionsSection() -> impl IntoView {
             ..Default::default()
         },
     ];
-    let get_data = move || resource.get().and_then(|res| res.ok());
+    let get_data = move || resource.get().and_then(|res| res.ok()).flatten();
 
     create_effect(move |_| {
         if let Some(data) = get_data() {
-            set_data.set(Some(
-                data.transactions
-                    .into_iter()
-                    .map(|opt_t| opt_t.map(PendingTxn::from))
-                    .collect::<Vec<Option<PendingTxn>>>(),
-            ))
+            let latest = data
+                .transactions
+                .into_iter()
+                .map(|opt_t| opt_t.map(PendingTxn::from))
+                .collect::<Vec<Option<PendingTxn>>>();
+            set_data.set(Some(merge_pending_txns(
+                &data_sig.get_untracked().unwrap_or_default(),
+                latest,
+            )))
         }
     });
 
     view! {
         <TableSectionTemplate
+            table_id="pending-commands"
             table_columns
             data_sig
             metadata=Signal::derive(move || {
@@ -473,7 +525,193 @@ pub fn PendingTransactionsSection() -> impl IntoView {
             })
 
             is_loading=resource.loading()
+            error=Signal::derive(move || resource.get().and_then(|res| res.err()))
+            on_retry=Callback::new(move |_| resource.refetch())
             section_heading="Pending Commands"
         />
     }
 }
+
+const TRACKER_STEPS: [&str; 3] = ["Pending", "Included", "Final"];
+
+/// Follows a transaction from the pending pool to finality, refreshing with
+/// every new block. Shows `fallback` if the transaction is nowhere to be found.
+#[component]
+pub fn ConfirmationTracker(
+    hash: String,
+    /// Called once a pending transaction lands in a canonical block.
+    #[prop(optional)]
+    on_included: Option<Callback<()>>,
+    #[prop(optional, into)] fallback: ViewFn,
+) -> impl IntoView {
+    let (summary_sig, _, _) = use_local_storage::<BlockchainSummary, JsonSerdeCodec>(
+        use_network_storage_key(BLOCKCHAIN_SUMMARY_STORAGE_KEY),
+    );
+    let live = use_live_updates();
+    let network = use_network();
+    let finality_depth = network.with_value(|n| n.finality_depth);
+    let hash = store_value(hash);
+    let resource = create_local_resource(
+        move || live.refresh_key(),
+        move |_| async move { load_txn_tracking(&network.get_value(), hash.get_value()).await },
+    );
+    let tip = move || {
+        live.latest_height()
+            .unwrap_or_else(|| summary_sig.get().blockchain_length)
+    };
+    let tracking = move || resource.get().and_then(Result::ok);
+    let status = Memo::new(move |_| {
+        tracking().and_then(|tracking| {
+            ConfirmationStatus::new(
+                &tracking.inclusions,
+                tracking.is_pending,
+                tip(),
+                finality_depth,
+            )
+        })
+    });
+
+    create_effect(move |was_pending: Option<bool>| {
+        let status = status.get();
+        if was_pending == Some(true) && status.as_ref().is_some_and(ConfirmationStatus::is_included)
+        {
+            if let Some(on_included) = on_included {
+                on_included.call(());
+            }
+        }
+        matches!(status, Some(ConfirmationStatus::Pending))
+    });
+
+    let block_link = |inclusion: &Inclusion| {
+        view! {
+            <a class=LINK_HOVER_STATE href=format!("/blocks/{}", inclusion.state_hash)>
+                {format_number(inclusion.height.to_string())}
+            </a>
+        }
+    };
+
+    view! {
+        {move || match (resource.get(), status.get()) {
+            (None, _) => ().into_view(),
+            (Some(Err(err)), _) => {
+                view! { <ErrorView err on_retry=Some(Callback::new(move |_| resource.refetch())) /> }
+            }
+            (Some(Ok(_)), None) => fallback.run(),
+            (Some(Ok(tracking)), Some(status)) => {
+                let step = match status {
+                    ConfirmationStatus::Pending | ConfirmationStatus::Orphaned => 0,
+                    ConfirmationStatus::Included { .. } => 1,
+                    ConfirmationStatus::Final { .. } => 2,
+                };
+                let detail = match &status {
+                    ConfirmationStatus::Pending => {
+                        view! { <span>{t("Waiting in the pending pool")}</span> }.into_view()
+                    }
+                    ConfirmationStatus::Orphaned => {
+                        view! { <span>{t("Not in any canonical block or the pending pool")}</span> }
+                            .into_view()
+                    }
+                    ConfirmationStatus::Included { inclusion, confirmations } => {
+                        view! {
+                            <span>{t("Included in block")} " " {block_link(inclusion)}</span>
+                            <span id="confirmations">
+                                {t("{n} of {k} confirmations")
+                                    .replace("{n}", &format_number(confirmations.to_string()))
+                                    .replace("{k}", &finality_depth.to_string())}
+                            </span>
+                            <div class="h-2 rounded-full bg-slate-200 overflow-hidden">
+                                <div
+                                    class="h-full bg-granola-orange"
+                                    style=format!(
+                                        "width: {}%",
+                                        confirmations * 100 / finality_depth,
+                                    )
+                                />
+                            </div>
+                        }
+                            .into_view()
+                    }
+                    ConfirmationStatus::Final { inclusion } => {
+                        view! {
+                            <span>{t("Included in block")} " " {block_link(inclusion)}</span>
+                            <span id="confirmations">
+                                {t("Final after {n} confirmations")
+                                    .replace(
+                                        "{n}",
+                                        &format_number(
+                                            tip().saturating_sub(inclusion.height).to_string(),
+                                        ),
+                                    )}
+                            </span>
+                        }
+                            .into_view()
+                    }
+                };
+                let orphaned = tracking.orphaned().cloned().collect::<Vec<_>>();
+                view! {
+                    <AppSection>
+                        <AppHeading heading="Confirmations" />
+                        <div class="confirmation-tracker px-8 pb-6 flex flex-col gap-3 text-sm">
+                            <ol class="flex gap-2">
+                                {TRACKER_STEPS
+                                    .iter()
+                                    .enumerate()
+                                    .map(|(index, label)| {
+                                        view! {
+                                            <li
+                                                class="px-3 py-1 rounded-full"
+                                                class=("bg-granola-orange", index <= step)
+                                                class=("text-white", index <= step)
+                                                class=("bg-slate-200", index > step)
+                                                class=("text-slate-500", index > step)
+                                            >
+                                                {t(label)}
+                                            </li>
+                                        }
+                                    })
+                                    .collect_view()}
+                            </ol>
+                            {detail}
+                            {(!orphaned.is_empty())
+                                .then(|| {
+                                    view! {
+                                        <div
+                                            id="orphaned-warning"
+                                            class="p-3 rounded-md bg-amber-50 text-amber-800 flex flex-col gap-1"
+                                        >
+                                            {orphaned
+                                                .iter()
+                                                .map(|inclusion| {
+                                                    view! {
+                                                        <span>
+                                                            {t("Orphaned in block")} " "
+                                                            {block_link(inclusion)}
+                                                        </span>
+                                                    }
+                                                })
+                                                .collect_view()}
+                                            <span>
+                                                {match &status {
+                                                    ConfirmationStatus::Included { .. }
+                                                    | ConfirmationStatus::Final { .. } => {
+                                                        t("Re-included in a canonical block")
+                                                    }
+                                                    ConfirmationStatus::Pending => {
+                                                        t("Back in the pending pool")
+                                                    }
+                                                    ConfirmationStatus::Orphaned => {
+                                                        t("Not re-included in a canonical block yet")
+                                                    }
+                                                }}
+                                            </span>
+                                        </div>
+                                    }
+                                })}
+                        </div>
+                    </AppSection>
+                }
+                    .into_view()
+            }
+        }}
+    }
+}
@@ -1,44 +1,41 @@
 use super::{
     graphql::{transactions_query::TransactionsQueryTransactions, *},
-    models::PooledUserCommandsResponse,
+    models::{Inclusion, PooledUserCommandsResponse, TxnTracking},
 };
-use crate::common::{
-    constants::*,
-    functions::format_json_array_pretty,
-    models::{MyError, TransactionKind},
+use crate::{
+    advanced_filter::{
+        functions::{FilterTarget, set_field},
+        models::*,
+    },
+    common::{
+        client::{get_json, query_graphql},
+        functions::format_json_array_pretty,
+        memo::memo_search_values,
+        models::{MyError, PublicKey, RangeFilters, TransactionKind, TxnHash},
+        network::Network,
+    },
 };
-use graphql_client::reqwest::post_graphql;
 
 pub async fn load_pending_txn() -> Result<transactions_query::ResponseData, MyError> {
-    let response = reqwest::get("https://proxy.minaexplorer.com/graphql?query={pooledUserCommands{id hash kind nonce source{publicKey}receiver{publicKey}amount fee memo failureReason feeToken}}")
-        .await
-        .map_err(|e| MyError::NetworkError(e.to_string()))?;
+    let pending_txn = get_json::<PooledUserCommandsResponse>("https://proxy.minaexplorer.com/graphql?query={pooledUserCommands{id hash kind nonce source{publicKey}receiver{publicKey}amount fee memo failureReason feeToken}}").await?;
 
-    if response.status().is_success() {
-        let pending_txn = response
-            .json::<PooledUserCommandsResponse>()
-            .await
-            .map_err(|e| MyError::ParseError(e.to_string()))?;
-
-        let txn: Vec<Option<transactions_query::TransactionsQueryTransactions>> = pending_txn
-            .data
-            .pooled_user_commands
-            .into_iter()
-            .map(|pt| Some(transactions_query::TransactionsQueryTransactions::from(pt)))
-            .collect::<Vec<_>>();
+    let txn: Vec<Option<transactions_query::TransactionsQueryTransactions>> = pending_txn
+        .data
+        .pooled_user_commands
+        .into_iter()
+        .map(|pt| Some(transactions_query::TransactionsQueryTransactions::from(pt)))
+        .collect::<Vec<_>>();
 
-        Ok(transactions_query::ResponseData {
-            transactions: txn,
-            other_transactions: vec![],
-            tokens: vec![],
-        })
-    } else {
-        Err(MyError::NetworkError("Failed to fetch data".into()))
-    }
+    Ok(transactions_query::ResponseData {
+        transactions: txn,
+        other_transactions: vec![],
+        tokens: vec![],
+    })
 }
 
 #[allow(clippy::too_many_arguments)]
 pub async fn load_data(
+    network: &Network,
     limit: Option<u64>,
     from_account: Option<String>,
     to_account: Option<String>,
@@ -50,6 +47,9 @@ pub async fn load_data(
     is_applied: Option<bool>,
     kind: Option<TransactionKind>,
     token: Option<String>,
+    memo: Option<String>,
+    ranges: RangeFilters,
+    filter: Option<transactions_query::TransactionQueryInput>,
 ) -> Result<transactions_query::ResponseData, MyError> {
     let variables = transactions_query::Variables {
         sort_by: transactions_query::TransactionSortByInput::BLOCKHEIGHT_DESC,
@@ -72,6 +72,14 @@ pub async fn load_data(
                 }),
             kind: kind.map(|k| k.to_string()),
             token: token.clone(),
+            memo_in: memo.map(|memo| memo_search_values(&memo).into_iter().map(Some).collect()),
+            date_time_gte: ranges.date_time.gte,
+            date_time_lte: ranges.date_time.lte,
+            amount_gte: ranges.amount.gte,
+            amount_lte: ranges.amount.lte,
+            fee_gte: ranges.fee.gte,
+            fee_lte: ranges.fee.lte,
+            and: filter.map(|filter| vec![filter]),
             ..Default::default()
         },
         other_txn_query: Some(transactions_query::TransactionQueryInput {
@@ -86,19 +94,53 @@ pub async fn load_data(
         }),
     };
 
-    let client = reqwest::Client::new();
-
-    let response = post_graphql::<TransactionsQuery, _>(&client, GRAPHQL_ENDPOINT, variables)
-        .await
-        .map_err(|e| MyError::NetworkError(e.to_string()))?;
-
-    if let Some(errors) = response.errors {
-        return Err(MyError::GraphQLError(errors));
-    }
+    query_graphql::<TransactionsQuery>(network, variables).await
+}
 
-    response
-        .data
-        .ok_or(MyError::GraphQLEmpty("No data available".to_string()))
+/// Every block that includes `hash` and, unless one of them is canonical,
+/// whether the transaction is in the pending pool.
+pub async fn load_txn_tracking(network: &Network, hash: String) -> Result<TxnTracking, MyError> {
+    let data = load_data(
+        network,
+        Some(10),
+        None,
+        None,
+        Some(hash.clone()),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Default::default(),
+        None,
+    )
+    .await?;
+    let inclusions = data
+        .other_transactions
+        .into_iter()
+        .flatten()
+        .filter_map(|txn| {
+            Some(Inclusion {
+                height: txn.block_height? as u64,
+                state_hash: txn.block?.state_hash?,
+                canonical: txn.canonical.unwrap_or_default(),
+            })
+        })
+        .collect::<Vec<_>>();
+    let is_pending = !inclusions.iter().any(|inclusion| inclusion.canonical)
+        && load_pending_txn().await.is_ok_and(|pool| {
+            pool.transactions
+                .iter()
+                .flatten()
+                .any(|txn| txn.hash.as_ref() == Some(&hash))
+        });
+    Ok(TxnTracking {
+        inclusions,
+        is_pending,
+    })
 }
 
 pub fn get_actions(txn: &TransactionsQueryTransactions) -> Result<String, serde_json::Error> {
@@ -120,3 +162,115 @@ pub fn get_events(txn: &TransactionsQueryTransactions) -> Result<String, serde_j
 
     format_json_array_pretty(json_arr)
 }
+
+impl FilterTarget for transactions_query::TransactionQueryInput {
+    const FIELDS: &'static [FilterField] = &[
+        FilterField {
+            key: "from",
+            label: "From",
+            kind: FieldKind::Text(Some(PublicKey::validate)),
+            operators: TEXT_OPERATORS,
+        },
+        FilterField {
+            key: "to",
+            label: "To",
+            kind: FieldKind::Text(Some(PublicKey::validate)),
+            operators: TEXT_OPERATORS,
+        },
+        FilterField {
+            key: "kind",
+            label: "Type",
+            kind: FieldKind::Options(&["PAYMENT", "STAKE_DELEGATION", "ZKAPP"]),
+            operators: HASH_OPERATORS,
+        },
+        FilterField {
+            key: "memo",
+            label: "Memo",
+            kind: FieldKind::Text(None),
+            operators: TEXT_OPERATORS,
+        },
+        FilterField {
+            key: "hash",
+            label: "Txn Hash",
+            kind: FieldKind::Text(Some(TxnHash::validate)),
+            operators: HASH_OPERATORS,
+        },
+        FilterField {
+            key: "amount",
+            label: "Amount",
+            kind: FieldKind::Mina,
+            operators: AMOUNT_OPERATORS,
+        },
+        FilterField {
+            key: "fee",
+            label: "Fee",
+            kind: FieldKind::Mina,
+            operators: AMOUNT_OPERATORS,
+        },
+        FilterField {
+            key: "nonce",
+            label: "Nonce",
+            kind: FieldKind::Integer,
+            operators: NUMBER_OPERATORS,
+        },
+        FilterField {
+            key: "block_height",
+            label: "Height",
+            kind: FieldKind::Integer,
+            operators: NUMBER_OPERATORS,
+        },
+        FilterField {
+            key: "failure_reason",
+            label: "Failure Reason",
+            kind: FieldKind::Text(None),
+            operators: TEXT_OPERATORS,
+        },
+    ];
+
+    fn group(all: bool, children: Vec<Self>) -> Self {
+        if all {
+            Self {
+                and: Some(children),
+                ..Default::default()
+            }
+        } else {
+            Self {
+                or: Some(children),
+                ..Default::default()
+            }
+        }
+    }
+
+    fn condition(field: &str, op: Operator, value: FilterValue) -> Result<Self, String> {
+        let mut input = Self::default();
+        match field {
+            "from" => set_field!(input, op, value,
+                Eq => from, Ne => from_ne, In => from_in, Nin => from_nin, Exists => from_exists),
+            "to" => set_field!(input, op, value,
+                Eq => to, Ne => to_ne, In => to_in, Nin => to_nin, Exists => to_exists),
+            "kind" => set_field!(input, op, value,
+                Eq => kind, Ne => kind_ne, In => kind_in, Nin => kind_nin),
+            "memo" => set_field!(input, op, value,
+                Eq => memo, Ne => memo_ne, In => memo_in, Nin => memo_nin, Exists => memo_exists),
+            "hash" => set_field!(input, op, value,
+                Eq => hash, Ne => hash_ne, In => hash_in, Nin => hash_nin),
+            "amount" => set_field!(input, op, value,
+                Eq => amount, Ne => amount_ne, Gt => amount_gt, Gte => amount_gte,
+                Lt => amount_lt, Lte => amount_lte),
+            "fee" => set_field!(input, op, value,
+                Eq => fee, Ne => fee_ne, Gt => fee_gt, Gte => fee_gte, Lt => fee_lt, Lte => fee_lte),
+            "nonce" => set_field!(input, op, value,
+                Eq => nonce, Ne => nonce_ne, In => nonce_in, Nin => nonce_nin,
+                Gt => nonce_gt, Gte => nonce_gte, Lt => nonce_lt, Lte => nonce_lte),
+            "block_height" => set_field!(input, op, value,
+                Eq => block_height, Ne => block_height_ne, In => block_height_in,
+                Nin => block_height_nin, Gt => block_height_gt, Gte => block_height_gte,
+                Lt => block_height_lt, Lte => block_height_lte),
+            "failure_reason" => set_field!(input, op, value,
+                Eq => failure_reason, Ne => failure_reason_ne, In => failure_reason_in,
+                Nin => failure_reason_nin, Exists => failure_reason_exists),
+            _ => return Err(format!("Unknown field: {}", field)),
+        }
+        Ok(input)
+    }
+}
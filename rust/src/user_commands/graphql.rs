@@ -1,4 +1,5 @@
 use self::transactions_query::{BlockQueryInput, TransactionQueryInput};
+use crate::common::amount::Amount;
 use chrono::Utc;
 use graphql_client::GraphQLQuery;
 
@@ -7,7 +8,7 @@ type Long = i32;
 
 #[derive(GraphQLQuery)]
 #[graphql(
-    schema_path = "./graphql/schemas/mina-explorer.graphql",
+    schema_path = "./graphql/schemas/mina-explorer.typed.graphql",
     query_path = "./graphql/queries/transactions.graphql",
     response_derives = "Serialize,PartialEq,Debug,Clone,Default",
     skip_serializing_none
@@ -18,6 +19,7 @@ pub struct TransactionsQuery;
 impl Default for BlockQueryInput {
     fn default() -> Self {
         BlockQueryInput {
+            unique_block_producers_last_n_blocks: None,
             creator_nin: None,
             state_hash_lte: None,
             canonical_ne: None,
@@ -1,6 +1,6 @@
 pub mod components;
-mod functions;
+pub mod functions;
 pub mod graphql;
-mod models;
+pub mod models;
 pub mod page;
 pub mod table_trait;
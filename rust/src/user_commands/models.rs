@@ -1,5 +1,7 @@
 use super::graphql::transactions_query::{self, TransactionsQueryTransactions};
+use crate::common::amount::Amount;
 use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct PooledUserCommandSource {
@@ -87,8 +89,8 @@ pub struct PendingTxn {
     pub kind: Option<String>,
     pub sender_username: Option<String>,
     pub nonce: Option<i64>,
-    pub fee: Option<f64>,
-    pub amount: Option<f64>,
+    pub fee: Option<Amount>,
+    pub amount: Option<Amount>,
     pub source: Option<PendingTxnParty>,
     pub receiver: Option<PendingTxnParty>,
 }
@@ -111,3 +113,230 @@ impl From<TransactionsQueryTransactions> for PendingTxn {
         }
     }
 }
+
+/// Folds a fresh read of the pending pool into the rows on screen. Commands
+/// that just arrived go on top, the rest keep their place, and commands that
+/// left the pool are dropped.
+pub fn merge_pending_txns(
+    shown: &[Option<PendingTxn>],
+    latest: Vec<Option<PendingTxn>>,
+) -> Vec<Option<PendingTxn>> {
+    let hash = |txn: &Option<PendingTxn>| txn.as_ref().and_then(|t| t.txn_hash.clone());
+    let shown_hashes = shown.iter().filter_map(hash).collect::<HashSet<_>>();
+    let latest_hashes = latest.iter().filter_map(hash).collect::<HashSet<_>>();
+    latest
+        .into_iter()
+        .filter(|txn| hash(txn).is_none_or(|h| !shown_hashes.contains(&h)))
+        .chain(
+            shown
+                .iter()
+                .filter(|txn| hash(txn).is_some_and(|h| latest_hashes.contains(&h)))
+                .cloned(),
+        )
+        .collect()
+}
+
+/// A block that includes the transaction.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Inclusion {
+    pub height: u64,
+    pub state_hash: String,
+    pub canonical: bool,
+}
+
+/// Where a transaction is in its life, from the pending pool to finality.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ConfirmationStatus {
+    Pending,
+    Included {
+        inclusion: Inclusion,
+        confirmations: u64,
+    },
+    Final {
+        inclusion: Inclusion,
+    },
+    /// Only in orphaned blocks, and not in the pending pool.
+    Orphaned,
+}
+
+impl ConfirmationStatus {
+    /// `None` if the transaction is nowhere to be found. Confirmations count
+    /// the blocks on top of the canonical inclusion, up to `tip`, and reach
+    /// finality at `finality_depth`.
+    pub fn new(
+        inclusions: &[Inclusion],
+        is_pending: bool,
+        tip: u64,
+        finality_depth: u64,
+    ) -> Option<Self> {
+        match inclusions.iter().find(|inclusion| inclusion.canonical) {
+            Some(inclusion) => {
+                let confirmations = tip.saturating_sub(inclusion.height);
+                let inclusion = inclusion.clone();
+                Some(if confirmations >= finality_depth {
+                    ConfirmationStatus::Final { inclusion }
+                } else {
+                    ConfirmationStatus::Included {
+                        inclusion,
+                        confirmations,
+                    }
+                })
+            }
+            None if is_pending => Some(ConfirmationStatus::Pending),
+            None if !inclusions.is_empty() => Some(ConfirmationStatus::Orphaned),
+            None => None,
+        }
+    }
+
+    pub fn is_included(&self) -> bool {
+        matches!(
+            self,
+            ConfirmationStatus::Included { .. } | ConfirmationStatus::Final { .. }
+        )
+    }
+}
+
+/// What the confirmation tracker knows about a transaction.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TxnTracking {
+    pub inclusions: Vec<Inclusion>,
+    pub is_pending: bool,
+}
+
+impl TxnTracking {
+    pub fn orphaned(&self) -> impl Iterator<Item = &Inclusion> {
+        self.inclusions
+            .iter()
+            .filter(|inclusion| !inclusion.canonical)
+    }
+}
+
+#[cfg(test)]
+mod confirmation_tests {
+    use super::*;
+
+    const FINALITY_DEPTH: u64 = 290;
+
+    fn inclusion(height: u64, canonical: bool) -> Inclusion {
+        Inclusion {
+            height,
+            state_hash: format!("3N{}", height),
+            canonical,
+        }
+    }
+
+    #[test]
+    fn test_pending_and_not_found() {
+        assert_eq!(
+            ConfirmationStatus::new(&[], true, 100, FINALITY_DEPTH),
+            Some(ConfirmationStatus::Pending)
+        );
+        assert_eq!(
+            ConfirmationStatus::new(&[], false, 100, FINALITY_DEPTH),
+            None
+        );
+    }
+
+    #[test]
+    fn test_confirmations() {
+        assert_eq!(
+            ConfirmationStatus::new(&[inclusion(100, true)], false, 150, FINALITY_DEPTH),
+            Some(ConfirmationStatus::Included {
+                inclusion: inclusion(100, true),
+                confirmations: 50,
+            })
+        );
+        // The tip may lag behind the indexer
+        assert_eq!(
+            ConfirmationStatus::new(&[inclusion(100, true)], false, 90, FINALITY_DEPTH),
+            Some(ConfirmationStatus::Included {
+                inclusion: inclusion(100, true),
+                confirmations: 0,
+            })
+        );
+        assert_eq!(
+            ConfirmationStatus::new(
+                &[inclusion(100, true)],
+                false,
+                100 + FINALITY_DEPTH - 1,
+                FINALITY_DEPTH
+            )
+            .map(|status| matches!(status, ConfirmationStatus::Final { .. })),
+            Some(false)
+        );
+        assert_eq!(
+            ConfirmationStatus::new(
+                &[inclusion(100, true)],
+                false,
+                100 + FINALITY_DEPTH,
+                FINALITY_DEPTH
+            ),
+            Some(ConfirmationStatus::Final {
+                inclusion: inclusion(100, true)
+            })
+        );
+    }
+
+    #[test]
+    fn test_orphaned() {
+        assert_eq!(
+            ConfirmationStatus::new(&[inclusion(100, false)], false, 150, FINALITY_DEPTH),
+            Some(ConfirmationStatus::Orphaned)
+        );
+        // Back in the pool, waiting to be included again
+        assert_eq!(
+            ConfirmationStatus::new(&[inclusion(100, false)], true, 150, FINALITY_DEPTH),
+            Some(ConfirmationStatus::Pending)
+        );
+        let reincluded = ConfirmationStatus::new(
+            &[inclusion(100, false), inclusion(102, true)],
+            false,
+            150,
+            FINALITY_DEPTH,
+        );
+        assert_eq!(
+            reincluded,
+            Some(ConfirmationStatus::Included {
+                inclusion: inclusion(102, true),
+                confirmations: 48,
+            })
+        );
+        assert!(reincluded.unwrap().is_included());
+    }
+}
+
+#[cfg(test)]
+mod pending_tests {
+    use super::*;
+
+    fn txn(hash: &str) -> Option<PendingTxn> {
+        Some(PendingTxn {
+            txn_hash: Some(hash.to_string()),
+            kind: None,
+            sender_username: None,
+            nonce: None,
+            fee: None,
+            amount: None,
+            source: None,
+            receiver: None,
+        })
+    }
+
+    fn hashes(txns: &[Option<PendingTxn>]) -> Vec<&str> {
+        txns.iter()
+            .filter_map(|t| t.as_ref().and_then(|t| t.txn_hash.as_deref()))
+            .collect()
+    }
+
+    #[test]
+    fn test_merge_pending_txns() {
+        let shown = vec![txn("b"), txn("c"), txn("d")];
+        let merged = merge_pending_txns(&shown, vec![txn("d"), txn("e"), txn("b"), txn("a")]);
+        assert_eq!(hashes(&merged), vec!["e", "a", "b", "d"]);
+        assert_eq!(
+            hashes(&merge_pending_txns(&[], vec![txn("a"), txn("b")])),
+            vec!["a", "b"]
+        );
+        assert!(merge_pending_txns(&shown, vec![]).is_empty());
+    }
+}
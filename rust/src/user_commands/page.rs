@@ -1,6 +1,12 @@
-use super::{components::*, functions::*, table_trait::*};
+use super::{
+    components::*, functions::*, graphql::transactions_query::TransactionQueryInput, table_trait::*,
+};
 use crate::{
-    common::{components::*, constants::*, functions::*, models::*, spotlight::*, table::*},
+    advanced_filter::{components::AdvancedFilterSection, functions::FilterTarget},
+    common::{
+        components::*, constants::*, functions::*, memo::MemoDisplay, models::*, network::*,
+        spotlight::*, table::*,
+    },
     icons::*,
 };
 use leptos::*;
@@ -44,6 +50,7 @@ pub fn UserCommandsPage() -> impl IntoView {
     view! {
         <Title text="Commands | Search For Commands" />
         <PageContainer>
+            <AdvancedFilterSection fields=TransactionQueryInput::FIELDS />
             <TransactionsSection />
         </PageContainer>
     }
@@ -65,11 +72,13 @@ pub fn CommandSpotlightPage() -> impl IntoView {
     let memo_params_map = use_params_map();
     let (state_hash_sig, _) = create_query_signal::<String>(QUERY_PARAM_STATE_HASH);
     let (txn_memo, set_txn_memo) = create_signal("No Memo".to_string());
+    let network = use_network();
     let resource = create_resource(
         move || (memo_params_map.get(), state_hash_sig.get()),
-        |(value, state_hash)| async move {
+        move |(value, state_hash)| async move {
             let txn_hash = value.get("id");
             load_data(
+                &network.get_value(),
                 Some(10),
                 None,
                 None,
@@ -81,6 +90,9 @@ pub fn CommandSpotlightPage() -> impl IntoView {
                 None,
                 None,
                 None,
+                None,
+                Default::default(),
+                None,
             )
             .await
         },
@@ -90,12 +102,27 @@ pub fn CommandSpotlightPage() -> impl IntoView {
     create_effect(move |_| {
         if let Some(Ok(data)) = resource.get() {
             if let Some(Some(txn)) = data.transactions.first() {
-                set_txn_memo.set(txn.get_memo());
+                let memo = txn.get_memo();
+                if !memo.is_empty() {
+                    set_txn_memo.set(memo);
+                }
             }
         }
     });
 
     let get_data = move || resource.get().and_then(|res| res.ok());
+    // The indexer doesn't have the transaction (yet), but it may be pending.
+    let not_indexed = move || {
+        view! {
+            <ConfirmationTracker
+                hash=memo_params_map.get_untracked().get("id").cloned().unwrap_or_default()
+                on_included=Callback::new(move |_| resource.refetch())
+                fallback=|| {
+                    view! { <NotFound message=Some("Transaction Not Found :(".to_string()) /> }
+                }
+            />
+        }
+    };
 
     create_effect(move |_| {
         let Some(data) = get_data() else {
@@ -153,7 +180,7 @@ pub fn CommandSpotlightPage() -> impl IntoView {
                                 },
                                 SpotlightEntry {
                                     label: "Date".to_string(),
-                                    any_el: Some(convert_to_span(transaction.get_block_datetime())),
+                                    any_el: Some(convert_to_date_time(transaction.get_block_datetime())),
                                     ..Default::default()
                                 },
                                 SpotlightEntry {
@@ -250,7 +277,16 @@ pub fn CommandSpotlightPage() -> impl IntoView {
                                 SpotlightEntry {
                                     label: "Memo".to_string(),
                                     any_el: Some(
-                                        convert_to_pill(transaction.get_memo(), ColorVariant::Grey),
+                                        html::span()
+                                            .child(
+                                                view! {
+                                                    <MemoDisplay memo=transaction
+                                                        .memo
+                                                        .clone()
+                                                        .unwrap_or_default() />
+                                                },
+                                            )
+                                            .into(),
                                     ),
                                     ..Default::default()
                                 },
@@ -303,17 +339,12 @@ pub fn CommandSpotlightPage() -> impl IntoView {
                                     header="Command Spotlight"
                                     spotlight_items=spotlight_items
                                     id=Some(state_hash)
-                                    meta=Some(
-                                        format!(
-                                            "{} ({})",
-                                            convert_to_local_timezone_formatted(&date_time),
-                                            print_time_since(&date_time),
-                                        ),
-                                    )
+                                    meta=date_time_meta(date_time)
                                 >
 
                                     <TransactionIcon width=40 />
                                 </SpotlightSection>
+                                <ConfirmationTracker hash=transaction.get_hash() />
                                 {if transaction.zkapp.is_some() {
                                     view! {
                                         <AccountsUpdatedSection zkapp=transaction.zkapp.clone() />
@@ -362,6 +393,7 @@ pub fn CommandSpotlightPage() -> impl IntoView {
                                     .map(|_| {
                                         view! {
                                             <TableSectionTemplate
+                                                table_id="command-other-blocks"
                                                 table_columns
                                                 data_sig=other_txns
                                                 section_heading="In Other Blocks"
@@ -373,16 +405,15 @@ pub fn CommandSpotlightPage() -> impl IntoView {
                             }
                                 .into_view()
                         }
-                        _ => {
-                            view! {
-                                <NotFound message=Some("Transaction Not Found :(".to_string()) />
-                            }
-                        }
+                        _ => not_indexed(),
                     }
                 }
-                Some(Err(_)) => {
-                    view! { <NotFound message=Some("Transaction Not Found :(".to_string()) /> }
+                Some(Err(err)) if err.kind() != ErrorKind::NotFound => {
+                    view! {
+                        <ErrorView err on_retry=Some(Callback::new(move |_| resource.refetch())) />
+                    }
                 }
+                Some(Err(_)) => not_indexed(),
                 None => {
                     let spotlight_items = vec![
                         SpotlightEntry {
@@ -6,12 +6,15 @@ use super::{
     models::PendingTxn,
 };
 use crate::common::{
+    amount::Amount,
     constants::{
         LHS_MAX_DIGIT_PADDING, LHS_MAX_SPACE_FEES, QUERY_PARAM_ID, TXN_STATUS_APPLIED,
         TXN_STATUS_FAILED,
     },
     functions::*,
-    models::{ColorVariant, HasBlockHeight},
+    memo::{MemoFormat, format_memo},
+    models::ColorVariant,
+    pagination::Paginated,
     table::*,
 };
 use heck::ToTitleCase;
@@ -52,6 +55,18 @@ impl TableData for Vec<Option<TransactionsQueryOtherTransactions>> {
             })
             .collect::<Vec<_>>()
     }
+
+    fn get_raw_rows(&self) -> Vec<Vec<String>> {
+        self.iter()
+            .flatten()
+            .map(|transaction| {
+                vec![
+                    opt_to_string(transaction.block_height),
+                    transaction.get_hash(),
+                ]
+            })
+            .collect()
+    }
 }
 
 impl TableData for Vec<TransactionsQueryTransactionsZkappAccountsUpdated> {
@@ -61,7 +76,7 @@ impl TableData for Vec<TransactionsQueryTransactionsZkappAccountsUpdated> {
                 vec![
                     convert_to_linkable_address(au.username.clone(), au.pk.to_string()),
                     convert_to_span(format_number_for_html(
-                        &nanomina_to_mina_i64(au.balance_change),
+                        &Amount::from_nanomina(au.balance_change).format(),
                         14,
                     )),
                     convert_to_span(au.increment_nonce.to_string()),
@@ -73,6 +88,19 @@ impl TableData for Vec<TransactionsQueryTransactionsZkappAccountsUpdated> {
             })
             .collect::<Vec<_>>()
     }
+
+    fn get_raw_rows(&self) -> Vec<Vec<String>> {
+        self.iter()
+            .map(|au| {
+                vec![
+                    au.pk.to_string(),
+                    Amount::from_nanomina(au.balance_change).to_mina_string(),
+                    au.increment_nonce.to_string(),
+                    au.token.to_string(),
+                ]
+            })
+            .collect()
+    }
 }
 
 impl TableData for Vec<Option<TransactionsQueryTransactions>> {
@@ -81,34 +109,15 @@ impl TableData for Vec<Option<TransactionsQueryTransactions>> {
             .map(|opt_trans| match opt_trans {
                 Some(transaction) => vec![
                     convert_to_span(transaction.get_block_height()),
-                    if !transaction.get_memo().is_empty() {
-                        convert_array_to_span(vec![
-                            convert_to_copy_link(
-                                transaction.get_hash(),
-                                format!(
-                                    "/commands/{}?q-state-hash={}",
-                                    transaction.get_hash(),
-                                    transaction.get_block_state_hash()
-                                ),
-                            ),
-                            convert_to_span(transaction.get_memo())
-                                .attr("class", "block text-xs font-light text-slate-400"),
-                        ])
-                        .attr("class", "block")
-                    } else {
-                        convert_to_copy_link(
+                    convert_to_copy_link(
+                        transaction.get_hash(),
+                        format!(
+                            "/commands/{}?q-state-hash={}",
                             transaction.get_hash(),
-                            format!(
-                                "/commands/{}?q-state-hash={}",
-                                transaction.get_hash(),
-                                transaction.get_block_state_hash()
-                            ),
-                        )
-                    },
-                    convert_to_title(
-                        convert_to_local_timezone_formatted(&transaction.get_block_datetime()),
-                        transaction.get_block_datetime(),
+                            transaction.get_block_state_hash()
+                        ),
                     ),
+                    convert_to_date_time(transaction.get_block_datetime()),
                     convert_to_pill(
                         transaction.get_kind(),
                         match transaction.get_kind().as_str() {
@@ -137,11 +146,37 @@ impl TableData for Vec<Option<TransactionsQueryTransactions>> {
                     convert_to_pill(transaction.get_nonce(), ColorVariant::Grey),
                     convert_to_span(transaction.get_fee()),
                     convert_to_span(transaction.get_amount()),
+                    convert_to_span(transaction.get_memo()),
                 ],
                 None => vec![],
             })
             .collect::<Vec<_>>()
     }
+
+    fn get_raw_rows(&self) -> Vec<Vec<String>> {
+        self.iter()
+            .flatten()
+            .map(|transaction| {
+                vec![
+                    opt_to_string(transaction.block_height),
+                    transaction.get_hash(),
+                    transaction.get_block_datetime(),
+                    transaction.get_kind(),
+                    if transaction.get_failure_reason().is_none() {
+                        TXN_STATUS_APPLIED.to_string()
+                    } else {
+                        TXN_STATUS_FAILED.to_string()
+                    },
+                    transaction.get_from().unwrap_or_default(),
+                    transaction.get_receiver_public_key().unwrap_or_default(),
+                    opt_to_string(transaction.nonce),
+                    opt_to_string(transaction.fee.map(|f| f.to_mina_string())),
+                    opt_to_string(transaction.amount.map(|a| a.to_mina_string())),
+                    transaction.get_memo(),
+                ]
+            })
+            .collect()
+    }
 }
 
 impl TableData for Vec<Option<PendingTxn>> {
@@ -171,6 +206,23 @@ impl TableData for Vec<Option<PendingTxn>> {
             })
             .collect::<Vec<_>>()
     }
+
+    fn get_raw_rows(&self) -> Vec<Vec<String>> {
+        self.iter()
+            .flatten()
+            .map(|transaction| {
+                vec![
+                    transaction.get_hash(),
+                    transaction.get_kind(),
+                    transaction.get_from().unwrap_or_default(),
+                    transaction.get_receiver_public_key().unwrap_or_default(),
+                    opt_to_string(transaction.nonce),
+                    opt_to_string(transaction.fee.map(|f| f.to_mina_string())),
+                    opt_to_string(transaction.amount.map(|a| a.to_mina_string())),
+                ]
+            })
+            .collect()
+    }
 }
 
 pub trait TransactionsTrait {
@@ -226,7 +278,7 @@ impl TransactionsTrait for TransactionsQueryTransactions {
     fn get_memo(&self) -> String {
         self.memo
             .as_ref()
-            .map_or_else(String::new, ToString::to_string)
+            .map_or_else(String::new, |memo| format_memo(memo, MemoFormat::Decoded))
     }
 
     fn get_block_state_hash(&self) -> String {
@@ -246,9 +298,7 @@ impl TransactionsTrait for TransactionsQueryTransactions {
 
     fn get_fee(&self) -> String {
         self.fee
-            .map(|f| f.round() as u64)
-            .map(nanomina_to_mina)
-            .map(|number| format_number_for_html(&number, LHS_MAX_SPACE_FEES))
+            .map(|fee| format_number_for_html(&fee.format(), LHS_MAX_SPACE_FEES))
             .unwrap_or_default()
     }
 
@@ -260,9 +310,7 @@ impl TransactionsTrait for TransactionsQueryTransactions {
 
     fn get_amount(&self) -> String {
         self.amount
-            .map(|f| f.round() as u64)
-            .map(nanomina_to_mina)
-            .map(|number| format_number_for_html(&number, LHS_MAX_DIGIT_PADDING))
+            .map(|amount| format_number_for_html(&amount.format(), LHS_MAX_DIGIT_PADDING))
             .unwrap_or_default()
     }
 
@@ -331,9 +379,7 @@ impl TransactionsTrait for PendingTxn {
 
     fn get_fee(&self) -> String {
         self.fee
-            .map(|f| f.round() as u64)
-            .map(nanomina_to_mina)
-            .map(|number| format_number_for_html(&number, LHS_MAX_SPACE_FEES))
+            .map(|fee| format_number_for_html(&fee.format(), LHS_MAX_SPACE_FEES))
             .unwrap_or_default()
     }
 
@@ -345,9 +391,7 @@ impl TransactionsTrait for PendingTxn {
 
     fn get_amount(&self) -> String {
         self.amount
-            .map(|f| f.round() as u64)
-            .map(nanomina_to_mina)
-            .map(|number| format_number_for_html(&number, LHS_MAX_DIGIT_PADDING))
+            .map(|amount| format_number_for_html(&amount.format(), LHS_MAX_DIGIT_PADDING))
             .unwrap_or_default()
     }
 }
@@ -381,7 +425,7 @@ impl TransactionsTrait for TransactionsQueryOtherTransactions {
     fn get_memo(&self) -> String {
         self.memo
             .as_ref()
-            .map_or_else(String::new, ToString::to_string)
+            .map_or_else(String::new, |memo| format_memo(memo, MemoFormat::Decoded))
     }
 
     fn get_block_state_hash(&self) -> String {
@@ -422,8 +466,18 @@ impl TransactionsTrait for TransactionsQueryOtherTransactions {
     }
 }
 
-impl HasBlockHeight for TransactionsQueryTransactions {
-    fn block_height(&self) -> Option<i64> {
-        self.block_height
+impl Paginated for TransactionsQueryTransactions {
+    type Cursor = u64;
+
+    fn cursor(&self) -> Option<u64> {
+        self.block_height.map(|h| h as u64)
+    }
+
+    fn key(&self) -> Option<String> {
+        Some(format!(
+            "{}-{}",
+            self.get_hash(),
+            self.get_block_state_hash()
+        ))
     }
 }
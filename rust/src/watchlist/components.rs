@@ -0,0 +1,194 @@
+use super::{functions::*, models::*};
+use crate::{
+    common::{
+        address_book::use_address_book,
+        components::{AppHeading, AppSection, Button, Checkbox},
+        constants::NOTIFICATIONS_ENABLED_STORAGE_KEY,
+        functions::convert_to_linkable_address,
+        i18n::t,
+        live::use_live_updates,
+        models::ButtonStyleVariant,
+        network::{use_mina_token_address, use_network},
+    },
+    icons::StarIcon,
+};
+use codee::string::FromToStringCodec;
+use futures::future::join_all;
+use leptos::*;
+use leptos_router::use_navigate;
+use leptos_use::storage::use_local_storage;
+use std::collections::HashMap;
+
+/// Stars or unstars an account on the watchlist.
+#[component]
+pub fn WatchlistToggle(#[prop(into)] public_key: String) -> impl IntoView {
+    let (watchlist, set_watchlist) = use_watchlist();
+    let key = public_key.clone();
+    let is_watched = Signal::derive(move || watchlist.get().contains(&key));
+    let title = move || {
+        if is_watched.get() {
+            t("Remove from watchlist")
+        } else {
+            t("Add to watchlist")
+        }
+    };
+    view! {
+        <button
+            id="watchlist-toggle"
+            class="flex items-center text-granola-orange hover:opacity-75 mr-4"
+            title=title
+            aria-label=title
+            aria-pressed=move || is_watched.get().to_string()
+            on:click=move |_| set_watchlist.update(|list| toggle_watched(list, &public_key))
+        >
+            <StarIcon width=24 filled=is_watched />
+        </button>
+    }
+}
+
+/// Raises a browser notification for new activity on watched accounts each
+/// time the summary poller or the live stream announces a block. The first
+/// load of each account only records what is already there.
+#[component]
+pub fn WatchlistNotifier() -> impl IntoView {
+    let (watchlist, _) = use_watchlist();
+    let (rules, _) = use_notification_rules();
+    let (enabled, _, _) =
+        use_local_storage::<bool, FromToStringCodec>(NOTIFICATIONS_ENABLED_STORAGE_KEY);
+    let book = use_address_book().book;
+    let live = use_live_updates();
+    let network = use_network();
+    let mina_token_address = use_mina_token_address();
+    let navigate = use_navigate();
+    let snapshots = store_value(HashMap::<String, ActivitySnapshot>::new());
+
+    let resource = create_resource(
+        move || (live.latest_height(), enabled.get()),
+        move |(height, enabled)| async move {
+            if height.is_none() || !enabled || !notifications_permitted() {
+                return vec![];
+            }
+            let network = network.get_value();
+            join_all(watchlist.get_untracked().into_iter().map(|public_key| {
+                let network = network.clone();
+                async move {
+                    load_activity(&network, public_key.clone())
+                        .await
+                        .map(|data| (public_key, to_activity_snapshot(&data)))
+                        .ok()
+                }
+            }))
+            .await
+            .into_iter()
+            .flatten()
+            .collect::<Vec<_>>()
+        },
+    );
+
+    create_effect(move |_| {
+        let Some(results) = resource.get() else {
+            return;
+        };
+        for (public_key, current) in results {
+            let previous = snapshots.with_value(|s| s.get(&public_key).cloned());
+            snapshots.update_value(|s| {
+                s.insert(public_key.clone(), current.clone());
+            });
+            let Some(previous) = previous else {
+                continue;
+            };
+            let account_rules =
+                rules.with_untracked(|r| r.get(&public_key).copied().unwrap_or_default());
+            let name = book.with_untracked(|book| {
+                book.get(&public_key)
+                    .map(|entry| entry.label.clone())
+                    .unwrap_or(public_key.clone())
+            });
+            for notice in new_activity(&previous, &current, &account_rules) {
+                let href = activity_href(&mina_token_address, &public_key, &notice);
+                let navigate = navigate.clone();
+                show_notification(
+                    &t(notice.kind.title()),
+                    &format!("{}: {}", name, notice.id),
+                    move || navigate(&href, Default::default()),
+                );
+            }
+        }
+    });
+}
+
+/// Turns notifications on or off, and picks what to be notified of for each
+/// watched account.
+#[component]
+pub fn NotificationSettings() -> impl IntoView {
+    let (watchlist, _) = use_watchlist();
+    let (rules, set_rules) = use_notification_rules();
+    let (enabled, set_enabled, _) =
+        use_local_storage::<bool, FromToStringCodec>(NOTIFICATIONS_ENABLED_STORAGE_KEY);
+
+    let toggle_enabled = move |_| {
+        if enabled.get_untracked() {
+            set_enabled.set(false);
+        } else if notifications_permitted() {
+            set_enabled.set(true);
+        } else {
+            request_notification_permission(move || set_enabled.set(true));
+        }
+    };
+
+    view! {
+        <AppSection>
+            <div class="flex justify-between items-center pr-8">
+                <AppHeading heading="Notifications" />
+                {move || {
+                    view! {
+                        <Button
+                            style_variant=ButtonStyleVariant::Tertiary
+                            text=if enabled.get() { t("Turn off") } else { t("Turn on") }
+                            on_click=toggle_enabled
+                        />
+                    }
+                }}
+            </div>
+            <div class="px-8 pb-4 flex flex-col gap-4">
+                {move || {
+                    watchlist
+                        .get()
+                        .into_iter()
+                        .map(|public_key| {
+                            let account_rules = rules
+                                .with(|r| r.get(&public_key).copied().unwrap_or_default());
+                            view! {
+                                <div class="flex flex-col md:flex-row md:items-center gap-2 md:gap-6">
+                                    <div class="md:w-1/3 overflow-hidden">
+                                        {convert_to_linkable_address(None, public_key.clone())}
+                                    </div>
+                                    {ActivityKind::ALL
+                                        .into_iter()
+                                        .map(|kind| {
+                                            let public_key = public_key.clone();
+                                            view! {
+                                                <Checkbox
+                                                    label=t(kind.label())
+                                                    value=kind.is_enabled(&account_rules)
+                                                    handle_change=move |ev| {
+                                                        let checked = event_target_checked(&ev);
+                                                        set_rules
+                                                            .update(|rules| {
+                                                                let entry = rules.entry(public_key.clone()).or_default();
+                                                                kind.set_enabled(entry, checked);
+                                                            })
+                                                    }
+                                                />
+                                            }
+                                        })
+                                        .collect_view()}
+                                </div>
+                            }
+                        })
+                        .collect_view()
+                }}
+            </div>
+        </AppSection>
+    }
+}
@@ -0,0 +1,379 @@
+use super::models::*;
+use crate::{
+    account_activity::{
+        functions::load_data as load_account_activity,
+        graphql::account_activity_query,
+        models::{AccountActivityParams, merge_transactions},
+    },
+    common::{
+        client::query_graphql,
+        constants::{NOTIFICATION_RULES_STORAGE_KEY, WATCHLIST_STORAGE_KEY},
+        models::MyError,
+        network::{Network, use_network_storage_key},
+    },
+    user_commands::graphql::{TransactionsQuery, transactions_query},
+};
+use codee::string::JsonSerdeCodec;
+use leptos::*;
+use leptos_use::storage::use_local_storage;
+use wasm_bindgen::prelude::*;
+
+/// The public keys watched on the current network.
+pub fn use_watchlist() -> (Signal<Vec<String>>, WriteSignal<Vec<String>>) {
+    let (watchlist, set_watchlist, _) = use_local_storage::<Vec<String>, JsonSerdeCodec>(
+        use_network_storage_key(WATCHLIST_STORAGE_KEY),
+    );
+    (watchlist, set_watchlist)
+}
+
+/// Notification rules by public key, for the current network. Keys without
+/// rules get `NotificationRules::default()`.
+pub fn use_notification_rules() -> (
+    Signal<NotificationRulesByKey>,
+    WriteSignal<NotificationRulesByKey>,
+) {
+    let (rules, set_rules, _) = use_local_storage::<NotificationRulesByKey, JsonSerdeCodec>(
+        use_network_storage_key(NOTIFICATION_RULES_STORAGE_KEY),
+    );
+    (rules, set_rules)
+}
+
+/// Adds the key if it is not watched yet and removes it otherwise.
+pub fn toggle_watched(watchlist: &mut Vec<String>, public_key: &str) {
+    if let Some(index) = watchlist.iter().position(|key| key == public_key) {
+        watchlist.remove(index);
+    } else {
+        watchlist.push(public_key.to_string());
+    }
+}
+
+pub fn to_watched_account(
+    public_key: String,
+    data: account_activity_query::ResponseData,
+) -> WatchedAccount {
+    let last_activity = merge_transactions(data.incoming_transactions, data.outgoing_transactions)
+        .into_iter()
+        .flatten()
+        .find_map(|txn| txn.date_time);
+    match data.accounts.into_iter().flatten().next() {
+        Some(account) => WatchedAccount {
+            public_key,
+            username: account.username,
+            balance: account.balance,
+            nonce: account.nonce.and_then(|nonce| u64::try_from(nonce).ok()),
+            delegate: account.delegate,
+            delegate_username: account.delegate_username,
+            last_activity,
+        },
+        None => WatchedAccount {
+            public_key,
+            last_activity,
+            ..Default::default()
+        },
+    }
+}
+
+pub async fn load_watched_account(
+    network: &Network,
+    public_key: String,
+) -> Result<WatchedAccount, MyError> {
+    let data = load_account_activity(
+        network,
+        AccountActivityParams {
+            public_key: Some(public_key.clone()),
+            blocks_limit: Some(0),
+            snarks_limit: Some(0),
+            trans_limit: Some(1),
+            delegators_limit: Some(0),
+            internal_commands_limit: Some(0),
+            prover: Some(public_key.clone()),
+            block_producer: Some(public_key.clone()),
+            canonical: Some(true),
+            ..Default::default()
+        },
+    )
+    .await?;
+    Ok(to_watched_account(public_key, data))
+}
+
+pub fn to_activity_snapshot(data: &account_activity_query::ResponseData) -> ActivitySnapshot {
+    ActivitySnapshot {
+        incoming: data
+            .incoming_transactions
+            .iter()
+            .flatten()
+            .filter_map(|txn| txn.hash.clone())
+            .collect(),
+        outgoing: data
+            .outgoing_transactions
+            .iter()
+            .flatten()
+            .filter_map(|txn| txn.hash.clone())
+            .collect(),
+        blocks: data
+            .blocks
+            .iter()
+            .flatten()
+            .filter_map(|block| block.state_hash.clone())
+            .collect(),
+        coinbases: data
+            .internal_commands
+            .iter()
+            .flatten()
+            .filter(|cmd| {
+                cmd.type_
+                    .as_deref()
+                    .is_some_and(|kind| kind.eq_ignore_ascii_case("coinbase"))
+            })
+            .filter_map(|cmd| cmd.block_state_hash.as_ref()?.state_hash.clone())
+            .collect(),
+        delegate: data
+            .accounts
+            .iter()
+            .flatten()
+            .next()
+            .and_then(|account| account.delegate.clone()),
+    }
+}
+
+/// Activity in `current` that was not in `previous` and that `rules` ask for.
+pub fn new_activity(
+    previous: &ActivitySnapshot,
+    current: &ActivitySnapshot,
+    rules: &NotificationRules,
+) -> Vec<ActivityNotice> {
+    let unseen = |kind: ActivityKind, before: &[String], now: &[String]| {
+        now.iter()
+            .filter(|id| !before.contains(id))
+            .map(|id| ActivityNotice {
+                kind,
+                id: id.clone(),
+            })
+            .collect::<Vec<_>>()
+    };
+    let delegation = match (&previous.delegate, &current.delegate) {
+        (Some(before), Some(now)) if before != now => vec![ActivityNotice {
+            kind: ActivityKind::Delegation,
+            id: now.clone(),
+        }],
+        _ => vec![],
+    };
+    unseen(
+        ActivityKind::Incoming,
+        &previous.incoming,
+        &current.incoming,
+    )
+    .into_iter()
+    .chain(unseen(
+        ActivityKind::Outgoing,
+        &previous.outgoing,
+        &current.outgoing,
+    ))
+    .chain(unseen(
+        ActivityKind::Block,
+        &previous.blocks,
+        &current.blocks,
+    ))
+    .chain(unseen(
+        ActivityKind::Coinbase,
+        &previous.coinbases,
+        &current.coinbases,
+    ))
+    .chain(delegation)
+    .filter(|notice| notice.kind.is_enabled(rules))
+    .collect()
+}
+
+/// Where clicking the notification for `notice` on `public_key` leads.
+pub fn activity_href(
+    mina_token_address: &str,
+    public_key: &str,
+    notice: &ActivityNotice,
+) -> String {
+    match notice.kind {
+        ActivityKind::Incoming | ActivityKind::Outgoing => format!("/commands/{}", notice.id),
+        ActivityKind::Block | ActivityKind::Coinbase => format!("/blocks/{}", notice.id),
+        ActivityKind::Delegation => format!(
+            "/addresses/accounts/{}/{}/spotlight",
+            mina_token_address, public_key
+        ),
+    }
+}
+
+/// The latest commands, produced blocks and coinbases of a watched key.
+pub async fn load_activity(
+    network: &Network,
+    public_key: String,
+) -> Result<account_activity_query::ResponseData, MyError> {
+    load_account_activity(
+        network,
+        AccountActivityParams {
+            public_key: Some(public_key.clone()),
+            blocks_limit: Some(5),
+            snarks_limit: Some(0),
+            trans_limit: Some(10),
+            delegators_limit: Some(0),
+            internal_commands_limit: Some(5),
+            prover: Some(public_key.clone()),
+            block_producer: Some(public_key),
+            canonical: Some(true),
+            ..Default::default()
+        },
+    )
+    .await
+}
+
+pub fn notifications_permitted() -> bool {
+    web_sys::Notification::permission() == web_sys::NotificationPermission::Granted
+}
+
+/// Asks for permission to notify, then calls `on_granted` if it was given.
+pub fn request_notification_permission(on_granted: impl FnOnce() + 'static) {
+    let Ok(promise) = web_sys::Notification::request_permission() else {
+        return;
+    };
+    spawn_local(async move {
+        let permission = wasm_bindgen_futures::JsFuture::from(promise).await;
+        if permission.is_ok_and(|permission| permission.as_string().as_deref() == Some("granted")) {
+            on_granted();
+        }
+    });
+}
+
+pub fn show_notification(title: &str, body: &str, on_click: impl FnOnce() + 'static) {
+    let options = web_sys::NotificationOptions::new();
+    options.set_body(body);
+    let Ok(notification) = web_sys::Notification::new_with_options(title, &options) else {
+        logging::error!("Unable to show notification {}", title);
+        return;
+    };
+    // Freed once it runs, or with the notification if it is never clicked
+    let on_notification_click = Closure::once_into_js(move || {
+        let _ = leptos::window().focus();
+        on_click();
+    });
+    notification.set_onclick(Some(on_notification_click.unchecked_ref()));
+}
+
+/// Canonical commands sent or received by any of the keys, newest first.
+pub async fn load_recent_transactions(
+    network: &Network,
+    public_keys: Vec<String>,
+    limit: u64,
+) -> Result<transactions_query::ResponseData, MyError> {
+    let keys = public_keys.into_iter().map(Some).collect::<Vec<_>>();
+    let variables = transactions_query::Variables {
+        sort_by: transactions_query::TransactionSortByInput::BLOCKHEIGHT_DESC,
+        limit: Some(limit as i64),
+        txn_query: transactions_query::TransactionQueryInput {
+            canonical: Some(true),
+            or: Some(vec![
+                transactions_query::TransactionQueryInput {
+                    from_in: Some(keys.clone()),
+                    ..Default::default()
+                },
+                transactions_query::TransactionQueryInput {
+                    to_in: Some(keys),
+                    ..Default::default()
+                },
+            ]),
+            ..Default::default()
+        },
+        other_txn_query: None,
+        token_query: None,
+    };
+
+    query_graphql::<TransactionsQuery>(network, variables).await
+}
+
+#[cfg(test)]
+mod watchlist_tests {
+    use super::*;
+    use account_activity_query::{AccountActivityQueryAccounts, ResponseData};
+
+    const KEY: &str = "B62qrCz3ehCqi8Pn8y3vWC9zYEB9RKsidauv15DeZxhzkxL3bKeba5h";
+    const TOKEN: &str = "wSHV2S4qX9jFsLjQo8r1BsMLH2ZRKsZx6EJd1sbozGPieEC4Jf";
+
+    #[test]
+    fn test_toggle_watched() {
+        let mut watchlist = vec!["a".to_string()];
+        toggle_watched(&mut watchlist, KEY);
+        assert_eq!(watchlist, vec!["a".to_string(), KEY.to_string()]);
+        toggle_watched(&mut watchlist, "a");
+        assert_eq!(watchlist, vec![KEY.to_string()]);
+    }
+
+    #[test]
+    fn test_to_watched_account() {
+        let data = ResponseData {
+            accounts: vec![Some(AccountActivityQueryAccounts {
+                balance: Some(1_500_000_000),
+                nonce: Some(7),
+                delegate: Some(KEY.to_string()),
+                ..Default::default()
+            })],
+            ..Default::default()
+        };
+        let account = to_watched_account(KEY.to_string(), data);
+        assert_eq!(account.balance, Some(1_500_000_000));
+        assert_eq!(account.nonce, Some(7));
+        assert_eq!(account.delegate.as_deref(), Some(KEY));
+        assert_eq!(account.last_activity, None);
+    }
+
+    fn snapshot(incoming: &[&str], blocks: &[&str], delegate: Option<&str>) -> ActivitySnapshot {
+        ActivitySnapshot {
+            incoming: incoming.iter().map(|s| s.to_string()).collect(),
+            blocks: blocks.iter().map(|s| s.to_string()).collect(),
+            delegate: delegate.map(str::to_string),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_new_activity() {
+        let previous = snapshot(&["CkpA"], &["3NKa"], Some("B62qa"));
+        let current = snapshot(&["CkpB", "CkpA"], &["3NKa"], Some("B62qb"));
+        let notices = new_activity(&previous, &current, &NotificationRules::default());
+        assert_eq!(
+            notices,
+            vec![
+                ActivityNotice {
+                    kind: ActivityKind::Incoming,
+                    id: "CkpB".to_string()
+                },
+                ActivityNotice {
+                    kind: ActivityKind::Delegation,
+                    id: "B62qb".to_string()
+                },
+            ]
+        );
+        assert_eq!(activity_href(TOKEN, KEY, &notices[0]), "/commands/CkpB");
+    }
+
+    #[test]
+    fn test_new_activity_follows_rules() {
+        let previous = snapshot(&[], &[], None);
+        let current = snapshot(&["CkpA"], &["3NKa"], Some("B62qb"));
+        let rules = NotificationRules {
+            incoming: false,
+            ..Default::default()
+        };
+        let notices = new_activity(&previous, &current, &rules);
+        assert_eq!(notices.len(), 1);
+        assert_eq!(notices[0].kind, ActivityKind::Block);
+        assert_eq!(activity_href(TOKEN, KEY, &notices[0]), "/blocks/3NKa");
+    }
+
+    #[test]
+    fn test_to_watched_account_without_account() {
+        let account = to_watched_account(KEY.to_string(), ResponseData::default());
+        assert_eq!(
+            account,
+            WatchedAccount {
+                public_key: KEY.to_string(),
+                ..Default::default()
+            }
+        );
+    }
+}
@@ -0,0 +1,5 @@
+pub mod components;
+pub mod functions;
+mod models;
+pub mod page;
+mod table_trait;
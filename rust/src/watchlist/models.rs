@@ -0,0 +1,121 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// The latest state of a watched account.
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+pub struct WatchedAccount {
+    pub public_key: String,
+    pub username: Option<String>,
+    /// In nanomina.
+    pub balance: Option<i64>,
+    pub nonce: Option<u64>,
+    pub delegate: Option<String>,
+    pub delegate_username: Option<String>,
+    pub last_activity: Option<DateTime<Utc>>,
+}
+
+/// Which activity on a watched account raises a notification.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq)]
+#[serde(default)]
+pub struct NotificationRules {
+    pub incoming: bool,
+    pub outgoing: bool,
+    pub blocks: bool,
+    pub coinbase: bool,
+    pub delegation: bool,
+}
+
+impl Default for NotificationRules {
+    fn default() -> Self {
+        NotificationRules {
+            incoming: true,
+            outgoing: true,
+            blocks: true,
+            coinbase: true,
+            delegation: true,
+        }
+    }
+}
+
+/// Notification rules by public key.
+pub type NotificationRulesByKey = HashMap<String, NotificationRules>;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ActivityKind {
+    Incoming,
+    Outgoing,
+    Block,
+    Coinbase,
+    Delegation,
+}
+
+impl ActivityKind {
+    pub const ALL: [ActivityKind; 5] = [
+        ActivityKind::Incoming,
+        ActivityKind::Outgoing,
+        ActivityKind::Block,
+        ActivityKind::Coinbase,
+        ActivityKind::Delegation,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            ActivityKind::Incoming => "Incoming commands",
+            ActivityKind::Outgoing => "Outgoing commands",
+            ActivityKind::Block => "Produced blocks",
+            ActivityKind::Coinbase => "Coinbases",
+            ActivityKind::Delegation => "Delegation changes",
+        }
+    }
+
+    pub fn title(&self) -> &'static str {
+        match self {
+            ActivityKind::Incoming => "Incoming command",
+            ActivityKind::Outgoing => "Outgoing command",
+            ActivityKind::Block => "Block produced",
+            ActivityKind::Coinbase => "Coinbase received",
+            ActivityKind::Delegation => "Delegation changed",
+        }
+    }
+
+    pub fn is_enabled(&self, rules: &NotificationRules) -> bool {
+        match self {
+            ActivityKind::Incoming => rules.incoming,
+            ActivityKind::Outgoing => rules.outgoing,
+            ActivityKind::Block => rules.blocks,
+            ActivityKind::Coinbase => rules.coinbase,
+            ActivityKind::Delegation => rules.delegation,
+        }
+    }
+
+    pub fn set_enabled(&self, rules: &mut NotificationRules, enabled: bool) {
+        match self {
+            ActivityKind::Incoming => rules.incoming = enabled,
+            ActivityKind::Outgoing => rules.outgoing = enabled,
+            ActivityKind::Block => rules.blocks = enabled,
+            ActivityKind::Coinbase => rules.coinbase = enabled,
+            ActivityKind::Delegation => rules.delegation = enabled,
+        }
+    }
+}
+
+/// What was last seen on a watched account, to tell new activity apart.
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+pub struct ActivitySnapshot {
+    /// Transaction hashes.
+    pub incoming: Vec<String>,
+    pub outgoing: Vec<String>,
+    /// State hashes of the blocks the account produced, or took the coinbase of.
+    pub blocks: Vec<String>,
+    pub coinbases: Vec<String>,
+    pub delegate: Option<String>,
+}
+
+/// A single notification: a transaction hash, a state hash or, for
+/// delegation changes, the new delegate.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ActivityNotice {
+    pub kind: ActivityKind,
+    pub id: String,
+}
@@ -0,0 +1,183 @@
+use super::{components::NotificationSettings, functions::*};
+use crate::{
+    common::{
+        components::*, constants::*, i18n::t, live::use_live_updates, models::MyError,
+        network::use_network, table::*,
+    },
+    user_commands::graphql::transactions_query::TransactionsQueryTransactions,
+};
+use futures::future::join_all;
+use leptos::*;
+use leptos_meta::*;
+
+const RECENT_TRANSACTIONS_LIMIT: u64 = 25;
+
+#[component]
+pub fn WatchlistPage() -> impl IntoView {
+    let (watchlist, _) = use_watchlist();
+    let network = use_network();
+    let live = use_live_updates();
+    let (accounts_sig, set_accounts) = create_signal(None);
+    let (transactions_sig, set_transactions) =
+        create_signal::<Option<Vec<Option<TransactionsQueryTransactions>>>>(None);
+
+    let accounts_resource = create_resource(
+        move || (watchlist.get(), live.refresh_key()),
+        move |(public_keys, _)| async move {
+            let network = network.get_value();
+            join_all(public_keys.into_iter().map(|public_key| {
+                let network = network.clone();
+                async move {
+                    load_watched_account(&network, public_key.clone())
+                        .await
+                        .map_err(|e| logging::error!("Error loading {}: {:?}", public_key, e))
+                        .ok()
+                }
+            }))
+            .await
+        },
+    );
+
+    let transactions_resource = create_resource(
+        move || (watchlist.get(), live.refresh_key()),
+        move |(public_keys, _)| async move {
+            if public_keys.is_empty() {
+                return Ok::<_, MyError>(vec![]);
+            }
+            load_recent_transactions(&network.get_value(), public_keys, RECENT_TRANSACTIONS_LIMIT)
+                .await
+                .map(|data| data.transactions)
+        },
+    );
+
+    create_effect(move |_| {
+        if let Some(accounts) = accounts_resource.get() {
+            set_accounts.set(Some(accounts));
+        }
+    });
+
+    create_effect(move |_| {
+        if let Some(Ok(transactions)) = transactions_resource.get() {
+            set_transactions.set(Some(transactions));
+        }
+    });
+
+    let account_columns: Vec<TableColumn<AnySort>> = vec![
+        TableColumn {
+            column: "Account".to_string(),
+            width: Some(String::from(TABLE_COL_HASH_WIDTH)),
+            ..Default::default()
+        },
+        TableColumn {
+            column: "Balance".to_string(),
+            width: Some(String::from(TABLE_COL_LARGE_BALANCE)),
+            ..Default::default()
+        },
+        TableColumn {
+            column: "Nonce".to_string(),
+            width: Some(String::from(TABLE_COL_NUMERIC_WIDTH)),
+            alignment: Some(ColumnTextAlignment::Right),
+            ..Default::default()
+        },
+        TableColumn {
+            column: "Delegate".to_string(),
+            width: Some(String::from(TABLE_COL_HASH_WIDTH)),
+            ..Default::default()
+        },
+        TableColumn {
+            column: "Last Activity".to_string(),
+            width: Some(String::from(TABLE_COL_DATE_WIDTH)),
+            ..Default::default()
+        },
+    ];
+
+    let transaction_columns: Vec<TableColumn<AnySort>> = vec![
+        TableColumn {
+            column: "Height".to_string(),
+            alignment: Some(ColumnTextAlignment::Right),
+            width: Some(String::from(TABLE_COL_NUMERIC_WIDTH)),
+            ..Default::default()
+        },
+        TableColumn {
+            column: "Txn Hash".to_string(),
+            width: Some(String::from(TABLE_COL_HASH_WIDTH)),
+            ..Default::default()
+        },
+        TableColumn {
+            column: "Date".to_string(),
+            width: Some(String::from(TABLE_COL_DATE_WIDTH)),
+            ..Default::default()
+        },
+        TableColumn {
+            column: "Type".to_string(),
+            width: Some(String::from(TABLE_COL_SHORT_WIDTH)),
+            ..Default::default()
+        },
+        TableColumn {
+            column: "Status".to_string(),
+            width: Some(String::from(TABLE_COL_SHORT_WIDTH)),
+            ..Default::default()
+        },
+        TableColumn {
+            column: "From".to_string(),
+            width: Some(String::from(TABLE_COL_HASH_WIDTH)),
+            ..Default::default()
+        },
+        TableColumn {
+            column: "To".to_string(),
+            width: Some(String::from(TABLE_COL_HASH_WIDTH)),
+            ..Default::default()
+        },
+        TableColumn {
+            column: "Nonce".to_string(),
+            width: Some(String::from(TABLE_COL_NUMERIC_WIDTH)),
+            alignment: Some(ColumnTextAlignment::Right),
+            ..Default::default()
+        },
+        TableColumn {
+            column: "Fee".to_string(),
+            width: Some(String::from(TABLE_COL_NUMERIC_WIDTH)),
+            ..Default::default()
+        },
+        TableColumn {
+            column: "Amount".to_string(),
+            width: Some(String::from(TABLE_COL_LARGE_BALANCE)),
+            ..Default::default()
+        },
+    ];
+
+    let empty_hint = move || {
+        watchlist
+            .get()
+            .is_empty()
+            .then(|| t("Star an account on its page to add it to your watchlist."))
+    };
+
+    view! {
+        <Title text="Watchlist | Watched Accounts" />
+        <PageContainer>
+            <TableSectionTemplate
+                table_id="watchlist"
+                table_columns=account_columns
+                data_sig=accounts_sig
+                is_loading=Signal::derive(move || accounts_sig.get().is_none())
+                section_heading="Watchlist"
+                additional_info=move || {
+                    empty_hint().map(|hint| view! { <span class="text-sm">{hint}</span> })
+                }
+            />
+            <TableSectionTemplate
+                table_id="watchlist-recent-transactions"
+                table_columns=transaction_columns
+                data_sig=transactions_sig
+                is_loading=transactions_resource.loading()
+                error=Signal::derive(move || {
+                    transactions_resource.get().and_then(|res| res.err())
+                })
+                on_retry=Callback::new(move |_| transactions_resource.refetch())
+                section_heading="Recent Transactions"
+            />
+            <NotificationSettings />
+        </PageContainer>
+    }
+}
@@ -0,0 +1,56 @@
+use super::models::WatchedAccount;
+use crate::common::{amount::Amount, functions::*, models::ColorVariant, table::*};
+use leptos::*;
+
+impl TableData for Vec<Option<WatchedAccount>> {
+    fn get_rows(&self) -> Vec<Vec<HtmlElement<html::AnyElement>>> {
+        self.iter()
+            .map(|opt_account| match opt_account {
+                Some(account) => vec![
+                    convert_to_linkable_address(
+                        account.username.clone(),
+                        account.public_key.clone(),
+                    ),
+                    decorate_with_mina_tag(
+                        account
+                            .balance
+                            .map(|balance| Amount::from_nanomina(balance).format())
+                            .unwrap_or_default(),
+                    ),
+                    convert_to_pill(opt_to_string(account.nonce), ColorVariant::Grey),
+                    account
+                        .delegate
+                        .clone()
+                        .map(|delegate| {
+                            convert_to_linkable_address(account.delegate_username.clone(), delegate)
+                        })
+                        .unwrap_or(html::span().into()),
+                    account
+                        .last_activity
+                        .map(|date_time| convert_to_date_time(date_time.to_string()))
+                        .unwrap_or(html::span().into()),
+                ],
+                None => vec![],
+            })
+            .collect::<Vec<_>>()
+    }
+
+    fn get_raw_rows(&self) -> Vec<Vec<String>> {
+        self.iter()
+            .flatten()
+            .map(|account| {
+                vec![
+                    account.public_key.clone(),
+                    opt_to_string(
+                        account
+                            .balance
+                            .map(|balance| Amount::from_nanomina(balance).to_mina_string()),
+                    ),
+                    opt_to_string(account.nonce),
+                    account.delegate.clone().unwrap_or_default(),
+                    opt_to_string(account.last_activity),
+                ]
+            })
+            .collect()
+    }
+}